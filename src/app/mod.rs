@@ -52,13 +52,13 @@ pub fn show_autoscroll_area<R>(
         }
     }
 
-    if origin.is_some() {
-        if ui.input(|i| {
+    if origin.is_some()
+        && ui.input(|i| {
             i.pointer.button_pressed(egui::PointerButton::Primary)
                 || i.pointer.button_pressed(egui::PointerButton::Secondary)
-        }) {
-            origin = None;
-        }
+        })
+    {
+        origin = None;
     }
 
     if let Some(o) = origin {
@@ -76,6 +76,16 @@ include!("types.rs");
 
 const KEYRING_SERVICE: &str = "redsky";
 const KEYRING_USER: &str = "credentials";
+const UNREAD_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+const CHAT_LOG_ACTIVE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+const READING_POSITION_SAVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+const MAX_RESTORE_PAGES: usize = 10;
+const SAVED_SEARCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(120);
+const UNREAD_MARKER_HEIGHT: f32 = 24.0;
+const PROFILE_BANNER_HEIGHT: f32 = 100.0;
+const TYPEAHEAD_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(250);
+const MAX_RECENT_COMMANDS: usize = 8;
+const MAX_PALETTE_ENTRIES: usize = 12;
 
 pub struct RedskyApp {
     tx: Sender<BskyActorMsg>,
@@ -86,14 +96,40 @@ pub struct RedskyApp {
     main_view_state: MainViewState,
     login: String,
     pass: String,
+    session_did: String, // the logged in account, whatever was typed as login
+    awaiting_login: bool, // after switching accounts, until the new login is in
     msg: String,
     timeline: Vec<FeedItem>,
-    bookmarks: Vec<Post>,
+    bookmarks: Vec<FeedItem>,
+    removed_bookmarks: HashMap<String, (usize, FeedItem)>, // uri -> place in the tab, until the delete succeeds
     user_posts: HashMap<String, Option<Vec<FeedItem>>>,
     user_likes_posts: HashMap<String, Option<Vec<FeedItem>>>,
+    user_tabs: UserTabFeeds,
     user_view_states: HashMap<String, UserViewState>,
     media_image_sizes: HashMap<String, f32>,
     timeline_cursor: Option<String>,
+    saved_feeds: Vec<SavedFeed>,
+    feed_generators: HashMap<String, FeedGenerator>, // feed uri -> metadata
+    custom_feeds: HashMap<String, Vec<FeedItem>>,
+    custom_feed_cursors: HashMap<String, Option<String>>,
+    is_feeds_window_open: bool,
+    feeds_discovery_kind: FeedDiscoveryKind,
+    suggested_feeds: Vec<FeedGenerator>,
+    suggested_feeds_cursor: Option<String>,
+    popular_feeds: Vec<FeedGenerator>,
+    popular_feeds_cursor: Option<String>,
+    feeds_search_query: String,
+    own_lists: Vec<UserList>,
+    own_lists_cursor: Option<String>,
+    list_members_cache: HashMap<String, (UserList, Vec<ListMember>, Option<String>)>,
+    list_memberships: HashMap<String, Option<Vec<ListMembership>>>, // did -> own lists with membership
+    is_lists_window_open: bool,
+    selected_list: Option<String>,
+    is_list_form_open: bool,
+    list_form_uri: Option<String>, // None when creating a new list
+    list_name_input: String,
+    list_description_input: String,
+    list_purpose_input: String,
     user_cursors: HashMap<String, Option<String>>,
     user_likes_cursors: HashMap<String, Option<String>>,
     post_cache: HashMap<String, Post>,
@@ -103,6 +139,20 @@ pub struct RedskyApp {
     image_cache: HashMap<String, Option<egui::TextureHandle>>,
     post_likers_cache: HashMap<StrongRef, (Vec<UserProfile>, Option<String>)>,
     post_reposters_cache: HashMap<StrongRef, (Vec<UserProfile>, Option<String>)>,
+    user_lists_cache: HashMap<(String, UserListKind), (Vec<UserProfile>, Option<String>)>,
+    mute_overrides: HashMap<String, bool>, // did -> muted, for changes made this session
+    block_overrides: HashMap<String, bool>, // did -> blocked, for changes made this session
+    revealed_posts: HashSet<String>,
+    revealed_media: HashSet<String>,
+    is_moderation_window_open: bool,
+    moderation_view_state: ModerationViewState,
+    blocked_accounts: Vec<UserProfile>,
+    blocked_accounts_cursor: Option<String>,
+    muted_accounts: Vec<UserProfile>,
+    muted_accounts_cursor: Option<String>,
+    muted_word_input: String,
+    muted_word_targets: MutedWordTargets,
+    muted_word_duration_days: Option<i64>,
     post_replies_cache: HashMap<StrongRef, Option<Vec<FeedItem>>>,
     opened_image_views: HashSet<String>,
     opened_raw_views: HashMap<String, String>, // uri -> raw_json
@@ -111,12 +161,69 @@ pub struct RedskyApp {
     is_search_window_open: bool,
     search_query: String,
     search_results: Vec<UserProfile>,
+    search_results_query: String, // query of the last full search, older answers are dropped
+    search_results_cursor: Option<String>,
+    typeaheads: HashMap<String, ActorTypeahead>,
     is_search_posts_window_open: bool,
-    search_posts_query: String,
+    is_history_window_open: bool,
+    profile_edit: Option<ProfileEdit>, // the edit profile window is open while set
+    profile_edit_saving: bool,
+    profile_edit_error: Option<String>,
+    shortcut_input: ShortcutInput,
+    post_shortcut: Option<ShortcutAction>, // waiting for the main view to apply it to its selected post
+    selected_post: Option<String>,         // uri of the post picked with the keyboard in the main view
+    scroll_to_selected: bool,
+    is_shortcut_help_open: bool,
+    is_palette_open: bool,
+    palette_query: String,
+    palette_selected: usize,
+    open_link_input: Option<String>, // the open link window is open while set
+    open_link_error: Option<String>,
+    pending_link: Option<String>, // from the command line, opened once logged in
+    link_handler_status: Option<String>,
+    history_query: String,
+    history_filter: HistoryFilter,
+    history_results: Option<Vec<FeedItem>>,
+    cached_feeds: HashSet<String>, // feeds showing posts from the local database until the network answers
+    is_offline: bool,
+    search_posts: PostSearch,        // being edited in the search window
+    search_posts_active: PostSearch, // last one sent, pages load with the same filters
+    search_posts_total: Option<i64>,
+    saved_search_posts: HashMap<String, Vec<FeedItem>>, // saved search query -> posts
+    saved_search_cursors: HashMap<String, Option<String>>,
+    saved_search_unread: HashMap<String, usize>,
+    last_saved_search_poll: std::time::Instant,
     search_posts_results: Option<Vec<FeedItem>>,
     search_posts_cursor: Option<String>,
     unread_notifications: i64,
+    mark_seen_on_load: bool, // mark notifications as seen once the first page is shown
     notifications: Vec<AppNotification>,
+    notification_groups: Vec<NotificationGroup>,
+    notification_subjects: HashMap<String, Post>, // uri -> liked or reposted post
+    notification_filter: NotificationFilter,
+    last_unread_poll: std::time::Instant,
+    title_unread_count: i64, // unread count currently shown in the window title
+    desktop_notified_at: Option<String>, // newest notification already seen or announced
+    feed_top_visible: HashMap<String, (String, f32)>, // feed -> first visible post and how far it is scrolled past
+    feed_scroll_anchors: HashMap<String, (String, f32)>, // feed -> where to scroll once the post is rendered
+    reading_positions: ReadingPositions,
+    reading_positions_dirty: bool,
+    last_reading_positions_save: std::time::Instant,
+    restoring_feeds: HashMap<String, ReadingRestore>,
+    restored_feed_heads: HashSet<String>, // custom feeds whose next first page is merged above a restored position
+    feed_request_cursors: HashMap<String, String>, // feed -> cursor of the page being loaded
+    feed_page_cursors: HashMap<String, HashMap<String, String>>, // feed -> post uri -> cursor of its page
+    unread_markers: HashMap<String, String>, // feed -> last read post, unread posts are above it
+    pending_timeline_posts: Vec<Post>, // live posts waiting behind the "N new posts" pill, newest first
+    timeline_hidden: HashSet<String>,  // uris filtered out by the timeline view preferences
+    convos: Vec<Conversation>,
+    convos_cursor: Option<String>,
+    convo_messages: HashMap<String, (Vec<ChatMessage>, Option<String>)>, // convo id -> messages (oldest first), cursor
+    open_convos: Vec<String>,
+    chat_inputs: HashMap<String, String>,
+    chat_log_cursor: Option<String>,
+    chat_log_started: bool, // the first getLog answer only tells where the log is
+    last_chat_log_poll: std::time::Instant,
     remember_me: bool,
     pub notifications_cursor: Option<String>,
     pub bookmarks_cursor: Option<String>,
@@ -171,6 +278,7 @@ impl RedskyApp {
                 cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
                 content: "Hello, world! This is a mock post for the screenshot test.".to_string(),
                 author: "mockuser.bsky.social".to_string(),
+                author_did: "did:plc:mockuser".to_string(),
                 display_name: "Mock User".to_string(),
                 avatar_img: "".to_string(),
                 date: "2024-01-01T00:00:00Z".to_string(),
@@ -181,7 +289,12 @@ impl RedskyApp {
                 is_reply: false,
                 viewer_like: None,
                 viewer_repost: None,
+                bookmarked: false,
+                author_muted: false,
+                author_blocked: false,
                 thread_root: None,
+                tags: vec![],
+                labels: vec![],
                 raw_json: "{}".to_string(),
             }, None));
             timeline.push(FeedItem::Full(Post {
@@ -189,6 +302,7 @@ impl RedskyApp {
                 cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
                 content: "Another mock post right here.".to_string(),
                 author: "anotheruser.bsky.social".to_string(),
+                author_did: "did:plc:anotheruser".to_string(),
                 display_name: "Another User".to_string(),
                 avatar_img: "".to_string(),
                 date: "2024-01-01T00:05:00Z".to_string(),
@@ -199,7 +313,12 @@ impl RedskyApp {
                 is_reply: false,
                 viewer_like: None,
                 viewer_repost: None,
+                bookmarked: false,
+                author_muted: false,
+                author_blocked: false,
                 thread_root: None,
+                tags: vec![],
+                labels: vec![],
                 raw_json: "{}".to_string(),
             }, None));
             let _ = tx.send(BskyActorMsg::GetUnreadCount());
@@ -213,16 +332,42 @@ impl RedskyApp {
             is_post_window_open: false,
             main_view_state,
             login,
+            session_did: String::new(),
+            awaiting_login: false,
             pass,
             remember_me,
             msg: String::new(),
             timeline,
             bookmarks: Vec::new(),
+            removed_bookmarks: HashMap::new(),
             user_posts: HashMap::new(),
             user_likes_posts: HashMap::new(),
+            user_tabs: UserTabFeeds::default(),
             user_view_states: HashMap::new(),
             media_image_sizes: HashMap::new(),
             timeline_cursor: None,
+            saved_feeds: Vec::new(),
+            feed_generators: HashMap::new(),
+            custom_feeds: HashMap::new(),
+            custom_feed_cursors: HashMap::new(),
+            is_feeds_window_open: false,
+            feeds_discovery_kind: FeedDiscoveryKind::Saved,
+            suggested_feeds: Vec::new(),
+            suggested_feeds_cursor: None,
+            popular_feeds: Vec::new(),
+            popular_feeds_cursor: None,
+            feeds_search_query: String::new(),
+            own_lists: Vec::new(),
+            own_lists_cursor: None,
+            list_members_cache: HashMap::new(),
+            list_memberships: HashMap::new(),
+            is_lists_window_open: false,
+            selected_list: None,
+            is_list_form_open: false,
+            list_form_uri: None,
+            list_name_input: String::new(),
+            list_description_input: String::new(),
+            list_purpose_input: CURATE_LIST_PURPOSE.to_string(),
             user_cursors: HashMap::new(),
             user_likes_cursors: HashMap::new(),
             post_cache: HashMap::new(),
@@ -232,6 +377,20 @@ impl RedskyApp {
             image_cache: HashMap::new(),
             post_likers_cache: HashMap::new(),
             post_reposters_cache: HashMap::new(),
+            user_lists_cache: HashMap::new(),
+            mute_overrides: HashMap::new(),
+            block_overrides: HashMap::new(),
+            revealed_posts: HashSet::new(),
+            revealed_media: HashSet::new(),
+            is_moderation_window_open: false,
+            moderation_view_state: ModerationViewState::Blocked,
+            blocked_accounts: Vec::new(),
+            blocked_accounts_cursor: None,
+            muted_accounts: Vec::new(),
+            muted_accounts_cursor: None,
+            muted_word_input: String::new(),
+            muted_word_targets: MutedWordTargets::Both,
+            muted_word_duration_days: None,
             post_replies_cache: HashMap::new(),
             opened_image_views: HashSet::new(),
             opened_raw_views: HashMap::new(),
@@ -240,12 +399,69 @@ impl RedskyApp {
             is_search_window_open: is_screenshot_mode,
             search_query: String::new(),
             search_results: Vec::new(),
+            search_results_query: String::new(),
+            search_results_cursor: None,
+            typeaheads: HashMap::new(),
             is_search_posts_window_open: false,
-            search_posts_query: String::new(),
+            is_history_window_open: false,
+            profile_edit: None,
+            profile_edit_saving: false,
+            profile_edit_error: None,
+            shortcut_input: ShortcutInput::default(),
+            post_shortcut: None,
+            selected_post: None,
+            scroll_to_selected: false,
+            is_shortcut_help_open: false,
+            is_palette_open: false,
+            palette_query: String::new(),
+            palette_selected: 0,
+            open_link_input: None,
+            open_link_error: None,
+            pending_link: None,
+            link_handler_status: None,
+            history_query: String::new(),
+            history_filter: HistoryFilter::All,
+            history_results: None,
+            cached_feeds: HashSet::new(),
+            is_offline: false,
+            search_posts: PostSearch::default(),
+            search_posts_active: PostSearch::default(),
+            search_posts_total: None,
+            saved_search_posts: HashMap::new(),
+            saved_search_cursors: HashMap::new(),
+            saved_search_unread: HashMap::new(),
+            last_saved_search_poll: std::time::Instant::now(),
             search_posts_results: None,
             search_posts_cursor: None,
             unread_notifications: 0,
+            mark_seen_on_load: false,
             notifications: Vec::new(),
+            notification_groups: Vec::new(),
+            notification_subjects: HashMap::new(),
+            notification_filter: NotificationFilter::All,
+            last_unread_poll: std::time::Instant::now(),
+            title_unread_count: 0,
+            desktop_notified_at: None,
+            feed_top_visible: HashMap::new(),
+            feed_scroll_anchors: HashMap::new(),
+            reading_positions: ReadingPositions::default(), // loaded once we know the account
+            reading_positions_dirty: false,
+            last_reading_positions_save: std::time::Instant::now(),
+            restoring_feeds: HashMap::new(),
+            restored_feed_heads: HashSet::new(),
+            feed_request_cursors: HashMap::new(),
+            feed_page_cursors: HashMap::new(),
+            unread_markers: HashMap::new(),
+            pending_timeline_posts: Vec::new(),
+            timeline_hidden: HashSet::new(),
+            convos: Vec::new(),
+            convos_cursor: None,
+            convo_messages: HashMap::new(),
+            open_convos: Vec::new(),
+            chat_inputs: HashMap::new(),
+            chat_log_cursor: None,
+            chat_log_started: false,
+            last_chat_log_poll: std::time::Instant::now(),
             notifications_cursor: None,
             bookmarks_cursor: None,
             settings: AppSettings::load(),
@@ -257,6 +473,12 @@ impl RedskyApp {
             frames_rendered: 0,
         }
     }
+
+    /// A link given on the command line, opened once logged in.
+    pub fn with_startup_link(mut self, link: Option<String>) -> Self {
+        self.pending_link = link;
+        self
+    }
 }
 impl RedskyApp {
     fn post_message(&self, msg: BskyActorMsg) {
@@ -269,7 +491,7 @@ impl RedskyApp {
         if img_url.is_empty() {
             return;
         }
-        if self.image_cache.get(img_url).is_none() {
+        if !self.image_cache.contains_key(img_url) {
             println!("requesting image {}", img_url);
             self.image_cache.insert(img_url.to_string(), None);
             self.post_message(BskyActorMsg::LoadImage {
@@ -292,22 +514,8 @@ impl RedskyApp {
     where
         F: FnMut(&mut Post),
     {
-        // Update timeline
-        for item in &mut self.timeline {
-            if let FeedItem::Full(post, _) = item {
-                if post.uri == post_uri {
-                    update_fn(post);
-                }
-                if let Some(quoted) = &mut post.quoted_post {
-                    if quoted.uri == post_uri {
-                        update_fn(quoted);
-                    }
-                }
-            }
-        }
-        // Update user posts
-        for posts in self.user_posts.values_mut().flatten() {
-            for item in posts {
+        fn update_items<F: FnMut(&mut Post)>(items: &mut [FeedItem], post_uri: &str, update_fn: &mut F) {
+            for item in items {
                 if let FeedItem::Full(post, _) = item {
                     if post.uri == post_uri {
                         update_fn(post);
@@ -320,56 +528,104 @@ impl RedskyApp {
                 }
             }
         }
-        // Update user likes posts
-        for posts in self.user_likes_posts.values_mut().flatten() {
-            for item in posts {
-                if let FeedItem::Full(post, _) = item {
-                    if post.uri == post_uri {
-                        update_fn(post);
-                    }
-                    if let Some(quoted) = &mut post.quoted_post {
-                        if quoted.uri == post_uri {
-                            update_fn(quoted);
-                        }
-                    }
+        // Update timeline, bookmarks and custom feeds
+        update_items(&mut self.timeline, post_uri, &mut update_fn);
+        update_items(&mut self.bookmarks, post_uri, &mut update_fn);
+        for items in self.custom_feeds.values_mut() {
+            update_items(items, post_uri, &mut update_fn);
+        }
+        // Update user posts and likes
+        for items in self
+            .user_posts
+            .values_mut()
+            .chain(self.user_likes_posts.values_mut())
+            .chain(self.user_tabs.posts.values_mut())
+            .flatten()
+        {
+            update_items(items, post_uri, &mut update_fn);
+        }
+        // Update replies cache and search results
+        for items in self
+            .post_replies_cache
+            .values_mut()
+            .chain(std::iter::once(&mut self.search_posts_results))
+            .chain(std::iter::once(&mut self.history_results))
+            .flatten()
+            .chain(self.saved_search_posts.values_mut())
+        {
+            update_items(items, post_uri, &mut update_fn);
+        }
+        // Update post cache
+        if let Some(post) = self.post_cache.get_mut(post_uri) {
+            update_fn(post);
+        }
+        for post in self.post_cache.values_mut() {
+            if let Some(quoted) = &mut post.quoted_post {
+                if quoted.uri == post_uri {
+                    update_fn(quoted);
                 }
             }
         }
-        // Update replies cache
-        for posts in self.post_replies_cache.values_mut().flatten() {
-            for item in posts {
+    }
+    fn find_post(&self, post_uri: &str) -> Option<Post> {
+        let lists = [&self.timeline, &self.bookmarks]
+            .into_iter()
+            .chain(self.custom_feeds.values())
+            .chain(self.user_posts.values().flatten())
+            .chain(self.user_likes_posts.values().flatten())
+            .chain(self.user_tabs.posts.values().flatten())
+            .chain(self.post_replies_cache.values().flatten())
+            .chain(self.search_posts_results.iter())
+            .chain(self.history_results.iter())
+            .chain(self.saved_search_posts.values());
+        for items in lists {
+            for item in items {
                 if let FeedItem::Full(post, _) = item {
                     if post.uri == post_uri {
-                        update_fn(post);
-                    }
-                    if let Some(quoted) = &mut post.quoted_post {
-                        if quoted.uri == post_uri {
-                            update_fn(quoted);
-                        }
+                        return Some(post.clone());
                     }
                 }
             }
         }
-        // Update bookmarks
-        for post in &mut self.bookmarks {
-            if post.uri == post_uri {
-                update_fn(post);
+        self.post_cache.get(post_uri).cloned()
+    }
+    fn update_profile_optimistically<F>(&mut self, did: &str, mut update_fn: F)
+    where
+        F: FnMut(&mut UserProfile),
+    {
+        // Update profile headers
+        for profile in self.user_infos_cache.values_mut() {
+            if profile.did == did {
+                update_fn(profile);
             }
-            if let Some(quoted) = &mut post.quoted_post {
-                if quoted.uri == post_uri {
-                    update_fn(quoted);
+        }
+        // Update likers, reposters, followers and follows lists
+        for (profiles, _) in self
+            .post_likers_cache
+            .values_mut()
+            .chain(self.post_reposters_cache.values_mut())
+            .chain(self.user_lists_cache.values_mut())
+        {
+            for profile in profiles {
+                if profile.did == did {
+                    update_fn(profile);
                 }
             }
         }
-        // Update post cache
-        if let Some(post) = self.post_cache.get_mut(post_uri) {
-            update_fn(post);
+        // Update moderation lists
+        for profile in self
+            .blocked_accounts
+            .iter_mut()
+            .chain(self.muted_accounts.iter_mut())
+        {
+            if profile.did == did {
+                update_fn(profile);
+            }
         }
-        for post in self.post_cache.values_mut() {
-            if let Some(quoted) = &mut post.quoted_post {
-                if quoted.uri == post_uri {
-                    update_fn(quoted);
-                }
+        // Update account search results
+        for profile in &mut self.search_results {
+            if profile.did == did {
+                update_fn(profile);
             }
         }
     }
@@ -408,11 +664,18 @@ impl eframe::App for RedskyApp {
         while let Ok(msg) = self.rx.try_recv() {
             self.process_message(ctx, msg);
         }
+        self.poll_notifications(ctx);
+        self.poll_chat_log(ctx);
+        self.poll_saved_searches(ctx);
+        self.handle_shortcuts(ctx);
+        let closing = ctx.input(|i| i.viewport().close_requested());
+        self.save_reading_positions(closing);
         self.make_user_timelines_views(ctx);
         self.make_download_progress_view(ctx);
         self.make_image_viewports(ctx);
         self.make_open_thread_views(ctx);
         self.make_user_list_viewports(ctx);
+        self.make_convo_windows(ctx);
         if self.is_post_window_open {
             self.make_new_post_view(ctx);
         }
@@ -422,9 +685,33 @@ impl eframe::App for RedskyApp {
         if self.is_search_posts_window_open {
             self.make_search_posts_window(ctx);
         }
+        if self.is_history_window_open {
+            self.make_history_window(ctx);
+        }
+        if self.profile_edit.is_some() {
+            self.make_profile_edit_window(ctx);
+        }
         if self.is_settings_window_open {
             self.make_settings_window(ctx);
         }
+        if self.is_moderation_window_open {
+            self.make_moderation_window(ctx);
+        }
+        if self.is_feeds_window_open {
+            self.make_feeds_window(ctx);
+        }
+        if self.is_lists_window_open {
+            self.make_lists_window(ctx);
+        }
+        if self.is_shortcut_help_open {
+            self.make_shortcut_help_window(ctx);
+        }
+        if self.is_palette_open {
+            self.make_command_palette(ctx);
+        }
+        if self.open_link_input.is_some() {
+            self.make_open_link_window(ctx);
+        }
         if self.main_view_state != MainViewState::Login {
             let mut top_clicked = false;
             egui::Area::new(egui::Id::new("top_button"))
@@ -443,29 +730,49 @@ impl eframe::App for RedskyApp {
             ui.vertical(|ui| {
                 egui::MenuBar::new().ui(ui, |ui| {
                     ui.menu_button("File", |ui| {
-                        if ui.button("New post...").clicked() {
-                            self.is_post_window_open = true;
-                            self.reply_to = None;
+                        if ui.button("Command palette...").on_hover_text("Ctrl+K").clicked() {
+                            self.open_command_palette();
                         }
-                        if ui.button("Search accounts...").clicked() {
-                            self.is_search_window_open = true;
+                        for (text, command) in [
+                            ("New post...", PaletteCommand::NewPost),
+                            ("Search accounts...", PaletteCommand::SearchAccounts),
+                            ("Search posts...", PaletteCommand::SearchPosts),
+                            ("Search my history...", PaletteCommand::SearchHistory),
+                            ("Open link...", PaletteCommand::OpenLink),
+                            ("Discover feeds...", PaletteCommand::DiscoverFeeds),
+                            ("Edit profile...", PaletteCommand::EditProfile),
+                            ("Lists...", PaletteCommand::OpenLists),
+                            ("Settings...", PaletteCommand::OpenSettings),
+                            ("Moderation...", PaletteCommand::OpenModeration),
+                        ] {
+                            if ui.button(text).clicked() {
+                                self.run_command(ui.ctx(), command);
+                            }
                         }
-                        if ui.button("Search posts...").clicked() {
-                            self.is_search_posts_window_open = true;
-                        }
-                        if ui.button("Settings...").clicked() {
-                            self.is_settings_window_open = true;
+                        if self.main_view_state != MainViewState::Login && ui.button("Log out and forget saved login...").clicked() {
+                            self.run_command(ui.ctx(), PaletteCommand::SwitchAccount);
                         }
                         if ui.button("Quit").clicked() {
+                            self.save_reading_positions(true);
                             std::process::exit(0);
                         }
                     });
                     ui.menu_button("View", |ui| {
+                        if ui.button("Keyboard shortcuts").clicked() {
+                            self.run_command(ui.ctx(), PaletteCommand::ShowShortcuts);
+                            ui.close();
+                        }
                         if ui.button("Refresh timeline").clicked() {
-                            self.timeline_cursor = None;
-                            self.post_message(BskyActorMsg::GetTimeline { cursor: None });
+                            self.run_command(ui.ctx(), PaletteCommand::RefreshTimeline);
                             ui.close();
                         }
+                        if let MainViewState::CustomFeed(feed_uri) = &self.main_view_state {
+                            if ui.button("Refresh feed").clicked() {
+                                self.custom_feed_cursors.remove(feed_uri);
+                                self.post_message(custom_feed_request(feed_uri, None));
+                                ui.close();
+                            }
+                        }
                     });
                 });
                 if self.main_view_state != MainViewState::Login {
@@ -481,6 +788,8 @@ impl eframe::App for RedskyApp {
                                 MainViewState::TimelineFeed,
                                 RichText::new("Timeline feed").heading(),
                             );
+                            self.make_custom_feed_tabs(ui);
+                            self.make_saved_search_tabs(ui);
                             ui.selectable_value(
                                 &mut self.main_view_state,
                                 MainViewState::BookmarksFeed,
@@ -496,11 +805,22 @@ impl eframe::App for RedskyApp {
                                 MainViewState::NotificationsFeed,
                                 RichText::new(format!("Notifications{}", bell_icon)).heading(),
                             );
+                            let unread_messages = self.unread_messages();
+                            let messages_title = if unread_messages > 0 {
+                                format!("Messages ({})", unread_messages)
+                            } else {
+                                "Messages".to_string()
+                            };
+                            ui.selectable_value(
+                                &mut self.main_view_state,
+                                MainViewState::Messages,
+                                RichText::new(messages_title).heading(),
+                            );
                         });
                         ui.separator();
                     });
                 }
-                match self.main_view_state {
+                match self.main_view_state.clone() {
                     MainViewState::Login => {
                         ui.centered_and_justified(|ui| {
                             ui.vertical_centered_justified(|ui| {
@@ -533,6 +853,20 @@ impl eframe::App for RedskyApp {
                                             pass: self.pass.to_string(),
                                         });
                                     }
+                                    if ui
+                                        .button("Browse offline")
+                                        .on_hover_text("Show the posts saved during previous sessions")
+                                        .clicked()
+                                    {
+                                        self.is_offline = true;
+                                        self.main_view_state = MainViewState::TimelineFeed;
+                                        self.post_message(BskyActorMsg::LoadCachedFeed {
+                                            feed: "Your timeline".to_string(),
+                                        });
+                                        self.post_message(BskyActorMsg::LoadCachedFeed {
+                                            feed: "Your bookmarks".to_string(),
+                                        });
+                                    }
                                 });
                             });
                         });
@@ -554,15 +888,9 @@ impl eframe::App for RedskyApp {
                             egui::Layout::left_to_right(egui::Align::TOP).with_main_justify(true),
                             |ui| {
                                 ui.vertical(|ui| {
-                                    // Bookmarks also needs to use FeedItem if I want to use make_post_view
-                                    // or I should convert them.
-                                    // In this patch I'll convert them for simplicity as a first step.
-                                    let mut bookmark_items = crate::app::into_feed_items(
-                                        self.bookmarks.iter().cloned()
-                                    );
-                                    self.make_post_view(ui, "Your bookmarks", &mut bookmark_items);
-                                    // Note: changes to bookmark_items (like dehydration) won't persist back to self.bookmarks
-                                    // this way. Ideally bookmarks should also be Vec<FeedItem>.
+                                    let mut bookmarks = std::mem::take(&mut self.bookmarks);
+                                    self.make_post_view(ui, "Your bookmarks", &mut bookmarks);
+                                    self.bookmarks = bookmarks;
                                 });
                             },
                         );
@@ -572,59 +900,44 @@ impl eframe::App for RedskyApp {
                             egui::Layout::left_to_right(egui::Align::TOP).with_main_justify(true),
                             |ui| {
                                 ui.vertical(|ui| {
-                                    crate::app::show_autoscroll_area(ui, "notifications_scroll", false, |ui| {
-                                        for notif in &self.notifications {
-                                            ui.horizontal(|ui| {
-                                                if !notif.author_avatar.is_empty() {
-                                                    if let Some(texture) = self
-                                                        .image_cache
-                                                        .get(&notif.author_avatar)
-                                                        .unwrap_or(&None)
-                                                    {
-                                                        ui.add(
-                                                            egui::Image::new(texture)
-                                                                .max_width(24.0)
-                                                                .max_height(24.0),
-                                                        );
-                                                    } else {
-                                                        ui.spinner();
-                                                    }
-                                                }
-                                                let action_text = match notif.reason.as_str() {
-                                                    "like" => "liked your post",
-                                                    "repost" => "reposted your post",
-                                                    "follow" => "followed you",
-                                                    "mention" => "mentioned you",
-                                                    "reply" => "replied to your post",
-                                                    "quote" => "quoted your post",
-                                                    _ => &notif.reason,
-                                                };
-                                                ui.label(
-                                                    RichText::new(format!(
-                                                        "@{} {}",
-                                                        notif.author, action_text
-                                                    ))
-                                                    .strong(),
-                                                );
-                                                if !notif.is_read {
-                                                    ui.label(
-                                                        RichText::new("🔴 Unread")
-                                                            .color(egui::Color32::RED),
-                                                    );
-                                                }
-                                            });
-                                            ui.separator();
-                                        }
-                                    });
-                                    if ui.button("Refresh notifications").clicked() {
-                                        self.post_message(BskyActorMsg::GetNotifications { cursor: None });
-                                    }
-                                    if let Some(cursor) = self.notifications_cursor.clone() {
-                                        if ui.button("Load More").clicked() {
-                                            self.post_message(BskyActorMsg::GetNotifications { cursor: Some(cursor) });
-                                            self.notifications_cursor = None;
-                                        }
-                                    }
+                                    self.make_notifications_view(ui);
+                                });
+                            },
+                        );
+                    }
+                    MainViewState::Messages => {
+                        ui.with_layout(
+                            egui::Layout::left_to_right(egui::Align::TOP).with_main_justify(true),
+                            |ui| {
+                                ui.vertical(|ui| {
+                                    self.make_messages_view(ui);
+                                });
+                            },
+                        );
+                    }
+                    MainViewState::SavedSearch(query) => {
+                        ui.with_layout(
+                            egui::Layout::left_to_right(egui::Align::TOP).with_main_justify(true),
+                            |ui| {
+                                ui.vertical(|ui| {
+                                    self.make_saved_search_view(ui, &query);
+                                });
+                            },
+                        );
+                    }
+                    MainViewState::CustomFeed(feed_uri) => {
+                        if !self.custom_feeds.contains_key(&feed_uri) {
+                            self.custom_feeds.insert(feed_uri.clone(), Vec::new());
+                            self.post_message(BskyActorMsg::LoadCachedFeed { feed: feed_uri.clone() });
+                            self.restore_reading_position(&feed_uri);
+                        }
+                        ui.with_layout(
+                            egui::Layout::left_to_right(egui::Align::TOP).with_main_justify(true),
+                            |ui| {
+                                ui.vertical(|ui| {
+                                    let mut items = self.custom_feeds.remove(&feed_uri).unwrap_or_default();
+                                    self.make_post_view(ui, &feed_uri, &mut items);
+                                    self.custom_feeds.insert(feed_uri.clone(), items);
                                 });
                             },
                         );
@@ -640,6 +953,8 @@ impl eframe::App for RedskyApp {
                 }
             })
         });
+        // Only the post views of the main window take the post shortcuts
+        self.post_shortcut = None;
     }
 }
 include!("ui_post.rs");
@@ -648,3 +963,626 @@ include!("ui_thread.rs");
 include!("ui_widgets.rs");
 include!("msg_handler.rs");
 include!("ui_settings.rs");
+include!("ui_moderation.rs");
+include!("ui_feeds.rs");
+include!("ui_lists.rs");
+include!("ui_notifications.rs");
+include!("ui_messages.rs");
+include!("ui_saved_searches.rs");
+include!("ui_shortcuts.rs");
+include!("ui_palette.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A logged in app, with a channel standing in for the actor.
+    fn logged_in_app() -> (RedskyApp, Receiver<BskyActorMsg>) {
+        let (tx, actor_rx) = std::sync::mpsc::channel();
+        let (ui_tx, rx) = std::sync::mpsc::channel();
+        let mut app = RedskyApp::new(tx, ui_tx, rx, false, None);
+        app.is_logged_in = true;
+        app.main_view_state = MainViewState::TimelineFeed;
+        actor_rx.try_iter().for_each(drop); // the login of a remembered account
+        (app, actor_rx)
+    }
+
+    /// Handles the message and what it posts back to the UI, like a frame does.
+    fn process(app: &mut RedskyApp, msg: RedskyUiMsg) {
+        let ctx = egui::Context::default();
+        app.process_message(&ctx, msg);
+        while let Ok(msg) = app.rx.try_recv() {
+            app.process_message(&ctx, msg);
+        }
+    }
+
+    fn post(uri: &str) -> Post {
+        Post {
+            uri: uri.to_string(),
+            cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
+            content: String::new(),
+            author: "alice.bsky.social".to_string(),
+            author_did: "did:plc:alice".to_string(),
+            display_name: String::new(),
+            avatar_img: String::new(),
+            date: String::new(),
+            like_count: 0,
+            repost_count: 0,
+            embeds: vec![],
+            quoted_post: None,
+            is_reply: false,
+            viewer_like: None,
+            viewer_repost: None,
+            bookmarked: false,
+            author_muted: false,
+            author_blocked: false,
+            labels: vec![],
+            thread_root: None,
+            tags: vec![],
+            raw_json: String::new(),
+        }
+    }
+
+    fn uris(items: &[FeedItem]) -> Vec<&str> {
+        items.iter().filter_map(FeedItem::uri).collect()
+    }
+
+    fn bookmarked(app: &RedskyApp, uri: &str) -> bool {
+        app.find_post(uri).is_some_and(|post| post.bookmarked)
+    }
+
+    #[test]
+    fn test_list_membership_add_and_remove() {
+        let (mut app, actor_rx) = logged_in_app();
+        let list = UserList {
+            uri: "at://did:plc:me/app.bsky.graph.list/friends".to_string(),
+            name: "Friends".to_string(),
+            item_count: 3,
+            ..Default::default()
+        };
+        app.own_lists = vec![list.clone()];
+        process(&mut app, RedskyUiMsg::NotifyListMembershipsLoaded {
+            did: "did:plc:bob".to_string(),
+            memberships: vec![ListMembership { list: list.clone(), item_uri: None }],
+        });
+        let membership = |app: &RedskyApp| app.list_memberships["did:plc:bob"].as_ref().unwrap()[0].clone();
+        let toggle = |item_uri: Option<&str>| RedskyUiMsg::PrepareListMemberAction {
+            list_uri: list.uri.clone(),
+            did: "did:plc:bob".to_string(),
+            item_uri: item_uri.map(str::to_string),
+        };
+
+        // Adding shows as pending, which keeps the checkbox disabled, until a failure resets it
+        process(&mut app, toggle(None));
+        assert_eq!(membership(&app).item_uri, Some(RecordState::Pending));
+        assert_eq!(app.own_lists[0].item_count, 4);
+        assert!(matches!(actor_rx.try_recv(), Ok(BskyActorMsg::AddListMember { did, .. }) if did == "did:plc:bob"));
+        process(&mut app, RedskyUiMsg::AddListMemberFailed {
+            list_uri: list.uri.clone(),
+            did: "did:plc:bob".to_string(),
+            error: "offline".to_string(),
+        });
+        assert_eq!(membership(&app).item_uri, None);
+        assert_eq!(app.own_lists[0].item_count, 3);
+
+        // Once added, unchecking deletes the list item
+        process(&mut app, toggle(None));
+        process(&mut app, RedskyUiMsg::NotifyListMemberAdded {
+            list_uri: list.uri.clone(),
+            did: "did:plc:bob".to_string(),
+            item_uri: "at://did:plc:me/app.bsky.graph.listitem/1".to_string(),
+        });
+        let item_uri = "at://did:plc:me/app.bsky.graph.listitem/1".to_string();
+        assert_eq!(membership(&app).item_uri, Some(RecordState::Created(item_uri.clone())));
+        actor_rx.try_iter().for_each(drop);
+        process(&mut app, toggle(Some(&item_uri)));
+        assert_eq!(membership(&app).item_uri, None);
+        assert_eq!(app.own_lists[0].item_count, 3);
+        assert_eq!(actor_rx.try_recv(), Ok(BskyActorMsg::RemoveListMember { item_uri }));
+    }
+
+    #[test]
+    fn test_bookmark_toggle_and_rollback() {
+        let (mut app, actor_rx) = logged_in_app();
+        app.timeline = into_feed_items(vec![post("at://a")]);
+        app.bookmarks = into_feed_items(["at://b", "at://c"].map(|uri| Post { bookmarked: true, ..post(uri) }));
+        let toggle = |uri: &str, unbookmark| RedskyUiMsg::PrepareBookmarkAction {
+            post_uri: uri.to_string(),
+            post_cid: post(uri).cid,
+            unbookmark,
+        };
+        let failed = |uri: &str, bookmarked| RedskyUiMsg::BookmarkActionFailed {
+            post_uri: uri.to_string(),
+            bookmarked,
+            error: "offline".to_string(),
+        };
+
+        // Bookmarking shows right away and is taken back when createBookmark fails
+        process(&mut app, toggle("at://a", false));
+        assert_eq!(uris(&app.bookmarks), ["at://a", "at://b", "at://c"]);
+        assert!(bookmarked(&app, "at://a"));
+        assert!(matches!(actor_rx.try_recv(), Ok(BskyActorMsg::Bookmark { post_ref }) if post_ref.uri == "at://a"));
+        process(&mut app, failed("at://a", false));
+        assert_eq!(uris(&app.bookmarks), ["at://b", "at://c"]);
+        assert!(!bookmarked(&app, "at://a"));
+
+        // A failed deleteBookmark puts the post back in its place
+        process(&mut app, toggle("at://b", true));
+        assert_eq!(uris(&app.bookmarks), ["at://c"]);
+        assert!(matches!(actor_rx.try_recv(), Ok(BskyActorMsg::Unbookmark { post_uri }) if post_uri == "at://b"));
+        process(&mut app, failed("at://b", true));
+        assert_eq!(uris(&app.bookmarks), ["at://b", "at://c"]);
+        assert!(bookmarked(&app, "at://b"));
+
+        // Once the delete succeeds there is nothing left to restore
+        process(&mut app, toggle("at://c", true));
+        process(
+            &mut app,
+            RedskyUiMsg::NotifyBookmarkActionSucceeded {
+                post_uri: "at://c".to_string(),
+                bookmarked: false,
+            },
+        );
+        assert_eq!(uris(&app.bookmarks), ["at://b"]);
+        assert!(app.removed_bookmarks.is_empty());
+    }
+
+    #[test]
+    fn test_follow_unfollow_and_rollback() {
+        let (mut app, actor_rx) = logged_in_app();
+        let bob = "did:plc:bob".to_string();
+        let follow_uri = "at://did:plc:me/app.bsky.graph.follow/1".to_string();
+        app.user_infos_cache.insert(
+            "bob.bsky.social".to_string(),
+            UserProfile {
+                did: bob.clone(),
+                handle: "bob.bsky.social".to_string(),
+                follower_count: 10,
+                ..Default::default()
+            },
+        );
+        let profile = |app: &RedskyApp| {
+            let profile = &app.user_infos_cache["bob.bsky.social"];
+            (profile.viewer_following.clone(), profile.follower_count)
+        };
+        let toggle = |unfollow| RedskyUiMsg::PrepareFollowAction { did: bob.clone(), unfollow };
+        let failed = |follow_uri: Option<&String>| RedskyUiMsg::FollowActionFailed {
+            did: bob.clone(),
+            follow_uri: follow_uri.cloned(),
+            error: "offline".to_string(),
+        };
+
+        // Following shows right away, an unfollow click has nothing to delete until the record exists
+        process(&mut app, toggle(false));
+        assert_eq!(profile(&app), (Some(RecordState::Pending), 11));
+        assert_eq!(actor_rx.try_recv(), Ok(BskyActorMsg::Follow { did: bob.clone() }));
+        process(&mut app, toggle(true));
+        assert_eq!(profile(&app), (Some(RecordState::Pending), 11));
+        assert!(actor_rx.try_recv().is_err());
+        let created = RedskyUiMsg::NotifyFollowActionSucceeded { did: bob.clone(), follow_uri: follow_uri.clone() };
+        process(&mut app, created);
+        assert_eq!(profile(&app), (Some(RecordState::Created(follow_uri.clone())), 11));
+
+        // A failed unfollow brings the follow record and the count back
+        process(&mut app, toggle(true));
+        assert_eq!(profile(&app), (None, 10));
+        assert_eq!(
+            actor_rx.try_recv(),
+            Ok(BskyActorMsg::Unfollow { did: bob.clone(), follow_record_uri: follow_uri.clone() })
+        );
+        process(&mut app, failed(Some(&follow_uri)));
+        assert_eq!(profile(&app), (Some(RecordState::Created(follow_uri.clone())), 11));
+
+        // A failed follow takes it back again
+        process(&mut app, toggle(true));
+        process(&mut app, toggle(false));
+        assert_eq!(profile(&app), (Some(RecordState::Pending), 11));
+        process(&mut app, failed(None));
+        assert_eq!(profile(&app), (None, 10));
+    }
+
+    #[test]
+    fn test_follower_and_follow_paging() {
+        let (mut app, _actor_rx) = logged_in_app();
+        let page = |kind, handles: &[&str], cursor: Option<&str>, append| RedskyUiMsg::NotifyUserListLoaded {
+            username: "alice.bsky.social".to_string(),
+            kind,
+            users: handles
+                .iter()
+                .map(|handle| UserProfile {
+                    handle: handle.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            cursor: cursor.map(str::to_string),
+            append,
+        };
+        let list = |app: &RedskyApp, kind| {
+            let (users, cursor) = &app.user_lists_cache[&("alice.bsky.social".to_string(), kind)];
+            let handles: Vec<&str> = users.iter().map(|user| user.handle.as_str()).collect();
+            (handles.join(" "), cursor.clone())
+        };
+
+        // Load More appends the next page and keeps its cursor, followers and follows page separately
+        process(&mut app, page(UserListKind::Followers, &["bob"], Some("c1"), false));
+        process(&mut app, page(UserListKind::Follows, &["carol"], None, false));
+        process(&mut app, page(UserListKind::Followers, &["dave"], Some("c2"), true));
+        assert_eq!(list(&app, UserListKind::Followers), ("bob dave".to_string(), Some("c2".to_string())));
+        assert_eq!(list(&app, UserListKind::Follows), ("carol".to_string(), None));
+
+        // A page for a closed window is dropped, opening the list again starts over
+        process(&mut app, RedskyUiMsg::CloseUserListView {
+            username: "alice.bsky.social".to_string(),
+            kind: UserListKind::Followers,
+        });
+        process(&mut app, page(UserListKind::Followers, &["erin"], None, true));
+        assert!(!app.user_lists_cache.contains_key(&("alice.bsky.social".to_string(), UserListKind::Followers)));
+        process(&mut app, page(UserListKind::Followers, &["bob"], Some("c1"), false));
+        assert_eq!(list(&app, UserListKind::Followers), ("bob".to_string(), Some("c1".to_string())));
+    }
+
+    #[test]
+    fn test_block_and_mute_filter_feed_posts() {
+        let (mut app, actor_rx) = logged_in_app();
+        let bob = "did:plc:bob".to_string();
+        let bob_post = Post {
+            author: "bob.bsky.social".to_string(),
+            author_did: bob.clone(),
+            ..post("at://did:plc:bob/app.bsky.feed.post/1")
+        };
+        let alice_post = post("at://did:plc:alice/app.bsky.feed.post/1");
+
+        // Muting hides the author's posts behind a stub right away, a failed mute shows them again
+        process(&mut app, RedskyUiMsg::PrepareMuteAction { did: bob.clone(), unmute: false });
+        assert_eq!(app.post_hidden_reason(&bob_post), Some("muted account @bob.bsky.social".to_string()));
+        assert_eq!(app.post_hidden_reason(&alice_post), None);
+        assert_eq!(actor_rx.try_recv(), Ok(BskyActorMsg::Mute { did: bob.clone() }));
+        let failed = RedskyUiMsg::MuteActionFailed { did: bob.clone(), muted: false, error: "offline".to_string() };
+        process(&mut app, failed);
+        assert_eq!(app.post_hidden_reason(&bob_post), None);
+
+        // Unmuting overrides what the post was loaded with, until the unmute fails
+        let carol = "did:plc:carol".to_string();
+        let muted_post = Post {
+            author_did: carol.clone(),
+            author_muted: true,
+            ..post("at://did:plc:carol/app.bsky.feed.post/1")
+        };
+        assert!(app.post_hidden_reason(&muted_post).is_some());
+        process(&mut app, RedskyUiMsg::PrepareMuteAction { did: carol.clone(), unmute: true });
+        assert_eq!(app.post_hidden_reason(&muted_post), None);
+        assert_eq!(actor_rx.try_recv(), Ok(BskyActorMsg::Unmute { did: carol.clone() }));
+        let failed = RedskyUiMsg::MuteActionFailed { did: carol, muted: true, error: "offline".to_string() };
+        process(&mut app, failed);
+        assert!(app.post_hidden_reason(&muted_post).is_some());
+
+        // Blocked authors drop out of the feed, a failed block brings them back
+        process(&mut app, RedskyUiMsg::PrepareBlockAction { did: bob.clone(), unblock: false });
+        assert!(app.is_author_blocked(&bob_post) && !app.is_author_blocked(&alice_post));
+        assert_eq!(actor_rx.try_recv(), Ok(BskyActorMsg::Block { did: bob.clone() }));
+        let failed = RedskyUiMsg::BlockActionFailed { did: bob.clone(), block_uri: None, error: "offline".to_string() };
+        process(&mut app, failed);
+        assert!(!app.is_author_blocked(&bob_post));
+    }
+
+    #[test]
+    fn test_saved_feed_pin_and_unpin() {
+        let (mut app, actor_rx) = logged_in_app();
+        let cats = "at://did:plc:bob/app.bsky.feed.generator/cats";
+        let dogs = "at://did:plc:bob/app.bsky.feed.generator/dogs";
+        let saved_feed = |id: &str, kind: &str, uri: &str, pinned| SavedFeed {
+            id: id.to_string(),
+            kind: kind.to_string(),
+            uri: uri.to_string(),
+            pinned,
+        };
+        process(&mut app, RedskyUiMsg::NotifySavedFeedsLoaded {
+            saved: vec![saved_feed("1", "timeline", "following", true), saved_feed("2", "feed", cats, true)],
+            generators: vec![],
+        });
+        let put_feeds = |actor_rx: &Receiver<BskyActorMsg>| match actor_rx.try_recv() {
+            Ok(BskyActorMsg::PutSavedFeeds { saved }) => saved,
+            other => panic!("expected the saved feeds to be written back, got {:?}", other),
+        };
+
+        // Unpinning keeps the feed saved, the whole list goes back to the preferences
+        app.save_feed_uri(cats, "feed", false);
+        assert!(app.is_feed_saved(cats) && !app.is_feed_pinned(cats));
+        assert_eq!(put_feeds(&actor_rx), app.saved_feeds);
+        assert_eq!(app.saved_feeds.len(), 2);
+
+        // Pinning a feed that is not saved yet adds it at the end
+        app.save_feed_uri(dogs, "feed", true);
+        assert!(app.is_feed_pinned(dogs));
+        let saved = put_feeds(&actor_rx);
+        assert_eq!(saved.iter().map(|feed| feed.uri.as_str()).collect::<Vec<_>>(), ["following", cats, dogs]);
+
+        app.save_feed_uri(cats, "feed", true);
+        assert!(app.is_feed_pinned(cats));
+        assert_eq!(put_feeds(&actor_rx).len(), 3);
+        app.unsave_feed(cats);
+        assert!(!app.is_feed_saved(cats));
+        assert_eq!(put_feeds(&actor_rx), app.saved_feeds);
+    }
+
+    #[test]
+    fn test_merge_feed_head_and_fill_gap() {
+        let post = |rkey: &str| post(&format!("at://did:plc:alice/app.bsky.feed.post/{}", rkey));
+        let rkeys = |items: &[FeedItem]| -> Vec<String> {
+            items
+                .iter()
+                .map(|item| match item {
+                    FeedItem::Gap { cursor, .. } => format!("gap:{}", cursor),
+                    _ => item.uri().unwrap().rsplit('/').next().unwrap().to_string(),
+                })
+                .collect()
+        };
+
+        let mut items = into_feed_items(vec![post("c"), post("d")]);
+        // The refreshed page overlaps what is loaded: only newer posts get prepended
+        let added = merge_feed_head(&mut items, vec![post("b"), post("c"), post("d")], Some("1".to_string()));
+        assert_eq!(added, 1);
+        assert_eq!(rkeys(&items), ["b", "c", "d"]);
+
+        // No overlap: a gap marker sits between the new head and the older posts
+        let added = merge_feed_head(&mut items, vec![post("z"), post("y")], Some("2".to_string()));
+        assert_eq!(added, 2);
+        assert_eq!(rkeys(&items), ["z", "y", "gap:2", "b", "c", "d"]);
+
+        // The gap page still does not reach the loaded posts, so the gap moves down
+        fill_feed_gap(&mut items, "2", vec![post("x")], Some("3".to_string()));
+        assert_eq!(rkeys(&items), ["z", "y", "x", "gap:3", "b", "c", "d"]);
+
+        fill_feed_gap(&mut items, "3", vec![post("w"), post("b")], Some("4".to_string()));
+        assert_eq!(rkeys(&items), ["z", "y", "x", "w", "b", "c", "d"]);
+
+        // A repost of a loaded post on top of the page does not hide the newer posts below it
+        let added = merge_feed_head(&mut items, vec![post("c"), post("v"), post("z")], Some("5".to_string()));
+        assert_eq!(added, 1);
+        assert_eq!(rkeys(&items), ["v", "z", "y", "x", "w", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_reading_positions_round_trip() {
+        let mut positions = ReadingPositions::default();
+        positions.feeds.insert(
+            "Your timeline".to_string(),
+            ReadingPosition {
+                uri: "at://did:plc:alice/app.bsky.feed.post/1".to_string(),
+                cursor: Some("2024-01-01T00:00:00.000Z".to_string()),
+            },
+        );
+        positions.feeds.insert(
+            "at://did:plc:bob/app.bsky.feed.generator/cats".to_string(),
+            ReadingPosition {
+                uri: "at://did:plc:carol/app.bsky.feed.post/2".to_string(),
+                cursor: None,
+            },
+        );
+        let contents = toml::to_string(&positions).unwrap();
+        let loaded: ReadingPositions = toml::from_str(&contents).unwrap();
+        assert_eq!(loaded.feeds, positions.feeds);
+        let empty: ReadingPositions = toml::from_str("").unwrap();
+        assert!(empty.feeds.is_empty());
+    }
+
+    #[test]
+    fn test_reading_position_restore_paging() {
+        let uri = |rkey: &str| format!("at://did:plc:alice/app.bsky.feed.post/{}", rkey);
+        let position = ReadingPosition {
+            uri: uri("saved"),
+            cursor: Some("1".to_string()),
+        };
+        let mut restore = ReadingRestore {
+            position: position.clone(),
+            pages: 0,
+        };
+        let mut items = into_feed_items(vec![post(&uri("a"))]);
+        assert_eq!(restore.page_loaded(&items, Some("2".to_string())), RestoreStep::LoadPage("2".to_string()));
+        items.extend(into_feed_items(vec![post(&uri("saved"))]));
+        assert_eq!(restore.page_loaded(&items, Some("3".to_string())), RestoreStep::Found);
+
+        // The end of the feed, or too many pages without the post, stop the restore
+        let mut restore = ReadingRestore { position, pages: 0 };
+        let items = into_feed_items(vec![post(&uri("a"))]);
+        assert_eq!(restore.page_loaded(&items, None), RestoreStep::GiveUp);
+        let mut steps = Vec::new();
+        while let RestoreStep::LoadPage(cursor) = restore.page_loaded(&items, Some("next".to_string())) {
+            steps.push(cursor);
+        }
+        assert_eq!(steps.len(), 8);
+        assert_eq!(restore.pages, 10, "gives up after MAX_RESTORE_PAGES");
+    }
+
+    #[test]
+    fn test_post_search_inline_operators() {
+        let mut search = PostSearch {
+            query: "from:@alice.bsky.social since:2026-01-01 rust tag:#egui sort:latest https://example.com".to_string(),
+            tags: "gui".to_string(),
+            ..Default::default()
+        };
+        search.apply_inline_operators();
+        assert_eq!(search.query, "rust https://example.com");
+        assert_eq!(search.author, "alice.bsky.social");
+        assert_eq!(search.since, "2026-01-01");
+        assert_eq!(search.sort, PostSearchSort::Latest);
+        assert_eq!(search.tag_list(), ["gui", "egui"]);
+
+        // Saved searches keep the filters in a single line and always sort by latest
+        let line = search.to_inline_query();
+        assert_eq!(line, "rust https://example.com from:alice.bsky.social since:2026-01-01 tag:gui tag:egui sort:latest");
+        let saved = PostSearch::saved("tag:rust", "did:plc:me");
+        assert_eq!(saved.sort, PostSearchSort::Latest);
+        assert_eq!(saved.tag_list(), ["rust"]);
+        assert_eq!(PostSearch::saved(&line, "did:plc:me"), search);
+
+        // Both the search tab and saved searches map `me` to the logged in account
+        let saved = PostSearch::saved("rust from:me mentions:@me", "did:plc:me");
+        assert_eq!(saved.author, "did:plc:me");
+        assert_eq!(saved.mentions, "did:plc:me");
+        let mut search = PostSearch {
+            query: "from:me".to_string(),
+            ..Default::default()
+        };
+        search.apply_inline_operators();
+        search.resolve_own_account("did:plc:me");
+        assert_eq!(search.author, "did:plc:me");
+    }
+
+    #[test]
+    fn test_user_tabs_load_more_and_cached_profiles() {
+        let uri = |rkey: &str| format!("at://did:plc:alice/app.bsky.feed.post/{}", rkey);
+        let tab_uris = |tabs: &UserTabFeeds, view| -> Vec<String> {
+            tabs.posts[&("alice".to_string(), view)]
+                .iter()
+                .flatten()
+                .filter_map(|item| item.uri().map(|uri| uri.to_string()))
+                .collect()
+        };
+        let load = |tabs: &mut UserTabFeeds, view, rkey: &str, cursor: Option<&str>, append| {
+            let items = into_feed_items(vec![post(&uri(rkey))]);
+            tabs.page_loaded("alice".to_string(), view, items, cursor.map(str::to_string), append);
+        };
+        let mut tabs = UserTabFeeds::default();
+        load(&mut tabs, UserViewState::Media, "a", Some("c1"), false);
+        load(&mut tabs, UserViewState::Videos, "v", None, false);
+        assert!(tabs.has_more("alice", UserViewState::Media));
+        assert!(!tabs.has_more("alice", UserViewState::Videos));
+
+        // Load More takes the cursor, a second click sends nothing until the page is in
+        assert_eq!(tabs.take_cursor("alice", UserViewState::Media), Some("c1".to_string()));
+        assert_eq!(tabs.take_cursor("alice", UserViewState::Media), None);
+        load(&mut tabs, UserViewState::Media, "b", None, true);
+        assert_eq!(tab_uris(&tabs, UserViewState::Media), [uri("a"), uri("b")]);
+        assert!(!tabs.has_more("alice", UserViewState::Media));
+        assert_eq!(tab_uris(&tabs, UserViewState::Videos), [uri("v")]);
+
+        // A refresh starts the tab over, dropping the user forgets all tabs
+        load(&mut tabs, UserViewState::Media, "c", None, false);
+        assert_eq!(tab_uris(&tabs, UserViewState::Media), [uri("c")]);
+        tabs.drop_user("alice");
+        assert!(tabs.posts.is_empty() && !tabs.has_more("alice", UserViewState::Media));
+
+        // Profiles cached before banners, labels and pinned posts still load
+        let cached = r#"{"did":"did:plc:alice","handle":"alice.bsky.social","display_name":"Alice",
+            "bio":"","avatar_uri":"","follower_count":1,"follow_count":2,"post_count":3,
+            "viewer_following":null,"viewer_followed_by":true,"viewer_blocking":null,"viewer_muted":false}"#;
+        let profile: UserProfile = serde_json::from_str(cached).unwrap();
+        assert!(profile.viewer_followed_by);
+        assert!(profile.banner_uri.is_empty() && profile.labels.is_empty() && profile.pinned_post.is_none());
+    }
+
+    #[test]
+    fn test_shortcut_sequences_and_conflicts() {
+        let mut settings = AppSettings::default();
+        let mut input = ShortcutInput::default();
+        assert_eq!(input.key_typed(&settings, "j"), Some(ShortcutAction::NextPost));
+        assert_eq!(input.key_typed(&settings, "g"), None);
+        assert_eq!(input.key_typed(&settings, "n"), Some(ShortcutAction::GoToNotifications));
+        // A sequence that leads nowhere starts over with the last key
+        assert_eq!(input.key_typed(&settings, "g"), None);
+        assert_eq!(input.key_typed(&settings, "k"), Some(ShortcutAction::PreviousPost));
+        assert!(settings.shortcut_conflicts().is_empty());
+
+        settings.shortcuts.insert(ShortcutAction::Like, "f".to_string());
+        settings.shortcuts.insert(ShortcutAction::Repost, "g".to_string());
+        assert_eq!(input.key_typed(&settings, "f"), Some(ShortcutAction::Like));
+        assert_eq!(input.key_typed(&settings, "l"), None);
+        let conflicts = settings.shortcut_conflicts();
+        assert!(conflicts.contains(&ShortcutAction::Repost) && conflicts.contains(&ShortcutAction::GoToTimeline));
+        assert!(!conflicts.contains(&ShortcutAction::Like));
+
+        let saved: AppSettings = toml::from_str(&toml::to_string(&settings).unwrap()).unwrap();
+        assert_eq!(saved.shortcut_keys(ShortcutAction::Like), "f");
+    }
+
+    #[test]
+    fn test_command_palette_matching() {
+        assert!(fuzzy_score("np", "New post").is_some());
+        assert!(fuzzy_score("pn", "New post").is_none());
+        // Word starts and consecutive characters rank first
+        assert!(fuzzy_score("set", "Open settings") > fuzzy_score("set", "Search my history"));
+
+        assert_eq!(
+            PaletteCommand::from_query("@alice.bsky.social"),
+            vec![
+                PaletteCommand::OpenProfile("alice.bsky.social".to_string()),
+                PaletteCommand::DownloadMedia("alice.bsky.social".to_string()),
+            ]
+        );
+        assert!(PaletteCommand::from_query("new post").is_empty());
+        assert_eq!(
+            AtUriTarget::parse("at://did:plc:alice/app.bsky.feed.post/3k2"),
+            Some(AtUriTarget::Post("at://did:plc:alice/app.bsky.feed.post/3k2".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse("at://alice.bsky.social"),
+            Some(AtUriTarget::Profile("alice.bsky.social".to_string()))
+        );
+        assert_eq!(AtUriTarget::parse("at://did:plc:alice/app.bsky.feed.like/3k2"), None);
+
+        // Recent commands are kept per account, most recent first and without repeats
+        let mut settings = AppSettings::default();
+        settings.add_recent_command("did:plc:alice", PaletteCommand::NewPost);
+        settings.add_recent_command("did:plc:alice", PaletteCommand::OpenProfile("bob.bsky.social".to_string()));
+        settings.add_recent_command("did:plc:alice", PaletteCommand::NewPost);
+        settings.add_recent_command("did:plc:bob", PaletteCommand::OpenSettings);
+        let saved: AppSettings = toml::from_str(&toml::to_string(&settings).unwrap()).unwrap();
+        assert_eq!(
+            saved.recent_commands("did:plc:alice"),
+            [PaletteCommand::NewPost, PaletteCommand::OpenProfile("bob.bsky.social".to_string())]
+        );
+        assert_eq!(saved.recent_commands("did:plc:bob"), [PaletteCommand::OpenSettings]);
+        assert!(saved.recent_commands("did:plc:carol").is_empty());
+    }
+
+    #[test]
+    fn test_open_link_parsing() {
+        assert_eq!(
+            AtUriTarget::parse_link("https://bsky.app/profile/alice.bsky.social/post/3k2?ref=share"),
+            Some(AtUriTarget::Post("at://alice.bsky.social/app.bsky.feed.post/3k2".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse_link("bsky.app/profile/did:plc:alice/feed/cats"),
+            Some(AtUriTarget::Feed("at://did:plc:alice/app.bsky.feed.generator/cats".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse_link("https://www.bsky.app/profile/alice.bsky.social/lists/3k2"),
+            Some(AtUriTarget::List("at://alice.bsky.social/app.bsky.graph.list/3k2".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse_link("https://bsky.app/profile/alice.bsky.social/"),
+            Some(AtUriTarget::Profile("alice.bsky.social".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse_link(" @alice.bsky.social "),
+            Some(AtUriTarget::Profile("alice.bsky.social".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse_link("did:plc:alice"),
+            Some(AtUriTarget::Profile("did:plc:alice".to_string()))
+        );
+        assert_eq!(
+            AtUriTarget::parse_link("at://did:plc:alice/app.bsky.feed.post/3k2"),
+            Some(AtUriTarget::Post("at://did:plc:alice/app.bsky.feed.post/3k2".to_string()))
+        );
+        assert_eq!(AtUriTarget::parse_link("https://example.com/profile/alice.bsky.social"), None);
+        assert_eq!(AtUriTarget::parse_link("https://bsky.app/search?q=cats"), None);
+        assert_eq!(AtUriTarget::parse_link("not a link"), None);
+
+        // Launch arguments and handed over links need a scheme, a file name is not a handle
+        assert_eq!(
+            AtUriTarget::parse_url("https://bsky.app/profile/alice.bsky.social"),
+            Some(AtUriTarget::Profile("alice.bsky.social".to_string()))
+        );
+        assert!(AtUriTarget::parse_url("at://did:plc:alice").is_some());
+        assert_eq!(AtUriTarget::parse_url("notes.txt"), None);
+        assert_eq!(AtUriTarget::parse_url("bsky.app/profile/alice.bsky.social"), None);
+        assert_eq!(AtUriTarget::parse_url("https://example.com"), None);
+
+        assert_eq!(
+            PaletteCommand::from_query("https://bsky.app/profile/alice.bsky.social"),
+            vec![PaletteCommand::OpenAtUri("https://bsky.app/profile/alice.bsky.social".to_string())]
+        );
+    }
+}
@@ -1,5 +1,17 @@
 impl RedskyApp {
     fn process_message(&mut self, ctx: &egui::Context, msg: RedskyUiMsg) {
+        if self.awaiting_login
+            && !matches!(
+                msg,
+                RedskyUiMsg::NotifySessionLoaded { .. }
+                    | RedskyUiMsg::LogInSucceededMsg()
+                    | RedskyUiMsg::ShowErrorMsg { .. }
+                    | RedskyUiMsg::OpenForwardedLink { .. }
+            )
+        {
+            // Late answers to requests of the account we switched away from
+            return;
+        }
         match msg {
             RedskyUiMsg::ActionSucceeded() => {
                 self.post_message(BskyActorMsg::GetTimeline { cursor: None });
@@ -8,24 +20,269 @@ impl RedskyApp {
                 posts,
                 cursor,
                 append,
+                hidden,
             } => {
                 self.request_post_images(&posts);
-                let new_items = crate::app::into_feed_items(posts);
+                if self.cached_feeds.remove("Your timeline") {
+                    self.timeline.clear();
+                }
+                self.timeline_hidden.extend(hidden);
+                let request_cursor = if append { self.feed_request_cursors.remove("Your timeline") } else { None };
+                self.record_feed_page("Your timeline", &posts, request_cursor);
                 if append {
-                    self.timeline.extend(new_items);
+                    self.timeline.extend(crate::app::into_feed_items(posts));
+                    self.timeline_cursor = cursor;
+                } else if self.timeline.is_empty() {
+                    self.timeline = crate::app::into_feed_items(posts);
+                    self.timeline_cursor = cursor;
                 } else {
-                    self.timeline = new_items;
+                    // Refresh: keep what is loaded and only prepend the newer posts
+                    let added = crate::app::merge_feed_head(&mut self.timeline, posts, cursor);
+                    if added > 0 {
+                        self.anchor_feed_scroll("Your timeline");
+                    }
+                    self.pending_timeline_posts
+                        .retain(|post| !self.timeline.iter().any(|item| item.uri() == Some(post.uri.as_str())));
                 }
-                self.timeline_cursor = cursor;
+                self.continue_reading_restore("Your timeline");
             }
-            RedskyUiMsg::RefreshBookmarksMsg { posts, cursor, append } => {
+            RedskyUiMsg::FillTimelineGapMsg {
+                gap_cursor,
+                posts,
+                cursor,
+                hidden,
+            } => {
+                self.request_post_images(&posts);
+                self.timeline_hidden.extend(hidden);
+                self.record_feed_page("Your timeline", &posts, Some(gap_cursor.clone()));
+                crate::app::fill_feed_gap(&mut self.timeline, &gap_cursor, posts, cursor);
+            }
+            RedskyUiMsg::FeedPageFailed { feed, cursor } => {
+                // Stop restoring the reading position, the feed tracks it again from here
+                self.restoring_feeds.remove(&feed);
+                // request_feed_page took the cursor out, bring "Load more" back
+                if cursor.is_some() {
+                    if feed == "Your timeline" {
+                        self.timeline_cursor = cursor;
+                    } else {
+                        self.custom_feed_cursors.insert(feed, cursor);
+                    }
+                }
+            }
+            RedskyUiMsg::TimelineGapFailed { gap_cursor, error } => {
+                print!("error: {}", error);
+                // Let the gap be retried
+                for item in &mut self.timeline {
+                    if let FeedItem::Gap { cursor, loading } = item {
+                        if *cursor == gap_cursor {
+                            *loading = false;
+                        }
+                    }
+                }
+            }
+            RedskyUiMsg::RefreshCustomFeedMsg {
+                feed_uri,
+                posts,
+                cursor,
+                append,
+            } => {
                 self.request_post_images(&posts);
+                let request_cursor = if append { self.feed_request_cursors.remove(&feed_uri) } else { None };
+                self.record_feed_page(&feed_uri, &posts, request_cursor);
+                let items = self.custom_feeds.entry(feed_uri.clone()).or_default();
+                if self.cached_feeds.remove(&feed_uri) {
+                    items.clear();
+                }
+                if self.restored_feed_heads.remove(&feed_uri) && !append {
+                    // Custom feeds are not chronological, the newer posts go above the restored ones without a gap
+                    if crate::app::merge_feed_head(items, posts, None) > 0 {
+                        self.anchor_feed_scroll(&feed_uri);
+                    }
+                } else {
+                    let new_items = crate::app::into_feed_items(posts);
+                    if append {
+                        items.extend(new_items);
+                    } else {
+                        *items = new_items;
+                    }
+                    self.custom_feed_cursors.insert(feed_uri.clone(), cursor);
+                }
+                self.continue_reading_restore(&feed_uri);
+            }
+            RedskyUiMsg::NotifyListsLoaded { lists, cursor, append } => {
+                for list in &lists {
+                    self.request_image(&list.avatar_uri);
+                }
                 if append {
-                    self.bookmarks.extend(posts);
+                    self.own_lists.extend(lists);
+                } else {
+                    self.own_lists = lists;
+                }
+                self.own_lists_cursor = cursor;
+            }
+            RedskyUiMsg::NotifyListCreated { list } => {
+                self.selected_list = Some(list.uri.clone());
+                self.list_members_cache
+                    .insert(list.uri.clone(), (list.clone(), Vec::new(), None));
+                self.own_lists.insert(0, list);
+            }
+            RedskyUiMsg::NotifyListMembersLoaded {
+                list,
+                members,
+                cursor,
+                append,
+            } => {
+                for member in &members {
+                    self.request_image(&member.profile.avatar_uri);
+                }
+                let entry = self
+                    .list_members_cache
+                    .entry(list.uri.clone())
+                    .or_insert_with(|| (list.clone(), Vec::new(), None));
+                entry.0 = list;
+                if append {
+                    entry.1.extend(members);
+                } else {
+                    entry.1 = members;
+                }
+                entry.2 = cursor;
+            }
+            RedskyUiMsg::PrepareListMemberships { did } => {
+                if !self.list_memberships.contains_key(&did) {
+                    self.list_memberships.insert(did.clone(), None);
+                    self.post_message(BskyActorMsg::GetListMemberships { did });
+                }
+            }
+            RedskyUiMsg::NotifyListMembershipsLoaded { did, memberships } => {
+                self.list_memberships.insert(did, Some(memberships));
+            }
+            RedskyUiMsg::PrepareListMemberAction {
+                list_uri,
+                did,
+                item_uri,
+            } => {
+                if let Some(item_uri) = item_uri {
+                    self.post_message(BskyActorMsg::RemoveListMember {
+                        item_uri: item_uri.clone(),
+                    });
+                    self.update_list_membership(&list_uri, &did, None);
+                    if let Some((_, members, _)) = self.list_members_cache.get_mut(&list_uri) {
+                        members.retain(|member| member.item_uri != item_uri);
+                    }
+                } else {
+                    self.post_message(BskyActorMsg::AddListMember {
+                        list_uri: list_uri.clone(),
+                        did: did.clone(),
+                    });
+                    self.update_list_membership(&list_uri, &did, Some(RecordState::Pending));
+                }
+            }
+            RedskyUiMsg::AddListMemberFailed { list_uri, did, error } => {
+                print!("error: {}", error);
+                self.update_list_membership(&list_uri, &did, None);
+            }
+            RedskyUiMsg::NotifyListMemberAdded {
+                list_uri,
+                did,
+                item_uri,
+            } => {
+                self.update_list_membership(&list_uri, &did, Some(RecordState::Created(item_uri.clone())));
+                if let Some(profile) = self.find_profile(&did) {
+                    if let Some((_, members, _)) = self.list_members_cache.get_mut(&list_uri) {
+                        members.insert(0, ListMember { item_uri, profile });
+                    }
+                }
+            }
+            RedskyUiMsg::NotifySavedFeedsLoaded { saved, generators } => {
+                self.saved_feeds = saved;
+                for generator in generators {
+                    self.request_image(&generator.avatar_uri);
+                    self.feed_generators.insert(generator.uri.clone(), generator);
+                }
+            }
+            RedskyUiMsg::NotifyFeedGeneratorsLoaded {
+                kind,
+                generators,
+                cursor,
+                append,
+            } => {
+                for generator in &generators {
+                    self.request_image(&generator.avatar_uri);
+                }
+                let (feeds, feeds_cursor) = match kind {
+                    FeedDiscoveryKind::Popular => (&mut self.popular_feeds, &mut self.popular_feeds_cursor),
+                    _ => (&mut self.suggested_feeds, &mut self.suggested_feeds_cursor),
+                };
+                if append {
+                    feeds.extend(generators);
+                } else {
+                    *feeds = generators;
+                }
+                *feeds_cursor = cursor;
+            }
+            RedskyUiMsg::RefreshBookmarksMsg { posts, cursor, append } => {
+                self.request_post_images(&posts);
+                let new_items = crate::app::into_feed_items(posts);
+                if append && !self.cached_feeds.remove("Your bookmarks") {
+                    self.bookmarks.extend(new_items);
                 } else {
-                    self.bookmarks = posts;
+                    self.bookmarks = new_items;
                 }
                 self.bookmarks_cursor = cursor;
+                self.cached_feeds.remove("Your bookmarks");
+            }
+            RedskyUiMsg::ShowCachedFeedMsg { feed, posts, cursor } => {
+                let items = match feed.as_str() {
+                    "Your timeline" => &mut self.timeline,
+                    "Your bookmarks" => &mut self.bookmarks,
+                    feed_uri => self.custom_feeds.entry(feed_uri.to_string()).or_default(),
+                };
+                // The network answered first
+                if !items.is_empty() || posts.is_empty() {
+                    return;
+                }
+                *items = crate::app::into_feed_items(posts.clone());
+                match feed.as_str() {
+                    "Your timeline" => self.timeline_cursor = cursor,
+                    "Your bookmarks" => self.bookmarks_cursor = cursor,
+                    feed_uri => {
+                        self.custom_feed_cursors.insert(feed_uri.to_string(), cursor);
+                    }
+                }
+                self.request_post_images(&posts);
+                self.cached_feeds.insert(feed);
+            }
+            RedskyUiMsg::RefreshSavedSearchMsg {
+                query,
+                posts,
+                cursor,
+                append,
+            } => {
+                self.request_post_images(&posts);
+                let items = self.saved_search_posts.entry(query.clone()).or_default();
+                if append || items.is_empty() {
+                    items.extend(crate::app::into_feed_items(posts));
+                    self.saved_search_cursors.insert(query, cursor);
+                } else {
+                    // Auto-refresh: prepend the newer posts, older pages keep their cursor
+                    let added = crate::app::merge_feed_head(items, posts, None);
+                    if added > 0 {
+                        self.anchor_feed_scroll(&format!("search:{}", query));
+                        if self.main_view_state != MainViewState::SavedSearch(query.clone()) {
+                            *self.saved_search_unread.entry(query).or_default() += added;
+                        }
+                    }
+                }
+            }
+            RedskyUiMsg::OpenPostSearch { search } => {
+                self.search_posts = search;
+                self.is_search_posts_window_open = true;
+                self.run_post_search();
+            }
+            RedskyUiMsg::PinSavedSearch { search } => self.pin_saved_search(search),
+            RedskyUiMsg::ShowHistoryResults { posts } => {
+                self.request_post_images(&posts);
+                self.history_results = Some(crate::app::into_feed_items(posts));
             }
             RedskyUiMsg::PrepareUserView { username } => {
                 self.user_posts.insert(username.clone(), None);
@@ -40,14 +297,51 @@ impl RedskyApp {
                 self.user_infos_cache
                     .insert(profile.handle.clone(), profile);
             }
+            RedskyUiMsg::ShowProfileRecord { edit } => {
+                self.profile_edit = Some(edit);
+                self.profile_edit_saving = false;
+                self.profile_edit_error = None;
+            }
+            RedskyUiMsg::ProfileUpdated { profile } => {
+                self.profile_edit = None;
+                self.profile_edit_saving = false;
+                self.request_image(&profile.avatar_uri);
+                self.user_infos_cache.insert(profile.handle.clone(), profile);
+            }
+            RedskyUiMsg::ProfileUpdateFailed { error } => {
+                self.profile_edit_saving = false;
+                self.profile_edit_error = Some(error);
+            }
+            RedskyUiMsg::OpenUserView { username } => self.open_profile(&username),
+            RedskyUiMsg::OpenFeedView { feed_uri } => {
+                self.main_view_state = MainViewState::CustomFeed(feed_uri);
+            }
+            RedskyUiMsg::OpenLinkFailed { link, error } => {
+                self.open_link_input = Some(link);
+                self.open_link_error = Some(error);
+            }
+            RedskyUiMsg::OpenForwardedLink { link } => {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                if self.is_logged_in {
+                    self.open_link(&link);
+                } else {
+                    self.pending_link = Some(link);
+                }
+            }
             RedskyUiMsg::ShowUserPostsMsg {
                 username,
+                view,
                 posts,
                 cursor,
                 append,
             } => {
                 self.request_post_images(&posts);
                 let new_items = crate::app::into_feed_items(posts);
+                if view != UserViewState::Posts {
+                    self.user_tabs.page_loaded(username, view, new_items, cursor, append);
+                    return;
+                }
                 if append {
                     if let Some(Some(existing_posts)) = self.user_posts.get_mut(&username) {
                         existing_posts.extend(new_items);
@@ -86,6 +380,7 @@ impl RedskyApp {
             RedskyUiMsg::DropUserPostsMsg { username } => {
                 self.user_posts.remove(&username);
                 self.user_likes_posts.remove(&username);
+                self.user_tabs.drop_user(&username);
             }
             RedskyUiMsg::ShowErrorMsg { error } => {
                 print!("error: {}", error);
@@ -129,6 +424,30 @@ impl RedskyApp {
                     self.post_reposters_cache.insert(post_uri, (reposters, cursor));
                 }
             }
+            RedskyUiMsg::NotifyUserListLoaded {
+                username,
+                kind,
+                users,
+                cursor,
+                append,
+            } => {
+                for profile in &users {
+                    self.request_image(&profile.avatar_uri);
+                }
+                if append {
+                    if let Some((existing, existing_cursor)) =
+                        self.user_lists_cache.get_mut(&(username.clone(), kind))
+                    {
+                        existing.extend(users);
+                        *existing_cursor = cursor;
+                    }
+                } else {
+                    self.user_lists_cache.insert((username, kind), (users, cursor));
+                }
+            }
+            RedskyUiMsg::CloseUserListView { username, kind } => {
+                self.user_lists_cache.remove(&(username, kind));
+            }
             RedskyUiMsg::CloseLikesView { post_uri } => {
                 self.post_likers_cache.remove(&post_uri);
             }
@@ -201,6 +520,58 @@ impl RedskyApp {
                     });
                 }
             }
+            RedskyUiMsg::PrepareBookmarkAction {
+                post_uri,
+                post_cid,
+                unbookmark,
+            } => {
+                self.update_post_optimistically(&post_uri, |post| {
+                    post.bookmarked = !unbookmark;
+                });
+                if unbookmark {
+                    if let Some(idx) = self.bookmarks.iter().position(|item| item.uri() == Some(post_uri.as_str())) {
+                        let item = self.bookmarks.remove(idx);
+                        self.removed_bookmarks.insert(post_uri.clone(), (idx, item));
+                    }
+                    self.post_message(BskyActorMsg::Unbookmark { post_uri });
+                } else {
+                    if let Some(post) = self.find_post(&post_uri) {
+                        self.bookmarks.insert(0, FeedItem::Full(post, None));
+                    }
+                    self.post_message(BskyActorMsg::Bookmark {
+                        post_ref: StrongRef {
+                            uri: post_uri,
+                            cid: post_cid,
+                        },
+                    });
+                }
+            }
+            RedskyUiMsg::NotifyBookmarkActionSucceeded {
+                post_uri,
+                bookmarked,
+            } => {
+                self.removed_bookmarks.remove(&post_uri);
+                self.update_post_optimistically(&post_uri, |post| {
+                    post.bookmarked = bookmarked;
+                });
+            }
+            RedskyUiMsg::BookmarkActionFailed {
+                post_uri,
+                bookmarked,
+                error,
+            } => {
+                print!("error: {}", error);
+                if bookmarked {
+                    if let Some((idx, item)) = self.removed_bookmarks.remove(&post_uri) {
+                        self.bookmarks.insert(idx.min(self.bookmarks.len()), item);
+                    }
+                } else {
+                    self.bookmarks.retain(|item| item.uri() != Some(post_uri.as_str()));
+                }
+                self.update_post_optimistically(&post_uri, |post| {
+                    post.bookmarked = bookmarked;
+                });
+            }
             RedskyUiMsg::NotifyLikeActionSucceeded { post_uri, like_uri } => {
                 self.update_post_optimistically(&post_uri, |post| {
                     post.viewer_like = Some(like_uri.clone());
@@ -214,6 +585,163 @@ impl RedskyApp {
                     post.viewer_repost = Some(repost_uri.clone());
                 });
             }
+            RedskyUiMsg::PrepareFollowAction { did, unfollow } => {
+                if unfollow {
+                    let mut follow_record = None;
+                    self.update_profile_optimistically(&did, |profile| {
+                        if let Some(record) = &profile.viewer_following {
+                            follow_record = Some(record.clone());
+                        }
+                    });
+                    if follow_record == Some(RecordState::Pending) {
+                        // The follow record is not created yet, there is nothing to delete
+                        return;
+                    }
+                    let follow_record_uri = follow_record.as_ref().and_then(RecordState::uri).unwrap_or_default().to_string();
+                    self.update_profile_optimistically(&did, |profile| {
+                        profile.viewer_following = None;
+                    });
+                    for profile in self.user_infos_cache.values_mut() {
+                        if profile.did == did {
+                            profile.follower_count = (profile.follower_count - 1).max(0);
+                        }
+                    }
+                    if !follow_record_uri.is_empty() {
+                        self.post_message(BskyActorMsg::Unfollow {
+                            did,
+                            follow_record_uri,
+                        });
+                    }
+                } else {
+                    self.update_profile_optimistically(&did, |profile| {
+                        profile.viewer_following = Some(RecordState::Pending);
+                    });
+                    for profile in self.user_infos_cache.values_mut() {
+                        if profile.did == did {
+                            profile.follower_count += 1;
+                        }
+                    }
+                    self.post_message(BskyActorMsg::Follow { did });
+                }
+            }
+            RedskyUiMsg::NotifyFollowActionSucceeded { did, follow_uri } => {
+                self.update_profile_optimistically(&did, |profile| {
+                    profile.viewer_following = Some(RecordState::Created(follow_uri.clone()));
+                });
+            }
+            RedskyUiMsg::FollowActionFailed { did, follow_uri, error } => {
+                print!("error: {}", error);
+                let count_change = if follow_uri.is_some() { 1 } else { -1 };
+                self.update_profile_optimistically(&did, |profile| {
+                    profile.viewer_following = follow_uri.clone().map(RecordState::Created);
+                });
+                for profile in self.user_infos_cache.values_mut() {
+                    if profile.did == did {
+                        profile.follower_count = (profile.follower_count + count_change).max(0);
+                    }
+                }
+            }
+            RedskyUiMsg::PrepareBlockAction { did, unblock } => {
+                if unblock {
+                    let mut block_record = None;
+                    self.update_profile_optimistically(&did, |profile| {
+                        if let Some(record) = &profile.viewer_blocking {
+                            block_record = Some(record.clone());
+                        }
+                    });
+                    if block_record == Some(RecordState::Pending) {
+                        // The block record is not created yet, there is nothing to delete
+                        return;
+                    }
+                    let block_record_uri = block_record.as_ref().and_then(RecordState::uri).unwrap_or_default().to_string();
+                    self.block_overrides.insert(did.clone(), false);
+                    self.update_profile_optimistically(&did, |profile| {
+                        profile.viewer_blocking = None;
+                    });
+                    if !block_record_uri.is_empty() {
+                        self.post_message(BskyActorMsg::Unblock {
+                            did,
+                            block_record_uri,
+                        });
+                    }
+                } else {
+                    self.block_overrides.insert(did.clone(), true);
+                    self.update_profile_optimistically(&did, |profile| {
+                        profile.viewer_blocking = Some(RecordState::Pending);
+                    });
+                    self.post_message(BskyActorMsg::Block { did });
+                }
+            }
+            RedskyUiMsg::NotifyBlockActionSucceeded { did, block_uri } => {
+                self.update_profile_optimistically(&did, |profile| {
+                    profile.viewer_blocking = Some(RecordState::Created(block_uri.clone()));
+                });
+            }
+            RedskyUiMsg::BlockActionFailed { did, block_uri, error } => {
+                print!("error: {}", error);
+                self.block_overrides.insert(did.clone(), block_uri.is_some());
+                self.update_profile_optimistically(&did, |profile| {
+                    profile.viewer_blocking = block_uri.clone().map(RecordState::Created);
+                });
+            }
+            RedskyUiMsg::PrepareMuteAction { did, unmute } => {
+                self.mute_overrides.insert(did.clone(), !unmute);
+                self.update_profile_optimistically(&did, |profile| {
+                    profile.viewer_muted = !unmute;
+                });
+                if unmute {
+                    self.post_message(BskyActorMsg::Unmute { did });
+                } else {
+                    self.post_message(BskyActorMsg::Mute { did });
+                }
+            }
+            RedskyUiMsg::MuteActionFailed { did, muted, error } => {
+                print!("error: {}", error);
+                self.mute_overrides.insert(did.clone(), muted);
+                self.update_profile_optimistically(&did, |profile| {
+                    profile.viewer_muted = muted;
+                });
+            }
+            RedskyUiMsg::NotifyBlocksLoaded { users, cursor, append } => {
+                for profile in &users {
+                    self.request_image(&profile.avatar_uri);
+                }
+                if append {
+                    self.blocked_accounts.extend(users);
+                } else {
+                    self.blocked_accounts = users;
+                }
+                self.blocked_accounts_cursor = cursor;
+            }
+            RedskyUiMsg::NotifyMutesLoaded { users, cursor, append } => {
+                for profile in &users {
+                    self.request_image(&profile.avatar_uri);
+                }
+                if append {
+                    self.muted_accounts.extend(users);
+                } else {
+                    self.muted_accounts = users;
+                }
+                self.muted_accounts_cursor = cursor;
+            }
+            RedskyUiMsg::NotifyMutedWordsLoaded { words } => {
+                self.settings.muted_words = words;
+                self.settings.save();
+            }
+            RedskyUiMsg::NotifyContentPrefsLoaded {
+                adult_content_enabled,
+                label_visibility,
+            } => {
+                self.settings.adult_content_enabled = adult_content_enabled;
+                self.settings.label_visibility = label_visibility;
+                self.settings.save();
+            }
+            RedskyUiMsg::NotifyFeedViewPrefLoaded { pref } => {
+                if self.settings.timeline_view_pref != pref {
+                    self.settings.timeline_view_pref = pref;
+                    self.settings.save();
+                }
+            }
             RedskyUiMsg::NotifyPostAndRepliesLoaded { post, replies } => {
                 let strong_ref = StrongRef {
                     uri: post.uri.clone(),
@@ -225,8 +753,13 @@ impl RedskyApp {
                 items.extend(replies.into_iter().map(|p| FeedItem::Full(p, None)));
                 self.post_replies_cache.insert(strong_ref, Some(items));
             }
+            RedskyUiMsg::NotifySessionLoaded { did } => {
+                self.reading_positions = ReadingPositions::load(&did);
+                self.session_did = did;
+            }
             RedskyUiMsg::LogInSucceededMsg() => {
                 self.is_logged_in = true;
+                self.awaiting_login = false;
 
                 if self.remember_me {
                     if let Ok(entry) = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER) {
@@ -241,28 +774,185 @@ impl RedskyApp {
                 self.main_view_state = MainViewState::OwnPostFeed;
                 self.post_message(BskyActorMsg::GetUserPosts {
                     username: self.login.clone(),
+                    view: UserViewState::Posts,
                     cursor: None,
                 });
                 self.post_message(BskyActorMsg::GetUserProfile {
                     username: self.login.clone(),
                 });
-                self.post_message(BskyActorMsg::GetTimeline { cursor: None });
+                self.is_offline = false;
+                // Cached posts show right away, the network answers replace them
+                self.post_message(BskyActorMsg::LoadCachedFeed {
+                    feed: "Your timeline".to_string(),
+                });
+                self.post_message(BskyActorMsg::LoadCachedFeed {
+                    feed: "Your bookmarks".to_string(),
+                });
+                self.restore_reading_position("Your timeline");
                 self.post_message(BskyActorMsg::GetBookmarks { cursor: None });
                 self.post_message(BskyActorMsg::GetUnreadCount());
                 self.post_message(BskyActorMsg::GetNotifications { cursor: None });
+                self.post_message(BskyActorMsg::GetMutedWords());
+                self.post_message(BskyActorMsg::GetContentPrefs());
+                self.post_message(BskyActorMsg::GetFeedViewPref());
+                self.post_message(BskyActorMsg::GetSavedFeeds());
+                self.post_message(BskyActorMsg::GetLists { cursor: None });
+                self.post_message(BskyActorMsg::ListConvos { cursor: None });
+                if self.settings.realtime_updates {
+                    self.post_message(BskyActorMsg::StartRealtime());
+                }
+                if let Some(link) = self.pending_link.take() {
+                    self.open_link(&link);
+                }
             }
             RedskyUiMsg::NotifyUnreadCount { count } => {
                 self.unread_notifications = count;
+                let focused = ctx.input(|i| i.viewport().focused.unwrap_or(true));
+                if count > 0 && !focused && !self.settings.desktop_notification_reasons.is_empty() {
+                    self.post_message(BskyActorMsg::RaiseDesktopNotifications {
+                        reasons: self.settings.desktop_notification_reasons.iter().cloned().collect(),
+                        since: self.desktop_notified_at.clone(),
+                    });
+                }
+            }
+            RedskyUiMsg::RefreshConvosMsg { convos, cursor, append } => {
+                for member in convos.iter().flat_map(|convo| &convo.members) {
+                    self.request_image(&member.avatar_uri);
+                }
+                if append {
+                    for convo in convos {
+                        if !self.convos.iter().any(|c| c.id == convo.id) {
+                            self.convos.push(convo);
+                        }
+                    }
+                } else {
+                    self.convos = convos;
+                }
+                self.convos_cursor = cursor;
+            }
+            RedskyUiMsg::RefreshMessagesMsg {
+                convo_id,
+                messages,
+                cursor,
+                append,
+            } => {
+                let entry = self.convo_messages.entry(convo_id).or_default();
+                if append {
+                    // Older page, goes before what is already loaded
+                    entry.0.splice(0..0, messages);
+                } else {
+                    entry.0 = messages;
+                }
+                entry.1 = cursor;
+            }
+            RedskyUiMsg::NotifyConvoOpened { convo } => {
+                let convo_id = convo.id.clone();
+                self.upsert_convo(convo);
+                self.open_convo(&convo_id);
+            }
+            RedskyUiMsg::NotifyConvoUpdated { convo } => {
+                self.upsert_convo(convo);
+            }
+            RedskyUiMsg::NotifyConvoLeft { convo_id } => {
+                self.remove_convo(&convo_id);
+            }
+            RedskyUiMsg::NotifyMessageSent { convo_id, message } => {
+                self.add_chat_message(&convo_id, message);
+            }
+            RedskyUiMsg::NotifyChatLog { events, cursor } => {
+                // The first call only establishes where the log currently is
+                if self.chat_log_started {
+                    self.apply_chat_log(events);
+                }
+                self.chat_log_started = true;
+                // An empty log has no cursor, start after the latest conversation change instead
+                let newest_rev = self.convos.iter().map(|convo| convo.rev.clone()).max();
+                if let Some(cursor) = cursor.or_else(|| self.chat_log_cursor.take()).or(newest_rev) {
+                    self.chat_log_cursor = Some(cursor);
+                }
+            }
+            RedskyUiMsg::NotifyRealtimePost { post } => {
+                let known = self.pending_timeline_posts.iter().any(|p| p.uri == post.uri)
+                    || self.timeline.iter().any(|item| item.uri() == Some(post.uri.as_str()));
+                if !known {
+                    self.request_post_images(&vec![post.clone()]);
+                    self.pending_timeline_posts.insert(0, post);
+                }
+            }
+            RedskyUiMsg::NotifyRealtimePostHidden { uri } => {
+                self.timeline_hidden.insert(uri);
+            }
+            RedskyUiMsg::ShowPendingTimelinePosts() => {
+                let pending = std::mem::take(&mut self.pending_timeline_posts);
+                self.timeline.splice(0..0, into_feed_items(pending));
+                self.main_view_state = MainViewState::TimelineFeed;
+                self.scroll_to_top = true;
             }
-            RedskyUiMsg::RefreshNotificationsMsg { notifications, cursor, append } => {
+            RedskyUiMsg::NotifyRealtimeCount {
+                subject_uri,
+                is_like,
+                delta,
+            } => {
+                let update = |post: &mut Post| {
+                    if is_like {
+                        post.like_count = (post.like_count + delta).max(0);
+                    } else {
+                        post.repost_count = (post.repost_count + delta).max(0);
+                    }
+                };
+                self.update_post_optimistically(&subject_uri, update);
+                if let Some(post) = self.notification_subjects.get_mut(&subject_uri) {
+                    update(post);
+                }
+            }
+            RedskyUiMsg::NotifyRealtimeInteraction() => {
+                self.post_message(BskyActorMsg::GetUnreadCount());
+                if self.main_view_state == MainViewState::NotificationsFeed {
+                    self.post_message(BskyActorMsg::GetNotifications { cursor: None });
+                }
+            }
+            RedskyUiMsg::NotifyDesktopNotificationsRaised { latest } => {
+                self.mark_desktop_notified(latest.as_deref());
+            }
+            RedskyUiMsg::DesktopNotificationClicked { target } => {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                match target {
+                    NotificationTarget::Thread(thread_ref) => {
+                        self.post_ui_message(RedskyUiMsg::PrepareThreadView { thread_ref });
+                    }
+                    NotificationTarget::Profile(handle) => self.open_profile(&handle),
+                    NotificationTarget::Notifications => {
+                        self.main_view_state = MainViewState::NotificationsFeed;
+                    }
+                }
+            }
+            RedskyUiMsg::RefreshNotificationsMsg {
+                notifications,
+                subject_posts,
+                cursor,
+                append,
+            } => {
                 for notif in &notifications {
                     self.request_image(&notif.author_avatar);
                 }
+                self.request_post_images(&subject_posts);
+                for post in subject_posts {
+                    self.notification_subjects.insert(post.uri.clone(), post);
+                }
                 if append {
                     self.notifications.extend(notifications);
                 } else {
+                    // Whatever is shown in the tab does not need a desktop notification anymore
+                    let newest = notifications.first().map(|notif| notif.date.clone());
+                    self.mark_desktop_notified(newest.as_deref());
                     self.notifications = notifications;
+                    if self.mark_seen_on_load {
+                        self.mark_seen_on_load = false;
+                        self.post_message(BskyActorMsg::UpdateSeen());
+                    }
                 }
+                self.notification_groups = crate::app::group_notifications(&self.notifications);
                 self.notifications_cursor = cursor;
             }
             RedskyUiMsg::NotifyImageLoaded { url, data } => {
@@ -318,13 +1008,40 @@ impl RedskyApp {
                 );
                 self.post_message(BskyActorMsg::StartImageDownload { id, username, path });
             }
-            RedskyUiMsg::ShowSearchResults { results } => {
+            RedskyUiMsg::ShowSearchResults {
+                query,
+                results,
+                cursor,
+                append,
+            } => {
+                if query != self.search_results_query {
+                    return;
+                }
                 for profile in &results {
                     self.request_image(&profile.avatar_uri);
                 }
-                self.search_results = results;
+                if append {
+                    self.search_results.extend(results);
+                } else {
+                    self.search_results = results;
+                }
+                self.search_results_cursor = cursor;
+            }
+            RedskyUiMsg::ShowActorSuggestions { target, seq, actors } => {
+                for profile in &actors {
+                    self.request_image(&profile.avatar_uri);
+                }
+                if let Some(typeahead) = self.typeaheads.get_mut(&target) {
+                    typeahead.accept(seq, actors);
+                }
             }
-            RedskyUiMsg::ShowSearchPostsResults { posts, cursor, append } => {
+            RedskyUiMsg::ShowSearchPostsResults {
+                posts,
+                cursor,
+                append,
+                hits_total,
+            } => {
+                self.search_posts_total = hits_total;
                 self.request_post_images(&posts);
                 let new_items = crate::app::into_feed_items(posts);
                 if append {
@@ -4,8 +4,9 @@ pub struct StrongRef {
     pub uri: String,
     pub cid: Cid,
 }
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct UserProfile {
+    pub did: String,
     pub handle: String,
     pub display_name: String,
     pub bio: String,
@@ -13,13 +14,149 @@ pub struct UserProfile {
     pub follower_count: i64,
     pub follow_count: i64,
     pub post_count: i64,
+    pub viewer_following: Option<RecordState>,
+    pub viewer_followed_by: bool,
+    pub viewer_blocking: Option<RecordState>,
+    pub viewer_muted: bool,
+    #[serde(default)]
+    pub banner_uri: String,
+    #[serde(default)]
+    pub labels: Vec<String>, // account and self labels
+    #[serde(default)]
+    pub pinned_post: Option<StrongRef>,
+}
+/// The editable part of our app.bsky.actor.profile record. `cid` is the version it was
+/// read from, saving fails if the record has changed since.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileEdit {
+    pub cid: Option<Cid>,
+    pub display_name: String,
+    pub description: String,
+    pub avatar_path: Option<String>, // newly picked image, uploaded on save
+    pub banner_path: Option<String>,
+}
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum UserListKind {
+    Followers,
+    Follows,
+    KnownFollowers,
+}
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedFeed {
+    pub id: String,
+    pub kind: String, // "feed", "list" or "timeline"
+    pub uri: String,
+    pub pinned: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeedGenerator {
+    pub uri: String,
+    pub display_name: String,
+    pub description: String,
+    pub avatar_uri: String,
+    pub creator_handle: String,
+    pub like_count: usize,
+}
+pub const CURATE_LIST_PURPOSE: &str = "app.bsky.graph.defs#curatelist";
+pub const MOD_LIST_PURPOSE: &str = "app.bsky.graph.defs#modlist";
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserList {
+    pub uri: String,
+    pub name: String,
+    pub description: String,
+    pub purpose: String, // CURATE_LIST_PURPOSE or MOD_LIST_PURPOSE
+    pub avatar_uri: String,
+    pub item_count: usize,
+    pub creator_handle: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListMember {
+    pub item_uri: String,
+    pub profile: UserProfile,
+}
+/// A record of ours, such as a follow or a block. Shown right away, its uri comes once the server has it.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum RecordState {
+    Pending,
+    Created(String),
+}
+
+impl RecordState {
+    pub fn uri(&self) -> Option<&str> {
+        match self {
+            RecordState::Pending => None,
+            RecordState::Created(uri) => Some(uri),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListMembership {
+    pub list: UserList,
+    pub item_uri: Option<RecordState>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChatMessage {
+    pub id: String,
+    pub sender_did: String,
+    pub text: String,
+    pub sent_at: String,
+    pub deleted: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvoMember {
+    pub did: String,
+    pub handle: String,
+    pub display_name: String,
+    pub avatar_uri: String,
 }
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conversation {
+    pub id: String,
+    pub members: Vec<ConvoMember>,
+    pub muted: bool,
+    pub unread_count: i64,
+    pub last_message: Option<ChatMessage>,
+    pub rev: String, // chat log position of the last change
+}
+
+impl Conversation {
+    /// Everyone but the logged in user, "@a, @b"
+    pub fn title(&self, own_did: &str) -> String {
+        let others: Vec<String> = self
+            .members
+            .iter()
+            .filter(|member| member.did != own_did)
+            .map(|member| format!("@{}", member.handle))
+            .collect();
+        if others.is_empty() {
+            self.members.first().map(|member| format!("@{}", member.handle)).unwrap_or_default()
+        } else {
+            others.join(", ")
+        }
+    }
+}
+/// An entry of the chat.bsky.convo.getLog event stream
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChatLogEvent {
+    NewMessage { convo_id: String, message: ChatMessage },
+    MessageDeleted { convo_id: String, message_id: String },
+    ConvoLeft { convo_id: String },
+    ConvoChanged { convo_id: String }, // begin, accept, mute, read...
+}
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum FeedDiscoveryKind {
+    Saved,
+    Suggested,
+    Popular,
+}
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct Post {
     pub uri: String,
     pub cid: Cid,
     pub content: String,
     pub author: String,
+    pub author_did: String,
     pub display_name: String,
     pub avatar_img: String,
     pub date: String,
@@ -31,18 +168,70 @@ pub struct Post {
     pub is_reply: bool,
     pub viewer_like: Option<String>,
     pub viewer_repost: Option<String>,
+    pub bookmarked: bool,
+    pub author_muted: bool,
+    pub author_blocked: bool,
+    pub labels: Vec<String>, // post, self and author labels
     pub thread_root: Option<StrongRef>,
+    pub tags: Vec<String>,
     pub raw_json: String,
 }
+#[allow(clippy::large_enum_variant)]
 pub enum FeedItem {
     Full(Post, Option<f32>),
     Dehydrated { uri: String, height: Option<f32> },
+    Gap { cursor: String, loading: bool }, // posts between a refreshed head and the older ones
+}
+
+impl FeedItem {
+    pub fn uri(&self) -> Option<&str> {
+        match self {
+            FeedItem::Full(post, _) => Some(&post.uri),
+            FeedItem::Dehydrated { uri, .. } => Some(uri),
+            FeedItem::Gap { .. } => None,
+        }
+    }
 }
 
 pub fn into_feed_items(posts: impl IntoIterator<Item = Post>) -> Vec<FeedItem> {
     posts.into_iter().map(|p| FeedItem::Full(p, None)).collect()
 }
 
+/// Takes the posts of `page` that are not in `items` yet. A repost of a loaded post does not
+/// hide the newer posts below it. When the page does not overlap `items` at all, a gap that
+/// resumes at `cursor` is appended so the missing posts can be loaded.
+fn unseen_head(items: &[FeedItem], page: Vec<Post>, cursor: Option<String>) -> Vec<FeedItem> {
+    let mut known: HashSet<String> = items.iter().filter_map(FeedItem::uri).map(str::to_string).collect();
+    let reached_known = page.iter().any(|post| known.contains(&post.uri));
+    let mut head = into_feed_items(page.into_iter().filter(|post| known.insert(post.uri.clone())));
+    if !reached_known && !head.is_empty() {
+        if let Some(cursor) = cursor {
+            head.push(FeedItem::Gap { cursor, loading: false });
+        }
+    }
+    head
+}
+
+/// Prepends the newer posts of a refreshed first page, returns how many were added.
+pub fn merge_feed_head(items: &mut Vec<FeedItem>, page: Vec<Post>, cursor: Option<String>) -> usize {
+    let head = unseen_head(items, page, cursor);
+    let count = head.iter().filter(|item| item.uri().is_some()).count();
+    items.splice(0..0, head);
+    count
+}
+
+/// Replaces the gap that resumes at `gap_cursor` with the page loaded from it.
+pub fn fill_feed_gap(items: &mut Vec<FeedItem>, gap_cursor: &str, page: Vec<Post>, cursor: Option<String>) {
+    let Some(gap_idx) = items
+        .iter()
+        .position(|item| matches!(item, FeedItem::Gap { cursor, .. } if cursor == gap_cursor))
+    else {
+        return;
+    };
+    let fill = unseen_head(items, page, cursor);
+    items.splice(gap_idx..gap_idx + 1, fill);
+}
+
 #[derive(Debug)]
 pub struct DownloadTask {
     #[allow(dead_code)]
@@ -56,7 +245,7 @@ pub struct DownloadTask {
     pub status: DownloadStatus,
     pub errors: Vec<String>,
 }
-#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+#[derive(Eq, PartialEq, Hash, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PostImage {
     pub thumbnail_url: String,
     pub url: String,
@@ -81,15 +270,314 @@ pub enum DownloadStatus {
 }
 #[derive(Clone, Debug)]
 pub struct AppNotification {
-    #[allow(dead_code)]
     pub uri: String,
+    pub cid: Cid,
     pub author: String,
+    pub author_did: String,
     pub author_avatar: String,
     pub reason: String,
+    pub reason_subject: Option<String>, // uri of the post that was liked, reposted...
+    pub record_text: String,            // text of replies, mentions and quotes
+    pub date: String,
     pub is_read: bool,
 }
+#[derive(Clone, Debug)]
+pub struct NotificationGroup {
+    pub reason: String,
+    pub subject_uri: Option<String>,
+    pub notifications: Vec<AppNotification>,
+}
+
+impl NotificationGroup {
+    pub fn is_read(&self) -> bool {
+        self.notifications.iter().all(|notif| notif.is_read)
+    }
+
+    /// "@a", "@a and @b", "@a, @b and 12 others"
+    pub fn authors_text(&self) -> String {
+        let mut authors: Vec<&str> = Vec::new();
+        for notif in &self.notifications {
+            if !authors.contains(&notif.author.as_str()) {
+                authors.push(&notif.author);
+            }
+        }
+        match authors.len() {
+            0 => String::new(),
+            1 => format!("@{}", authors[0]),
+            2 => format!("@{} and @{}", authors[0], authors[1]),
+            3 => format!("@{}, @{} and 1 other", authors[0], authors[1]),
+            n => format!("@{}, @{} and {} others", authors[0], authors[1], n - 2),
+        }
+    }
+}
+
+/// Likes, reposts and follows on the same subject are folded into one group, in order of first appearance.
+pub fn group_notifications(notifications: &[AppNotification]) -> Vec<NotificationGroup> {
+    let mut groups: Vec<NotificationGroup> = Vec::new();
+    for notif in notifications {
+        let groupable = matches!(
+            notif.reason.as_str(),
+            "like" | "repost" | "follow" | "like-via-repost" | "repost-via-repost"
+        );
+        if groupable {
+            if let Some(group) = groups
+                .iter_mut()
+                .find(|g| g.reason == notif.reason && g.subject_uri == notif.reason_subject)
+            {
+                group.notifications.push(notif.clone());
+                continue;
+            }
+        }
+        groups.push(NotificationGroup {
+            reason: notif.reason.clone(),
+            subject_uri: notif.reason_subject.clone(),
+            notifications: vec![notif.clone()],
+        });
+    }
+    groups
+}
+pub fn notification_action_text(reason: &str) -> &str {
+    match reason {
+        "like" => "liked your post",
+        "like-via-repost" => "liked your repost",
+        "repost" => "reposted your post",
+        "repost-via-repost" => "reposted your repost",
+        "follow" => "followed you",
+        "mention" => "mentioned you",
+        "reply" => "replied to your post",
+        "quote" => "quoted your post",
+        _ => reason,
+    }
+}
+
+/// Reasons that can raise a desktop notification, with their settings label.
+pub const DESKTOP_NOTIFICATION_REASONS: [(&str, &str); 6] = [
+    ("mention", "Mentions"),
+    ("reply", "Replies"),
+    ("quote", "Quotes"),
+    ("follow", "Follows"),
+    ("like", "Likes"),
+    ("repost", "Reposts"),
+];
+
+/// What to open when a desktop notification is clicked.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+pub enum NotificationTarget {
+    Thread(StrongRef),
+    Profile(String),
+    Notifications,
+}
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum NotificationFilter {
+    All,
+    Mentions,
+    Likes,
+    Reposts,
+    Follows,
+}
+
+impl NotificationFilter {
+    pub fn matches(&self, reason: &str) -> bool {
+        match self {
+            NotificationFilter::All => true,
+            NotificationFilter::Mentions => matches!(reason, "mention" | "reply" | "quote"),
+            NotificationFilter::Likes => matches!(reason, "like" | "like-via-repost"),
+            NotificationFilter::Reposts => matches!(reason, "repost" | "repost-via-repost"),
+            NotificationFilter::Follows => reason == "follow",
+        }
+    }
+}
+/// A search pinned as a tab, `query` may contain inline operators.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum PostSearchSort {
+    #[default]
+    Top,
+    Latest,
+}
+/// searchPosts query and filters, empty strings mean no filter.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct PostSearch {
+    pub query: String,
+    pub author: String,
+    pub mentions: String,
+    pub since: String, // YYYY-MM-DD or a datetime
+    pub until: String,
+    pub lang: String,
+    pub domain: String,
+    pub url: String,
+    pub tags: String, // space or comma separated, without the #
+    pub sort: PostSearchSort,
+}
+
+impl PostSearch {
+    /// Moves inline operators such as `from:alice since:2026-01-01` out of the query and into the filters.
+    pub fn apply_inline_operators(&mut self) {
+        let mut words = Vec::new();
+        for word in self.query.split_whitespace() {
+            let Some((operator, value)) = word.split_once(':').filter(|(_, value)| !value.is_empty()) else {
+                words.push(word);
+                continue;
+            };
+            let handle = value.trim_start_matches('@').to_string();
+            match operator {
+                "from" => self.author = handle,
+                "mentions" | "mention" | "to" => self.mentions = handle,
+                "since" => self.since = value.to_string(),
+                "until" => self.until = value.to_string(),
+                "lang" => self.lang = value.to_string(),
+                "domain" => self.domain = value.to_string(),
+                "url" => self.url = value.to_string(),
+                "tag" => {
+                    let tag = value.trim_start_matches('#');
+                    if !self.tag_list().iter().any(|t| t == tag) {
+                        self.tags = format!("{} {}", self.tags, tag).trim().to_string();
+                    }
+                }
+                "sort" if value == "latest" => self.sort = PostSearchSort::Latest,
+                "sort" if value == "top" => self.sort = PostSearchSort::Top,
+                _ => words.push(word), // not an operator, e.g. a url
+            }
+        }
+        self.query = words.join(" ");
+    }
+
+    /// The reverse of apply_inline_operators, for saving a search as a single line.
+    pub fn to_inline_query(&self) -> String {
+        let mut parts = vec![self.query.trim().to_string()];
+        for (operator, value) in [
+            ("from", &self.author),
+            ("mentions", &self.mentions),
+            ("since", &self.since),
+            ("until", &self.until),
+            ("lang", &self.lang),
+            ("domain", &self.domain),
+            ("url", &self.url),
+        ] {
+            if !value.trim().is_empty() {
+                parts.push(format!("{}:{}", operator, value.trim()));
+            }
+        }
+        parts.extend(self.tag_list().iter().map(|tag| format!("tag:{}", tag)));
+        if self.sort == PostSearchSort::Latest {
+            parts.push("sort:latest".to_string());
+        }
+        parts.retain(|part| !part.is_empty());
+        parts.join(" ")
+    }
+
+    /// `from:me` and `mentions:me` stand for the logged in account, the search api takes its did.
+    pub fn resolve_own_account(&mut self, own_did: &str) {
+        for handle in [&mut self.author, &mut self.mentions] {
+            if handle == "me" && !own_did.is_empty() {
+                *handle = own_did.to_string();
+            }
+        }
+    }
+
+    /// Saved searches are followed like a feed, newest posts first.
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    pub fn saved(query: &str, own_did: &str) -> Self {
+        let mut search = PostSearch {
+            query: query.to_string(),
+            ..Default::default()
+        };
+        search.apply_inline_operators();
+        search.resolve_own_account(own_did);
+        search.sort = PostSearchSort::Latest;
+        search
+    }
+
+    pub fn tag_list(&self) -> Vec<String> {
+        self.tags
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|tag| tag.trim_start_matches('#'))
+            .filter(|tag| !tag.is_empty())
+            .map(|tag| tag.to_string())
+            .collect()
+    }
+}
+
+/// Account suggestions for one text field. A request only goes out once typing pauses,
+/// and answers to anything but the latest request are dropped.
+#[derive(Default, Debug)]
+pub struct ActorTypeahead {
+    pub query: String,
+    pub suggestions: Vec<UserProfile>,
+    seq: u64,
+    edited_at: Option<std::time::Instant>, // set until the query has been sent
+}
+
+impl ActorTypeahead {
+    pub fn set_query(&mut self, query: &str, now: std::time::Instant) {
+        if query == self.query {
+            return;
+        }
+        self.query = query.to_string();
+        if self.query.is_empty() {
+            self.clear();
+        } else {
+            self.edited_at = Some(now);
+        }
+    }
+
+    /// Returns the query and sequence number to request once `debounce` has passed since
+    /// the last edit.
+    pub fn poll(&mut self, now: std::time::Instant, debounce: std::time::Duration) -> Option<(String, u64)> {
+        let edited_at = self.edited_at?;
+        if now.duration_since(edited_at) < debounce {
+            return None;
+        }
+        self.edited_at = None;
+        self.seq += 1;
+        Some((self.query.clone(), self.seq))
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.edited_at.is_some()
+    }
+
+    /// Returns false when the answer is stale.
+    pub fn accept(&mut self, seq: u64, actors: Vec<UserProfile>) -> bool {
+        if seq != self.seq || self.edited_at.is_some() {
+            return false;
+        }
+        self.suggestions = actors;
+        true
+    }
+
+    /// Hides the suggestions, including those of a request still in flight.
+    pub fn clear(&mut self) {
+        self.suggestions.clear();
+        self.edited_at = None;
+        self.seq += 1;
+    }
+}
+
+/// Finds the @-mention being typed at `cursor` (a char index), returning the char index
+/// of its '@' and the partial handle after it.
+pub fn mention_at(text: &str, cursor: usize) -> Option<(usize, String)> {
+    let before: Vec<char> = text.chars().take(cursor).collect();
+    let at = before.iter().rposition(|c| !(c.is_alphanumeric() || *c == '.' || *c == '-'))?;
+    if before[at] != '@' || (at > 0 && !before[at - 1].is_whitespace()) {
+        return None;
+    }
+    Some((at, before[at + 1..].iter().collect()))
+}
+
+#[allow(clippy::large_enum_variant)]
 pub enum RedskyUiMsg {
     LogInSucceededMsg(),
+    NotifySessionLoaded {
+        did: String,
+    },
     ActionSucceeded(),
     RefreshBookmarksMsg {
         posts: Vec<Post>,
@@ -128,6 +616,17 @@ pub enum RedskyUiMsg {
     CloseRepostersView {
         post_uri: StrongRef,
     },
+    NotifyUserListLoaded {
+        username: String,
+        kind: UserListKind,
+        users: Vec<UserProfile>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    CloseUserListView {
+        username: String,
+        kind: UserListKind,
+    },
     PrepareLikeAction {
         post_uri: String,
         post_cid: Cid,
@@ -138,6 +637,20 @@ pub enum RedskyUiMsg {
         post_cid: Cid,
         unrepost: bool,
     },
+    PrepareBookmarkAction {
+        post_uri: String,
+        post_cid: Cid,
+        unbookmark: bool,
+    },
+    NotifyBookmarkActionSucceeded {
+        post_uri: String,
+        bookmarked: bool,
+    },
+    BookmarkActionFailed {
+        post_uri: String,
+        bookmarked: bool, // the state to restore
+        error: String,
+    },
     NotifyLikeActionSucceeded {
         post_uri: String,
         like_uri: String,
@@ -146,6 +659,58 @@ pub enum RedskyUiMsg {
         post_uri: String,
         repost_uri: String,
     },
+    PrepareFollowAction {
+        did: String,
+        unfollow: bool,
+    },
+    NotifyFollowActionSucceeded {
+        did: String,
+        follow_uri: String,
+    },
+    FollowActionFailed {
+        did: String,
+        follow_uri: Option<String>, // the follow record to restore, None when following failed
+        error: String,
+    },
+    PrepareBlockAction {
+        did: String,
+        unblock: bool,
+    },
+    NotifyBlockActionSucceeded {
+        did: String,
+        block_uri: String,
+    },
+    BlockActionFailed {
+        did: String,
+        block_uri: Option<String>, // the block record to restore, None when blocking failed
+        error: String,
+    },
+    PrepareMuteAction {
+        did: String,
+        unmute: bool,
+    },
+    MuteActionFailed {
+        did: String,
+        muted: bool, // the state to restore
+        error: String,
+    },
+    NotifyMutedWordsLoaded {
+        words: Vec<MutedWord>,
+    },
+    NotifyContentPrefsLoaded {
+        adult_content_enabled: bool,
+        label_visibility: std::collections::BTreeMap<String, LabelVisibility>,
+    },
+    NotifyBlocksLoaded {
+        users: Vec<UserProfile>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    NotifyMutesLoaded {
+        users: Vec<UserProfile>,
+        cursor: Option<String>,
+        append: bool,
+    },
     NotifyPostAndRepliesLoaded {
         post: Post,
         replies: Vec<Post>,
@@ -153,13 +718,134 @@ pub enum RedskyUiMsg {
     ShowUserProfile {
         profile: UserProfile,
     },
+    ShowProfileRecord {
+        edit: ProfileEdit,
+    },
+    ProfileUpdated {
+        profile: UserProfile,
+    },
+    OpenUserView {
+        username: String,
+    },
+    OpenFeedView {
+        feed_uri: String,
+    },
+    OpenLinkFailed {
+        link: String,
+        error: String,
+    },
+    OpenForwardedLink {
+        link: String, // from a second launch of Redsky
+    },
+    ProfileUpdateFailed {
+        error: String,
+    },
     RefreshTimelineMsg {
         posts: Vec<Post>,
         cursor: Option<String>,
         append: bool,
+        hidden: Vec<String>, // uris left out by the timeline view preferences
+    },
+    FillTimelineGapMsg {
+        gap_cursor: String,
+        posts: Vec<Post>,
+        cursor: Option<String>,
+        hidden: Vec<String>,
+    },
+    TimelineGapFailed {
+        gap_cursor: String,
+        error: String,
+    },
+    FeedPageFailed {
+        feed: String, // keyed like make_post_view
+        cursor: Option<String>,
+    },
+    NotifyFeedViewPrefLoaded {
+        pref: FeedViewPref,
+    },
+    RefreshCustomFeedMsg {
+        feed_uri: String,
+        posts: Vec<Post>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    NotifyListsLoaded {
+        lists: Vec<UserList>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    NotifyListCreated {
+        list: UserList,
+    },
+    NotifyListMembersLoaded {
+        list: UserList,
+        members: Vec<ListMember>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    PrepareListMemberships {
+        did: String,
+    },
+    NotifyListMembershipsLoaded {
+        did: String,
+        memberships: Vec<ListMembership>,
+    },
+    PrepareListMemberAction {
+        list_uri: String,
+        did: String,
+        item_uri: Option<String>, // set when removing the member
+    },
+    AddListMemberFailed {
+        list_uri: String,
+        did: String,
+        error: String,
+    },
+    RefreshConvosMsg {
+        convos: Vec<Conversation>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    RefreshMessagesMsg {
+        convo_id: String,
+        messages: Vec<ChatMessage>, // oldest first
+        cursor: Option<String>,
+        append: bool,
+    },
+    NotifyConvoOpened {
+        convo: Conversation,
+    },
+    NotifyConvoUpdated {
+        convo: Conversation,
+    },
+    NotifyConvoLeft {
+        convo_id: String,
+    },
+    NotifyMessageSent {
+        convo_id: String,
+        message: ChatMessage,
+    },
+    NotifyChatLog {
+        events: Vec<ChatLogEvent>,
+        cursor: Option<String>,
+    },
+    NotifyListMemberAdded {
+        list_uri: String,
+        did: String,
+        item_uri: String,
+    },
+    NotifySavedFeedsLoaded {
+        saved: Vec<SavedFeed>,
+        generators: Vec<FeedGenerator>,
+    },
+    NotifyFeedGeneratorsLoaded {
+        kind: FeedDiscoveryKind,
+        generators: Vec<FeedGenerator>,
+        cursor: Option<String>,
+        append: bool,
     },
     ShowUserPostsMsg {
         username: String,
+        view: UserViewState,
         posts: Vec<Post>,
         cursor: Option<String>,
         append: bool,
@@ -215,22 +901,82 @@ pub enum RedskyUiMsg {
         path: String,
     },
     ShowSearchResults {
+        query: String,
         results: Vec<UserProfile>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    ShowActorSuggestions {
+        target: String,
+        seq: u64,
+        actors: Vec<UserProfile>,
     },
     ShowSearchPostsResults {
         posts: Vec<Post>,
         cursor: Option<String>,
         append: bool,
+        hits_total: Option<i64>,
+    },
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    NotifyRealtimePostHidden {
+        uri: String, // filtered out by the timeline view preferences
+    },
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    NotifyRealtimePost {
+        post: Post,
+    },
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    NotifyRealtimeCount {
+        subject_uri: String,
+        is_like: bool, // repost otherwise
+        delta: i64,    // -1 for an unlike or an unrepost
+    },
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    NotifyRealtimeInteraction(),
+    ShowPendingTimelinePosts(),
+    ShowCachedFeedMsg {
+        feed: String, // same keys as make_post_view
+        posts: Vec<Post>,
+        cursor: Option<String>,
+    },
+    ShowHistoryResults {
+        posts: Vec<Post>,
+    },
+    RefreshSavedSearchMsg {
+        query: String,
+        posts: Vec<Post>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    OpenPostSearch {
+        search: PostSearch,
+    },
+    PinSavedSearch {
+        search: SavedSearch,
+    },
+    NotifyDesktopNotificationsRaised {
+        latest: Option<String>,
+    },
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    DesktopNotificationClicked {
+        target: NotificationTarget,
     },
     NotifyUnreadCount {
         count: i64,
     },
     RefreshNotificationsMsg {
         notifications: Vec<AppNotification>,
+        subject_posts: Vec<Post>,
         cursor: Option<String>,
         append: bool,
     },
 }
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum HistoryFilter {
+    All,
+    Liked,
+    Bookmarked,
+}
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum BskyActorMsg {
     Login {
@@ -242,10 +988,100 @@ pub enum BskyActorMsg {
         image_paths: Vec<String>,
         reply_to: Option<(StrongRef, StrongRef)>,
     },
+    GetTimelineGap {
+        cursor: String,
+    },
+    LoadCachedFeed {
+        feed: String,
+    },
+    SearchHistory {
+        query: String,
+        filter: HistoryFilter,
+    },
     GetTimeline {
         cursor: Option<String>,
     },
     GetBookmarks { cursor: Option<String> },
+    GetFeed {
+        feed_uri: String,
+        cursor: Option<String>,
+    },
+    GetSavedFeeds(),
+    GetListFeed {
+        list_uri: String,
+        cursor: Option<String>,
+    },
+    GetLists {
+        cursor: Option<String>,
+    },
+    GetList {
+        list_uri: String,
+        cursor: Option<String>,
+    },
+    GetListMemberships {
+        did: String,
+    },
+    CreateList {
+        name: String,
+        description: String,
+        purpose: String,
+    },
+    GetProfileRecord(),
+    UpdateProfile {
+        edit: ProfileEdit,
+    },
+    UpdateList {
+        list_uri: String,
+        name: String,
+        description: String,
+    },
+    DeleteList {
+        list_uri: String,
+    },
+    AddListMember {
+        list_uri: String,
+        did: String,
+    },
+    ListConvos {
+        cursor: Option<String>,
+    },
+    GetMessages {
+        convo_id: String,
+        cursor: Option<String>,
+    },
+    SendMessage {
+        convo_id: String,
+        text: String,
+    },
+    GetConvoForMembers {
+        did: String,
+    },
+    MuteConvo {
+        convo_id: String,
+        mute: bool,
+    },
+    LeaveConvo {
+        convo_id: String,
+    },
+    UpdateConvoRead {
+        convo_id: String,
+    },
+    GetChatLog {
+        cursor: Option<String>,
+    },
+    RemoveListMember {
+        item_uri: String,
+    },
+    PutSavedFeeds {
+        saved: Vec<SavedFeed>,
+    },
+    GetSuggestedFeeds {
+        cursor: Option<String>,
+    },
+    GetPopularFeeds {
+        query: Option<String>,
+        cursor: Option<String>,
+    },
     Like {
         post_ref: StrongRef,
     },
@@ -260,10 +1096,55 @@ pub enum BskyActorMsg {
     Repost {
         post_ref: StrongRef,
     },
+    Bookmark {
+        post_ref: StrongRef,
+    },
+    Unbookmark {
+        post_uri: String,
+    },
     Unrepost {
         post_uri: String,
         repost_record_uri: String,
     },
+    Follow {
+        did: String,
+    },
+    Unfollow {
+        did: String,
+        follow_record_uri: String,
+    },
+    Block {
+        did: String,
+    },
+    Unblock {
+        did: String,
+        block_record_uri: String,
+    },
+    Mute {
+        did: String,
+    },
+    Unmute {
+        did: String,
+    },
+    GetBlocks {
+        cursor: Option<String>,
+    },
+    GetMutes {
+        cursor: Option<String>,
+    },
+    GetMutedWords(),
+    PutMutedWords {
+        words: Vec<MutedWord>,
+    },
+    GetFeedViewPref(),
+    PutFeedViewPref {
+        pref: FeedViewPref,
+    },
+    GetContentPrefs(),
+    PutContentPrefs {
+        adult_content_enabled: bool,
+        label_visibility: std::collections::BTreeMap<String, LabelVisibility>,
+    },
     GetPostLikers {
         post_ref: StrongRef,
         cursor: Option<String>,
@@ -275,11 +1156,28 @@ pub enum BskyActorMsg {
     GetPostAndReplies {
         post_ref: StrongRef,
     },
+    ResolveLink {
+        link: String, // as typed, for the error message
+        target: AtUriTarget,
+    },
     GetUserProfile {
         username: String,
     },
+    GetFollowers {
+        username: String,
+        cursor: Option<String>,
+    },
+    GetFollows {
+        username: String,
+        cursor: Option<String>,
+    },
+    GetKnownFollowers {
+        username: String,
+        cursor: Option<String>,
+    },
     GetUserPosts {
         username: String,
+        view: UserViewState,
         cursor: Option<String>,
     },
     GetUserLikes {
@@ -288,8 +1186,18 @@ pub enum BskyActorMsg {
     },
     SearchActors {
         query: String,
+        cursor: Option<String>,
+    },
+    SearchActorsTypeahead {
+        target: String, // which text field the suggestions are for
+        query: String,
+        seq: u64,
     },
     SearchPosts {
+        search: PostSearch,
+        cursor: Option<String>,
+    },
+    GetSavedSearch {
         query: String,
         cursor: Option<String>,
     },
@@ -304,8 +1212,15 @@ pub enum BskyActorMsg {
     CancelImageDownload {
         id: u64,
     },
+    StartRealtime(),
+    StopRealtime(),
     GetUnreadCount(),
     GetNotifications { cursor: Option<String> },
+    UpdateSeen(),
+    RaiseDesktopNotifications {
+        reasons: Vec<String>,
+        since: Option<String>,
+    },
     #[allow(dead_code)]
     Close(),
 }
@@ -316,13 +1231,78 @@ pub enum MainViewState {
     OwnPostFeed,
     BookmarksFeed,
     NotificationsFeed,
+    CustomFeed(String), // feed uri
+    SavedSearch(String), // saved search query
+    Messages,
 }
 #[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ModerationViewState {
+    Blocked,
+    Muted,
+}
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum UserViewState {
     Posts,
+    Replies,
     Media,
+    Videos,
     Liked,
 }
+
+impl UserViewState {
+    /// The getAuthorFeed filter behind the tab, likes come from getActorLikes instead.
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    pub fn author_feed_filter(self) -> Option<&'static str> {
+        match self {
+            UserViewState::Posts => Some("posts_and_author_threads"),
+            UserViewState::Replies => Some("posts_with_replies"),
+            UserViewState::Media => Some("posts_with_media"),
+            UserViewState::Videos => Some("posts_with_video"),
+            UserViewState::Liked => None,
+        }
+    }
+}
+
+/// Posts of the replies, media and videos tabs of every open profile. None while the first page loads.
+#[derive(Default)]
+pub struct UserTabFeeds {
+    pub posts: HashMap<(String, UserViewState), Option<Vec<FeedItem>>>,
+    cursors: HashMap<(String, UserViewState), Option<String>>,
+}
+
+impl UserTabFeeds {
+    pub fn page_loaded(
+        &mut self,
+        username: String,
+        view: UserViewState,
+        items: Vec<FeedItem>,
+        cursor: Option<String>,
+        append: bool,
+    ) {
+        let key = (username, view);
+        match self.posts.get_mut(&key) {
+            Some(Some(existing_posts)) if append => existing_posts.extend(items),
+            _ => {
+                self.posts.insert(key.clone(), Some(items));
+            }
+        }
+        self.cursors.insert(key, cursor);
+    }
+
+    pub fn has_more(&self, username: &str, view: UserViewState) -> bool {
+        matches!(self.cursors.get(&(username.to_string(), view)), Some(Some(_)))
+    }
+
+    /// The cursor for Load More, taken so a second click does not request the same page again.
+    pub fn take_cursor(&mut self, username: &str, view: UserViewState) -> Option<String> {
+        self.cursors.get_mut(&(username.to_string(), view)).and_then(Option::take)
+    }
+
+    pub fn drop_user(&mut self, username: &str) {
+        self.posts.retain(|(name, _), _| name != username);
+        self.cursors.retain(|(name, _), _| name != username);
+    }
+}
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum AppTheme {
     System,
@@ -330,12 +1310,544 @@ pub enum AppTheme {
     Dark,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MutedWordTargets {
+    Content,
+    Tags,
+    Both,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MutedWord {
+    pub id: Option<String>,
+    pub value: String,
+    pub targets: MutedWordTargets,
+    pub expires_at: Option<i64>, // unix timestamp, in seconds
+    #[serde(default)]
+    pub actor_target: Option<String>, // "all" or "exclude-following", set by other clients
+}
+
+impl MutedWord {
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Tags are matched exactly, phrases as substrings and single words on word boundaries.
+    pub fn matches(&self, text: &str, tags: &[String]) -> bool {
+        let value = self.value.trim().trim_start_matches('#').to_lowercase();
+        if value.is_empty() {
+            return false;
+        }
+        if self.targets != MutedWordTargets::Content
+            && tags.iter().any(|tag| tag.to_lowercase() == value)
+        {
+            return true;
+        }
+        if self.targets == MutedWordTargets::Tags {
+            return false;
+        }
+        let text = text.to_lowercase();
+        if value.contains(char::is_whitespace) {
+            return text.contains(&value);
+        }
+        text.split_whitespace().any(|word| {
+            word == value || word.trim_matches(|c: char| !c.is_alphanumeric()) == value
+        })
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LabelVisibility {
+    Show,
+    Warn,
+    Hide,
+}
+
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+impl LabelVisibility {
+    pub fn from_pref(visibility: &str) -> Self {
+        match visibility {
+            "hide" => LabelVisibility::Hide,
+            "warn" => LabelVisibility::Warn,
+            _ => LabelVisibility::Show,
+        }
+    }
+
+    pub fn to_pref(self) -> &'static str {
+        match self {
+            LabelVisibility::Show => "ignore",
+            LabelVisibility::Warn => "warn",
+            LabelVisibility::Hide => "hide",
+        }
+    }
+}
+
+/// (label, description, is adult content, default visibility)
+pub const CONTENT_LABELS: [(&str, &str, bool, LabelVisibility); 4] = [
+    ("porn", "Adult content", true, LabelVisibility::Hide),
+    ("sexual", "Sexually suggestive", true, LabelVisibility::Warn),
+    ("nudity", "Non-sexual nudity", true, LabelVisibility::Show),
+    ("graphic-media", "Graphic media", false, LabelVisibility::Warn),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum ShortcutAction {
+    NextPost,
+    PreviousPost,
+    Like,
+    Reply,
+    Repost,
+    OpenThread,
+    OpenProfile,
+    NewPost,
+    Search,
+    GoToTimeline,
+    GoToNotifications,
+    GoToBookmarks,
+    ShowHelp,
+}
+
+impl ShortcutAction {
+    pub const ALL: [ShortcutAction; 13] = [
+        ShortcutAction::NextPost,
+        ShortcutAction::PreviousPost,
+        ShortcutAction::Like,
+        ShortcutAction::Reply,
+        ShortcutAction::Repost,
+        ShortcutAction::OpenThread,
+        ShortcutAction::OpenProfile,
+        ShortcutAction::NewPost,
+        ShortcutAction::Search,
+        ShortcutAction::GoToTimeline,
+        ShortcutAction::GoToNotifications,
+        ShortcutAction::GoToBookmarks,
+        ShortcutAction::ShowHelp,
+    ];
+
+    pub fn description(self) -> &'static str {
+        match self {
+            ShortcutAction::NextPost => "Select the next post",
+            ShortcutAction::PreviousPost => "Select the previous post",
+            ShortcutAction::Like => "Like the selected post",
+            ShortcutAction::Reply => "Reply to the selected post",
+            ShortcutAction::Repost => "Repost the selected post",
+            ShortcutAction::OpenThread => "Open the thread of the selected post",
+            ShortcutAction::OpenProfile => "Open the profile of the selected post's author",
+            ShortcutAction::NewPost => "New post",
+            ShortcutAction::Search => "Search posts",
+            ShortcutAction::GoToTimeline => "Go to the timeline",
+            ShortcutAction::GoToNotifications => "Go to the notifications",
+            ShortcutAction::GoToBookmarks => "Go to the bookmarks",
+            ShortcutAction::ShowHelp => "Show the keyboard shortcuts",
+        }
+    }
+
+    /// Keys typed one after the other, separated by spaces.
+    pub fn default_keys(self) -> &'static str {
+        match self {
+            ShortcutAction::NextPost => "j",
+            ShortcutAction::PreviousPost => "k",
+            ShortcutAction::Like => "l",
+            ShortcutAction::Reply => "r",
+            ShortcutAction::Repost => "t",
+            ShortcutAction::OpenThread => "o",
+            ShortcutAction::OpenProfile => "p",
+            ShortcutAction::NewPost => "n",
+            ShortcutAction::Search => "/",
+            ShortcutAction::GoToTimeline => "g t",
+            ShortcutAction::GoToNotifications => "g n",
+            ShortcutAction::GoToBookmarks => "g b",
+            ShortcutAction::ShowHelp => "?",
+        }
+    }
+
+    /// Acts on the post selected in the main view.
+    pub fn targets_post(self) -> bool {
+        !matches!(
+            self,
+            ShortcutAction::NewPost
+                | ShortcutAction::Search
+                | ShortcutAction::GoToTimeline
+                | ShortcutAction::GoToNotifications
+                | ShortcutAction::GoToBookmarks
+                | ShortcutAction::ShowHelp
+        )
+    }
+}
+
+/// Matches the typed keys against the bindings, keeping the start of a multi-key sequence.
+#[derive(Clone, Debug, Default)]
+pub struct ShortcutInput {
+    prefix: Vec<String>,
+}
+
+impl ShortcutInput {
+    pub fn key_typed(&mut self, settings: &AppSettings, key: &str) -> Option<ShortcutAction> {
+        self.prefix.push(key.to_string());
+        let mut is_prefix = false;
+        for action in ShortcutAction::ALL {
+            let keys: Vec<&str> = settings.shortcut_keys(action).split_whitespace().collect();
+            if keys == self.prefix {
+                self.prefix.clear();
+                return Some(action);
+            }
+            is_prefix |= keys.len() > self.prefix.len() && keys.iter().zip(&self.prefix).all(|(a, b)| a == b);
+        }
+        if !is_prefix {
+            // A sequence that leads nowhere starts over with the last key
+            let restart = self.prefix.len() > 1;
+            self.prefix.clear();
+            if restart {
+                return self.key_typed(settings, key);
+            }
+        }
+        None
+    }
+
+    pub fn clear(&mut self) {
+        self.prefix.clear();
+    }
+}
+
+/// An action of the command palette. Recently run commands are kept in the settings.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PaletteCommand {
+    NewPost,
+    SearchAccounts,
+    SearchPosts,
+    SearchHistory,
+    OpenLink,
+    GoToProfile,
+    GoToTimeline,
+    GoToNotifications,
+    GoToBookmarks,
+    GoToMessages,
+    RefreshTimeline,
+    OpenSettings,
+    OpenModeration,
+    DiscoverFeeds,
+    OpenLists,
+    EditProfile,
+    ShowShortcuts,
+    SwitchAccount,
+    OpenProfile(String),   // handle or did
+    OpenAtUri(String),     // at:// uri or bsky.app url
+    OpenFeed(String),      // feed generator or list uri
+    PinFeed(String),
+    UnpinFeed(String),
+    DownloadMedia(String), // handle
+}
+
+impl PaletteCommand {
+    /// The commands that don't depend on the query or the saved feeds.
+    pub const STATIC: [PaletteCommand; 18] = [
+        PaletteCommand::NewPost,
+        PaletteCommand::SearchAccounts,
+        PaletteCommand::SearchPosts,
+        PaletteCommand::SearchHistory,
+        PaletteCommand::OpenLink,
+        PaletteCommand::GoToProfile,
+        PaletteCommand::GoToTimeline,
+        PaletteCommand::GoToNotifications,
+        PaletteCommand::GoToBookmarks,
+        PaletteCommand::GoToMessages,
+        PaletteCommand::RefreshTimeline,
+        PaletteCommand::OpenSettings,
+        PaletteCommand::OpenModeration,
+        PaletteCommand::DiscoverFeeds,
+        PaletteCommand::OpenLists,
+        PaletteCommand::EditProfile,
+        PaletteCommand::ShowShortcuts,
+        PaletteCommand::SwitchAccount,
+    ];
+
+    /// `feed_title` names the feed of the feed commands.
+    pub fn title(&self, feed_title: impl Fn(&str) -> String) -> String {
+        match self {
+            PaletteCommand::NewPost => "New post".to_string(),
+            PaletteCommand::SearchAccounts => "Search accounts".to_string(),
+            PaletteCommand::SearchPosts => "Search posts".to_string(),
+            PaletteCommand::SearchHistory => "Search my history".to_string(),
+            PaletteCommand::OpenLink => "Open link".to_string(),
+            PaletteCommand::GoToProfile => "Go to my profile".to_string(),
+            PaletteCommand::GoToTimeline => "Go to the timeline".to_string(),
+            PaletteCommand::GoToNotifications => "Go to the notifications".to_string(),
+            PaletteCommand::GoToBookmarks => "Go to the bookmarks".to_string(),
+            PaletteCommand::GoToMessages => "Go to the messages".to_string(),
+            PaletteCommand::RefreshTimeline => "Refresh timeline".to_string(),
+            PaletteCommand::OpenSettings => "Open settings".to_string(),
+            PaletteCommand::OpenModeration => "Open moderation".to_string(),
+            PaletteCommand::DiscoverFeeds => "Discover feeds".to_string(),
+            PaletteCommand::OpenLists => "Open lists".to_string(),
+            PaletteCommand::EditProfile => "Edit profile".to_string(),
+            PaletteCommand::ShowShortcuts => "Show keyboard shortcuts".to_string(),
+            PaletteCommand::SwitchAccount => "Switch account (log out and forget the saved login)".to_string(),
+            PaletteCommand::OpenProfile(handle) => format!("Open profile @{}", handle),
+            PaletteCommand::OpenAtUri(uri) => format!("Open {}", uri),
+            PaletteCommand::OpenFeed(uri) => format!("Open feed {}", feed_title(uri)),
+            PaletteCommand::PinFeed(uri) => format!("Pin feed {}", feed_title(uri)),
+            PaletteCommand::UnpinFeed(uri) => format!("Unpin feed {}", feed_title(uri)),
+            PaletteCommand::DownloadMedia(handle) => format!("Download media of @{}", handle),
+        }
+    }
+
+    /// Commands built from the query itself, like opening the profile of a typed handle.
+    pub fn from_query(query: &str) -> Vec<PaletteCommand> {
+        let query = query.trim();
+        if query.contains("://") || query.starts_with("bsky.app/") {
+            return vec![PaletteCommand::OpenAtUri(query.to_string())];
+        }
+        let handle = query.trim_start_matches('@');
+        let looks_like_actor = handle.starts_with("did:")
+            || (handle.contains('.') && !handle.contains(char::is_whitespace) && !handle.ends_with('.'));
+        if looks_like_actor {
+            vec![
+                PaletteCommand::OpenProfile(handle.to_string()),
+                PaletteCommand::DownloadMedia(handle.to_string()),
+            ]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Scores `text` against a fuzzy `query`: every query character has to appear in order.
+/// Consecutive characters and characters starting a word score more, None when it doesn't match.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut previous_match: Option<usize> = None;
+    for query_char in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = (pos..text.len()).find(|&i| text[i] == query_char)?;
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous_match = Some(found);
+        pos = found + 1;
+    }
+    // Prefer the shorter titles among equal matches
+    Some(score * 100 - text.len() as i32)
+}
+
+/// Where an at:// uri points to. The uris may name their repo by handle until resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AtUriTarget {
+    Profile(String), // handle or did
+    Post(String),
+    Feed(String),
+    List(String),
+}
+
+impl AtUriTarget {
+    /// Accepts at:// uris, bsky.app urls, handles and dids.
+    pub fn parse_link(link: &str) -> Option<AtUriTarget> {
+        let link = link.trim();
+        if link.starts_with("at://") {
+            return Self::parse(link);
+        }
+        let web_path = ["https://", "http://", ""].iter().find_map(|scheme| {
+            let rest = link.strip_prefix(scheme)?;
+            rest.strip_prefix("bsky.app/").or_else(|| rest.strip_prefix("www.bsky.app/"))
+        });
+        if let Some(path) = web_path {
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            let parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+            let collection = match parts.as_slice() {
+                ["profile", actor] => return Some(AtUriTarget::Profile(actor.to_string())),
+                ["profile", _, "post", _] => "app.bsky.feed.post",
+                ["profile", _, "feed", _] => "app.bsky.feed.generator",
+                ["profile", _, "lists", _] => "app.bsky.graph.list",
+                _ => return None,
+            };
+            return Self::parse(&format!("at://{}/{}/{}", parts[1], collection, parts[3]));
+        }
+        let actor = link.trim_start_matches('@');
+        let is_did = actor.starts_with("did:") && actor.len() > 4;
+        let is_handle = actor.contains('.')
+            && !actor.starts_with('.')
+            && !actor.ends_with('.')
+            && actor.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+        (is_did || is_handle).then(|| AtUriTarget::Profile(actor.to_string()))
+    }
+
+    /// Only at:// uris and bsky.app urls with their scheme, the links the OS hands over to us.
+    pub fn parse_url(link: &str) -> Option<AtUriTarget> {
+        let link = link.trim();
+        ["at://", "https://", "http://"]
+            .iter()
+            .any(|scheme| link.starts_with(scheme))
+            .then(|| Self::parse_link(link))
+            .flatten()
+    }
+
+    pub fn parse(uri: &str) -> Option<AtUriTarget> {
+        let path = uri.strip_prefix("at://")?;
+        let mut parts = path.split('/').filter(|part| !part.is_empty());
+        let authority = parts.next()?.to_string();
+        let target = match (parts.next(), parts.next()) {
+            (None, _) | (Some("app.bsky.actor.profile"), _) => AtUriTarget::Profile(authority),
+            (Some("app.bsky.feed.post"), Some(_)) => AtUriTarget::Post(uri.to_string()),
+            (Some("app.bsky.feed.generator"), Some(_)) => AtUriTarget::Feed(uri.to_string()),
+            (Some("app.bsky.graph.list"), Some(_)) => AtUriTarget::List(uri.to_string()),
+            _ => return None,
+        };
+        Some(target)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LabelAction {
+    Hide(String),
+    Warn(String),
+    BlurMedia(String),
+}
+
+/// Following timeline filters, synced with the feedViewPref of the "home" feed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FeedViewPref {
+    pub hide_reposts: bool,
+    pub hide_replies: bool,
+    pub hide_replies_by_unfollowed: bool, // replies to people we don't follow
+    pub hide_quote_posts: bool,
+}
+
+impl Default for FeedViewPref {
+    /// The lexicon defaults, used until the preferences are loaded.
+    fn default() -> Self {
+        Self {
+            hide_reposts: false,
+            hide_replies: false,
+            hide_replies_by_unfollowed: true,
+            hide_quote_posts: false,
+        }
+    }
+}
+
+impl FeedViewPref {
+    /// `reply_to_followed` is set for replies, telling whether we follow the parent author
+    /// (or are the author).
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    pub fn hides(&self, is_repost: bool, reply_to_followed: Option<bool>, is_quote: bool) -> bool {
+        if is_repost {
+            return self.hide_reposts;
+        }
+        match reply_to_followed {
+            Some(_) if self.hide_replies => return true,
+            Some(false) if self.hide_replies_by_unfollowed => return true,
+            _ => {}
+        }
+        is_quote && self.hide_quote_posts
+    }
+}
+
+/// Where reading stopped in a feed. `cursor` loads the page holding the post, None for the first page.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReadingPosition {
+    pub uri: String,
+    pub cursor: Option<String>,
+}
+
+/// Where the files of one account go under `base`, so that accounts don't see each other's state.
+pub fn account_dir(base: &std::path::Path, did: &str) -> std::path::PathBuf {
+    base.join("accounts").join(did.replace(':', "_"))
+}
+
+/// Reading positions keyed like make_post_view: "Your timeline", or the custom feed / list uri.
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+pub struct ReadingPositions {
+    #[serde(skip)]
+    pub did: String, // the account they belong to, nothing is saved without one
+    #[serde(default)]
+    pub feeds: std::collections::BTreeMap<String, ReadingPosition>,
+}
+
+impl ReadingPositions {
+    pub fn load(did: &str) -> Self {
+        let mut positions = Self::default();
+        if let Some(proj_dirs) = directories::ProjectDirs::from("com", "Redsky", "Redsky") {
+            let path = account_dir(proj_dirs.config_dir(), did).join("reading_positions.toml");
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                if let Ok(loaded) = toml::from_str(&contents) {
+                    positions = loaded;
+                }
+            }
+        }
+        positions.did = did.to_string();
+        positions
+    }
+
+    pub fn save(&self) {
+        if self.did.is_empty() {
+            return;
+        }
+        if let Some(proj_dirs) = directories::ProjectDirs::from("com", "Redsky", "Redsky") {
+            let dir = account_dir(proj_dirs.config_dir(), &self.did);
+            if std::fs::create_dir_all(&dir).is_ok() {
+                if let Ok(contents) = toml::to_string(self) {
+                    let _ = std::fs::write(dir.join("reading_positions.toml"), contents);
+                }
+            }
+        }
+    }
+}
+
+/// A feed being reopened at its reading position, `pages` counts the pages loaded so far.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadingRestore {
+    pub position: ReadingPosition,
+    pub pages: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RestoreStep {
+    LoadPage(String), // cursor of the next page
+    Found,
+    GiveUp,
+}
+
+impl ReadingRestore {
+    /// Called with every page loaded while restoring: pages in until the saved post shows up,
+    /// giving up after MAX_RESTORE_PAGES or at the end of the feed.
+    pub fn page_loaded(&mut self, items: &[FeedItem], next_cursor: Option<String>) -> RestoreStep {
+        self.pages += 1;
+        if items.iter().any(|item| item.uri() == Some(self.position.uri.as_str())) {
+            return RestoreStep::Found;
+        }
+        match next_cursor {
+            Some(cursor) if self.pages < MAX_RESTORE_PAGES => RestoreStep::LoadPage(cursor),
+            _ => RestoreStep::GiveUp,
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AppSettings {
     pub theme: AppTheme,
     pub max_image_size: f32,
     pub zoom_factor: f32,
     pub allow_dehydration: bool,
+    #[serde(default)]
+    pub adult_content_enabled: bool,
+    #[serde(default)]
+    pub muted_words: Vec<MutedWord>,
+    #[serde(default)]
+    pub label_visibility: std::collections::BTreeMap<String, LabelVisibility>,
+    #[serde(default)]
+    pub desktop_notification_reasons: std::collections::BTreeSet<String>,
+    #[serde(default)]
+    pub realtime_updates: bool,
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+    #[serde(default)]
+    pub timeline_view_pref: FeedViewPref,
+    #[serde(default)]
+    pub shortcuts: std::collections::BTreeMap<ShortcutAction, String>, // changed from the defaults
+    #[serde(default)]
+    pub recent_commands_by_account: std::collections::BTreeMap<String, Vec<PaletteCommand>>, // by did, most recent first
 }
 
 impl Default for AppSettings {
@@ -345,6 +1857,15 @@ impl Default for AppSettings {
             max_image_size: 640.0,
             zoom_factor: 1.0,
             allow_dehydration: true,
+            adult_content_enabled: false,
+            muted_words: Vec::new(),
+            label_visibility: std::collections::BTreeMap::new(),
+            desktop_notification_reasons: std::collections::BTreeSet::new(),
+            realtime_updates: false,
+            saved_searches: Vec::new(),
+            timeline_view_pref: FeedViewPref::default(),
+            shortcuts: std::collections::BTreeMap::new(),
+            recent_commands_by_account: std::collections::BTreeMap::new(),
         }
     }
 }
@@ -352,6 +1873,76 @@ impl Default for AppSettings {
 
 
 impl AppSettings {
+    pub fn visibility_for_label(&self, label: &str) -> LabelVisibility {
+        match label {
+            "!hide" => return LabelVisibility::Hide,
+            "!warn" => return LabelVisibility::Warn,
+            _ => {}
+        }
+        let Some((_, _, adult, default)) = CONTENT_LABELS.iter().find(|(l, ..)| *l == label) else {
+            return LabelVisibility::Show;
+        };
+        if *adult && !self.adult_content_enabled {
+            return LabelVisibility::Hide;
+        }
+        self.label_visibility.get(label).copied().unwrap_or(*default)
+    }
+
+    pub fn recent_commands(&self, did: &str) -> &[PaletteCommand] {
+        self.recent_commands_by_account.get(did).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Moves the command to the front of the account's recent commands.
+    pub fn add_recent_command(&mut self, did: &str, command: PaletteCommand) {
+        let recent = self.recent_commands_by_account.entry(did.to_string()).or_default();
+        recent.retain(|known| known != &command);
+        recent.insert(0, command);
+        recent.truncate(MAX_RECENT_COMMANDS);
+    }
+
+    pub fn shortcut_keys(&self, action: ShortcutAction) -> &str {
+        self.shortcuts
+            .get(&action)
+            .map(|keys| keys.as_str())
+            .unwrap_or(action.default_keys())
+    }
+
+    /// Actions bound to the same keys, or to the start of another action's keys, never run.
+    pub fn shortcut_conflicts(&self) -> std::collections::BTreeSet<ShortcutAction> {
+        let mut conflicts = std::collections::BTreeSet::new();
+        for action in ShortcutAction::ALL {
+            let keys: Vec<&str> = self.shortcut_keys(action).split_whitespace().collect();
+            for other in ShortcutAction::ALL {
+                let other_keys: Vec<&str> = self.shortcut_keys(other).split_whitespace().collect();
+                if action == other || keys.is_empty() {
+                    continue;
+                }
+                if other_keys.starts_with(&keys) {
+                    conflicts.insert(action);
+                    conflicts.insert(other);
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Picks the most restrictive action among the labels. Warnings on media labels only blur the media.
+    pub fn moderate_labels(&self, labels: &[String]) -> Option<LabelAction> {
+        let mut action = None;
+        for label in labels {
+            let next = match self.visibility_for_label(label) {
+                LabelVisibility::Show => continue,
+                LabelVisibility::Hide => return Some(LabelAction::Hide(label.clone())),
+                LabelVisibility::Warn if label.starts_with('!') => LabelAction::Warn(label.clone()),
+                LabelVisibility::Warn => LabelAction::BlurMedia(label.clone()),
+            };
+            if !matches!(action, Some(LabelAction::Warn(_))) {
+                action = Some(next);
+            }
+        }
+        action
+    }
+
     pub fn load() -> Self {
         if let Some(proj_dirs) = directories::ProjectDirs::from("com", "Redsky", "Redsky") {
             let config_dir = proj_dirs.config_dir();
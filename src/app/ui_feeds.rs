@@ -0,0 +1,376 @@
+/// List feeds are served by getListFeed, everything else by getFeed.
+fn custom_feed_request(feed_uri: &str, cursor: Option<String>) -> BskyActorMsg {
+    if feed_uri.contains("/app.bsky.graph.list/") {
+        BskyActorMsg::GetListFeed {
+            list_uri: feed_uri.to_string(),
+            cursor,
+        }
+    } else {
+        BskyActorMsg::GetFeed {
+            feed_uri: feed_uri.to_string(),
+            cursor,
+        }
+    }
+}
+
+impl RedskyApp {
+    fn feed_title(&self, feed_uri: &str) -> String {
+        if let Some(generator) = self.feed_generators.get(feed_uri) {
+            return generator.display_name.clone();
+        }
+        if let Some(list) = self.own_lists.iter().find(|list| list.uri == feed_uri) {
+            return list.name.clone();
+        }
+        if let Some((list, _, _)) = self.list_members_cache.get(feed_uri) {
+            return list.name.clone();
+        }
+        feed_uri.rsplit('/').next().unwrap_or(feed_uri).to_string()
+    }
+
+    fn make_custom_feed_tabs(&mut self, ui: &mut Ui) {
+        let mut tab_uris: Vec<String> = self
+            .saved_feeds
+            .iter()
+            .filter(|feed| feed.pinned && (feed.kind == "feed" || feed.kind == "list"))
+            .map(|feed| feed.uri.clone())
+            .collect();
+        // Keep a tab for a feed opened from the discovery window, even when it is not pinned
+        if let MainViewState::CustomFeed(feed_uri) = &self.main_view_state {
+            if !tab_uris.contains(feed_uri) {
+                tab_uris.push(feed_uri.clone());
+            }
+        }
+        for feed_uri in tab_uris {
+            let title = self.feed_title(&feed_uri);
+            ui.selectable_value(
+                &mut self.main_view_state,
+                MainViewState::CustomFeed(feed_uri),
+                RichText::new(title).heading(),
+            );
+        }
+    }
+
+    fn feed_items(&self, feed_key: &str) -> Option<&Vec<FeedItem>> {
+        if feed_key == "Your timeline" {
+            Some(&self.timeline)
+        } else {
+            self.custom_feeds.get(feed_key)
+        }
+    }
+
+    fn feed_cursor(&self, feed_key: &str) -> Option<String> {
+        if feed_key == "Your timeline" {
+            self.timeline_cursor.clone()
+        } else {
+            self.custom_feed_cursors.get(feed_key).cloned().flatten()
+        }
+    }
+
+    /// Loads a page of the timeline or of a custom feed, remembering its cursor so reading
+    /// positions can point at the page holding a post.
+    fn request_feed_page(&mut self, feed_key: &str, cursor: Option<String>) {
+        if let Some(cursor) = &cursor {
+            self.feed_request_cursors.insert(feed_key.to_string(), cursor.clone());
+            // Avoid duplicate requests
+            if feed_key == "Your timeline" {
+                self.timeline_cursor = None;
+            } else {
+                self.custom_feed_cursors.insert(feed_key.to_string(), None);
+            }
+        }
+        if feed_key == "Your timeline" {
+            self.post_message(BskyActorMsg::GetTimeline { cursor });
+        } else {
+            self.post_message(custom_feed_request(feed_key, cursor));
+        }
+    }
+
+    /// Called with every page of a feed, `request_cursor` is None for the first page.
+    fn record_feed_page(&mut self, feed_key: &str, posts: &[Post], request_cursor: Option<String>) {
+        if let Some(cursor) = request_cursor {
+            let page_cursors = self.feed_page_cursors.entry(feed_key.to_string()).or_default();
+            for post in posts {
+                page_cursors.entry(post.uri.clone()).or_insert_with(|| cursor.clone());
+            }
+        }
+    }
+
+    /// Keeps the post being read in place when posts get prepended above it.
+    fn anchor_feed_scroll(&mut self, feed_key: &str) {
+        if self.feed_scroll_anchors.contains_key(feed_key) {
+            return;
+        }
+        if let Some(top_visible) = self.feed_top_visible.get(feed_key).cloned() {
+            self.feed_scroll_anchors.insert(feed_key.to_string(), top_visible);
+        }
+    }
+
+    /// Opens a feed at its saved reading position, or at the top when there is none.
+    fn restore_reading_position(&mut self, feed_key: &str) {
+        match self.reading_positions.feeds.get(feed_key).cloned() {
+            Some(position) => {
+                let cursor = position.cursor.clone();
+                self.restoring_feeds
+                    .insert(feed_key.to_string(), ReadingRestore { position, pages: 0 });
+                self.request_feed_page(feed_key, cursor);
+            }
+            None => self.request_feed_page(feed_key, None),
+        }
+    }
+
+    /// Pages in until the saved post shows up, then scrolls to it and marks where unread posts begin.
+    fn continue_reading_restore(&mut self, feed_key: &str) {
+        let Some(mut restore) = self.restoring_feeds.remove(feed_key) else {
+            return;
+        };
+        let next_cursor = self.feed_cursor(feed_key);
+        let step = restore.page_loaded(self.feed_items(feed_key).map(Vec::as_slice).unwrap_or(&[]), next_cursor);
+        let position = restore.position.clone();
+        if let RestoreStep::LoadPage(cursor) = step {
+            self.restoring_feeds.insert(feed_key.to_string(), restore);
+            self.request_feed_page(feed_key, Some(cursor));
+            return;
+        }
+        if step == RestoreStep::Found {
+            // Leave room above the post for the unread marker
+            self.feed_scroll_anchors
+                .insert(feed_key.to_string(), (position.uri.clone(), -UNREAD_MARKER_HEIGHT));
+            self.unread_markers.insert(feed_key.to_string(), position.uri);
+        }
+        if position.cursor.is_some() {
+            // Restoring started below the first page, bring in the newer posts above
+            if feed_key != "Your timeline" {
+                self.restored_feed_heads.insert(feed_key.to_string());
+            }
+            self.request_feed_page(feed_key, None);
+        }
+    }
+
+    fn update_reading_position(&mut self, feed_key: &str, uri: &str) {
+        if self.restoring_feeds.contains_key(feed_key) {
+            return;
+        }
+        let position = ReadingPosition {
+            uri: uri.to_string(),
+            cursor: self
+                .feed_page_cursors
+                .get(feed_key)
+                .and_then(|page_cursors| page_cursors.get(uri))
+                .cloned(),
+        };
+        if self.reading_positions.feeds.get(feed_key) != Some(&position) {
+            self.reading_positions.feeds.insert(feed_key.to_string(), position);
+            self.reading_positions_dirty = true;
+        }
+    }
+
+    fn save_reading_positions(&mut self, force: bool) {
+        if self.reading_positions_dirty
+            && (force || self.last_reading_positions_save.elapsed() >= READING_POSITION_SAVE_INTERVAL)
+        {
+            self.reading_positions.save();
+            self.reading_positions_dirty = false;
+            self.last_reading_positions_save = std::time::Instant::now();
+        }
+    }
+
+    fn is_feed_saved(&self, feed_uri: &str) -> bool {
+        self.saved_feeds.iter().any(|feed| feed.uri == feed_uri)
+    }
+
+    fn is_feed_pinned(&self, feed_uri: &str) -> bool {
+        self.saved_feeds.iter().any(|feed| feed.uri == feed_uri && feed.pinned)
+    }
+
+    fn update_saved_feeds<F>(&mut self, update_fn: F)
+    where
+        F: FnOnce(&mut Vec<SavedFeed>),
+    {
+        update_fn(&mut self.saved_feeds);
+        self.post_message(BskyActorMsg::PutSavedFeeds {
+            saved: self.saved_feeds.clone(),
+        });
+    }
+
+    fn save_feed(&mut self, generator: &FeedGenerator, pinned: bool) {
+        self.feed_generators.insert(generator.uri.clone(), generator.clone());
+        self.save_feed_uri(&generator.uri, "feed", pinned);
+    }
+
+    fn save_feed_uri(&mut self, feed_uri: &str, kind: &str, pinned: bool) {
+        let feed_uri = feed_uri.to_string();
+        let kind = kind.to_string();
+        let id = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| format!("{:x}", d.as_nanos()))
+            .unwrap_or_default();
+        self.update_saved_feeds(|saved| {
+            if let Some(feed) = saved.iter_mut().find(|feed| feed.uri == feed_uri) {
+                feed.pinned = pinned;
+            } else {
+                saved.push(SavedFeed {
+                    id,
+                    kind,
+                    uri: feed_uri,
+                    pinned,
+                });
+            }
+        });
+    }
+
+    fn unsave_feed(&mut self, feed_uri: &str) {
+        self.update_saved_feeds(|saved| saved.retain(|feed| feed.uri != feed_uri));
+    }
+
+    fn make_feed_generator_row(&mut self, ui: &mut Ui, generator: &FeedGenerator) {
+        ui.horizontal(|ui| {
+            if let Some(Some(texture)) = self.image_cache.get(&generator.avatar_uri) {
+                ui.add(egui::Image::new(texture).max_width(40.0).max_height(40.0));
+            }
+            ui.vertical(|ui| {
+                ui.label(RichText::new(&generator.display_name).strong());
+                ui.label(
+                    RichText::new(format!(
+                        "by @{} - liked by {} users",
+                        generator.creator_handle, generator.like_count
+                    ))
+                    .small()
+                    .weak(),
+                );
+            });
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                let saved = self.is_feed_saved(&generator.uri);
+                let pinned = self.is_feed_pinned(&generator.uri);
+                if ui.button("Open").clicked() {
+                    self.feed_generators.insert(generator.uri.clone(), generator.clone());
+                    self.main_view_state = MainViewState::CustomFeed(generator.uri.clone());
+                }
+                if ui
+                    .button(if pinned { "Unpin" } else { "Pin" })
+                    .on_hover_text("Pinned feeds get a tab in the main window")
+                    .clicked()
+                {
+                    self.save_feed(generator, !pinned);
+                }
+                if saved {
+                    if ui.button("Unsave").clicked() {
+                        self.unsave_feed(&generator.uri);
+                    }
+                } else if ui.button("Save").clicked() {
+                    self.save_feed(generator, false);
+                }
+            });
+        });
+        if !generator.description.is_empty() {
+            ui.label(&generator.description);
+        }
+        ui.separator();
+    }
+
+    fn make_feeds_window(&mut self, ctx: &egui::Context) {
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("__feeds"),
+            egui::ViewportBuilder::default()
+                .with_title("Feeds")
+                .with_inner_size([500.0, 600.0]),
+            |ui, _| {
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.selectable_value(
+                                &mut self.feeds_discovery_kind,
+                                FeedDiscoveryKind::Saved,
+                                RichText::new("Saved").heading(),
+                            );
+                            ui.selectable_value(
+                                &mut self.feeds_discovery_kind,
+                                FeedDiscoveryKind::Suggested,
+                                RichText::new("Suggested").heading(),
+                            );
+                            let popular = ui.selectable_value(
+                                &mut self.feeds_discovery_kind,
+                                FeedDiscoveryKind::Popular,
+                                RichText::new("Popular").heading(),
+                            );
+                            if popular.clicked() && self.popular_feeds.is_empty() {
+                                self.post_message(BskyActorMsg::GetPopularFeeds {
+                                    query: None,
+                                    cursor: None,
+                                });
+                            }
+                        });
+                        ui.separator();
+
+                        let generators: Vec<FeedGenerator> = match self.feeds_discovery_kind {
+                            FeedDiscoveryKind::Saved => self
+                                .saved_feeds
+                                .iter()
+                                .filter(|feed| feed.kind == "feed")
+                                .filter_map(|feed| self.feed_generators.get(&feed.uri).cloned())
+                                .collect(),
+                            FeedDiscoveryKind::Suggested => self.suggested_feeds.clone(),
+                            FeedDiscoveryKind::Popular => {
+                                ui.horizontal(|ui| {
+                                    let response = ui.add(
+                                        egui::TextEdit::singleline(&mut self.feeds_search_query)
+                                            .hint_text("Search feeds"),
+                                    );
+                                    if (response.lost_focus()
+                                        && ui.input(|i| i.key_pressed(egui::Key::Enter)))
+                                        || ui.button("Search").clicked()
+                                    {
+                                        let query = self.feeds_search_query.trim().to_string();
+                                        self.post_message(BskyActorMsg::GetPopularFeeds {
+                                            query: (!query.is_empty()).then_some(query),
+                                            cursor: None,
+                                        });
+                                    }
+                                });
+                                self.popular_feeds.clone()
+                            }
+                        };
+
+                        crate::app::show_autoscroll_area(ui, "feeds_scroll", false, |ui| {
+                            if generators.is_empty() {
+                                ui.label(RichText::new("No feeds").weak());
+                            }
+                            for generator in &generators {
+                                self.make_feed_generator_row(ui, generator);
+                            }
+
+                            match self.feeds_discovery_kind {
+                                FeedDiscoveryKind::Suggested => {
+                                    if let Some(cursor) = self.suggested_feeds_cursor.clone() {
+                                        if ui.button("Load More").clicked() {
+                                            self.post_message(BskyActorMsg::GetSuggestedFeeds {
+                                                cursor: Some(cursor),
+                                            });
+                                            self.suggested_feeds_cursor = None;
+                                        }
+                                    }
+                                }
+                                FeedDiscoveryKind::Popular => {
+                                    if let Some(cursor) = self.popular_feeds_cursor.clone() {
+                                        if ui.button("Load More").clicked() {
+                                            let query = self.feeds_search_query.trim().to_string();
+                                            self.post_message(BskyActorMsg::GetPopularFeeds {
+                                                query: (!query.is_empty()).then_some(query),
+                                                cursor: Some(cursor),
+                                            });
+                                            self.popular_feeds_cursor = None;
+                                        }
+                                    }
+                                }
+                                FeedDiscoveryKind::Saved => {}
+                            }
+                        });
+                    });
+                });
+
+                if ui.ctx().input(|i| i.viewport().close_requested()) {
+                    self.is_feeds_window_open = false;
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,333 @@
+impl RedskyApp {
+    fn update_list_membership(&mut self, list_uri: &str, did: &str, item_uri: Option<RecordState>) {
+        let mut delta: i64 = 0;
+        if let Some(Some(memberships)) = self.list_memberships.get_mut(did) {
+            if let Some(membership) = memberships.iter_mut().find(|m| m.list.uri == list_uri) {
+                delta = match (&membership.item_uri, &item_uri) {
+                    (None, Some(_)) => 1,
+                    (Some(_), None) => -1,
+                    _ => 0,
+                };
+                membership.item_uri = item_uri;
+            }
+        }
+        if delta == 0 {
+            return;
+        }
+        let update_count = |list: &mut UserList| {
+            list.item_count = (list.item_count as i64 + delta).max(0) as usize;
+        };
+        self.own_lists
+            .iter_mut()
+            .filter(|list| list.uri == list_uri)
+            .for_each(update_count);
+        if let Some((list, _, _)) = self.list_members_cache.get_mut(list_uri) {
+            update_count(list);
+        }
+        for membership in self.list_memberships.values_mut().flatten().flatten() {
+            if membership.list.uri == list_uri {
+                update_count(&mut membership.list);
+            }
+        }
+    }
+
+    fn find_profile(&self, did: &str) -> Option<UserProfile> {
+        self.user_infos_cache
+            .values()
+            .chain(self.search_results.iter())
+            .chain(self.user_lists_cache.values().flat_map(|(users, _)| users))
+            .chain(self.post_likers_cache.values().flat_map(|(users, _)| users))
+            .chain(self.post_reposters_cache.values().flat_map(|(users, _)| users))
+            .find(|profile| profile.did == did)
+            .cloned()
+    }
+
+    fn make_add_to_list_menu(&self, ui: &mut Ui, profile: &UserProfile) {
+        ui.menu_button("Lists", |ui| {
+            match self.list_memberships.get(&profile.did) {
+                Some(Some(memberships)) => {
+                    if memberships.is_empty() {
+                        ui.label(RichText::new("You have no lists").weak());
+                    }
+                    for membership in memberships {
+                        let mut is_member = membership.item_uri.is_some();
+                        let pending = membership.item_uri == Some(RecordState::Pending);
+                        let checkbox = ui.add_enabled(
+                            !pending,
+                            egui::Checkbox::new(&mut is_member, &membership.list.name),
+                        );
+                        if checkbox.changed() {
+                            self.post_ui_message(RedskyUiMsg::PrepareListMemberAction {
+                                list_uri: membership.list.uri.clone(),
+                                did: profile.did.clone(),
+                                item_uri: membership.item_uri.as_ref().and_then(RecordState::uri).map(str::to_string),
+                            });
+                        }
+                    }
+                }
+                Some(None) => {
+                    ui.spinner();
+                }
+                None => {
+                    self.post_ui_message(RedskyUiMsg::PrepareListMemberships {
+                        did: profile.did.clone(),
+                    });
+                    ui.spinner();
+                }
+            }
+        });
+    }
+
+    fn open_list_form(&mut self, list: Option<&UserList>) {
+        self.is_list_form_open = true;
+        self.list_form_uri = list.map(|list| list.uri.clone());
+        self.list_name_input = list.map(|list| list.name.clone()).unwrap_or_default();
+        self.list_description_input = list.map(|list| list.description.clone()).unwrap_or_default();
+        self.list_purpose_input = list
+            .map(|list| list.purpose.clone())
+            .unwrap_or(CURATE_LIST_PURPOSE.to_string());
+    }
+
+    fn submit_list_form(&mut self) {
+        let name = self.list_name_input.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let description = self.list_description_input.trim().to_string();
+        match self.list_form_uri.clone() {
+            Some(list_uri) => {
+                self.post_message(BskyActorMsg::UpdateList {
+                    list_uri: list_uri.clone(),
+                    name: name.clone(),
+                    description: description.clone(),
+                });
+                let lists = self.own_lists.iter_mut().chain(
+                    self.list_members_cache
+                        .get_mut(&list_uri)
+                        .map(|(list, _, _)| list),
+                );
+                for list in lists.filter(|list| list.uri == list_uri) {
+                    list.name = name.clone();
+                    list.description = description.clone();
+                }
+            }
+            None => {
+                self.post_message(BskyActorMsg::CreateList {
+                    name,
+                    description,
+                    purpose: self.list_purpose_input.clone(),
+                });
+            }
+        }
+        self.is_list_form_open = false;
+    }
+
+    fn make_list_form(&mut self, ui: &mut Ui) {
+        ui.heading(if self.list_form_uri.is_some() { "Edit list" } else { "New list" });
+        let mut submit = false;
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            let response = ui.text_edit_singleline(&mut self.list_name_input);
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                submit = true;
+            }
+        });
+        ui.label("Description:");
+        let response = ui.text_edit_multiline(&mut self.list_description_input);
+        if response.has_focus() && ui.input(|i| i.modifiers.command && i.key_pressed(egui::Key::Enter)) {
+            submit = true;
+        }
+        if self.list_form_uri.is_none() {
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut self.list_purpose_input, CURATE_LIST_PURPOSE.to_string(), "Curation list");
+                ui.radio_value(&mut self.list_purpose_input, MOD_LIST_PURPOSE.to_string(), "Moderation list");
+            });
+        }
+        ui.horizontal(|ui| {
+            if ui.button("Save").on_hover_text("Enter or Cmd+Enter").clicked() {
+                submit = true;
+            }
+            if ui.button("Cancel").clicked() {
+                self.is_list_form_open = false;
+            }
+        });
+        if submit {
+            self.submit_list_form();
+        }
+    }
+
+    fn make_list_details(&mut self, ui: &mut Ui, list_uri: &str) {
+        let Some((list, members, cursor)) = self.list_members_cache.get(list_uri).cloned() else {
+            ui.spinner();
+            return;
+        };
+        ui.heading(&list.name);
+        let purpose = if list.purpose == MOD_LIST_PURPOSE {
+            "Moderation list"
+        } else {
+            "Curation list"
+        };
+        ui.label(
+            RichText::new(format!(
+                "{} by @{} - {} member(s)",
+                purpose, list.creator_handle, list.item_count
+            ))
+            .small()
+            .weak(),
+        );
+        if !list.description.is_empty() {
+            ui.label(&list.description);
+        }
+        ui.horizontal(|ui| {
+            if list.purpose == CURATE_LIST_PURPOSE {
+                if ui.button("Open feed").clicked() {
+                    self.main_view_state = MainViewState::CustomFeed(list.uri.clone());
+                }
+                let pinned = self.is_feed_pinned(&list.uri);
+                if ui
+                    .button(if pinned { "Unpin" } else { "Pin as tab" })
+                    .on_hover_text("Pinned lists get a tab in the main window")
+                    .clicked()
+                {
+                    if pinned {
+                        self.unsave_feed(&list.uri);
+                    } else {
+                        self.save_feed_uri(&list.uri, "list", true);
+                    }
+                }
+            }
+            if list.creator_handle == self.login {
+                if ui.button("Edit").clicked() {
+                    self.open_list_form(Some(&list));
+                }
+                ui.menu_button("Delete", |ui| {
+                    if ui.button(format!("Really delete {}?", list.name)).clicked() {
+                        self.post_message(BskyActorMsg::DeleteList {
+                            list_uri: list.uri.clone(),
+                        });
+                        self.own_lists.retain(|l| l.uri != list.uri);
+                        self.list_members_cache.remove(&list.uri);
+                        self.selected_list = None;
+                        // Don't leave a tab for the deleted list
+                        if self.saved_feeds.iter().any(|feed| feed.uri == list.uri) {
+                            self.unsave_feed(&list.uri);
+                        }
+                        if self.main_view_state == MainViewState::CustomFeed(list.uri.clone()) {
+                            self.main_view_state = MainViewState::TimelineFeed;
+                        }
+                        ui.close();
+                    }
+                });
+            }
+        });
+        ui.separator();
+
+        crate::app::show_autoscroll_area(ui, format!("list_members_{}", list.uri), false, |ui| {
+            if members.is_empty() {
+                ui.label(RichText::new("No members yet. Add people from their profile's … menu.").weak());
+            }
+            for member in &members {
+                ui.horizontal(|ui| {
+                    if let Some(Some(texture)) = self.image_cache.get(&member.profile.avatar_uri) {
+                        ui.add(egui::Image::new(texture).max_width(32.0).max_height(32.0));
+                    }
+                    if ui
+                        .link(RichText::new(&member.profile.display_name).strong())
+                        .clicked()
+                    {
+                        self.post_ui_message(RedskyUiMsg::PrepareUserView {
+                            username: member.profile.handle.clone(),
+                        });
+                        self.post_message(BskyActorMsg::GetUserPosts {
+                            username: member.profile.handle.clone(),
+                            view: UserViewState::Posts,
+                            cursor: None,
+                        });
+                    }
+                    ui.label(RichText::new(format!("@{}", member.profile.handle)).small());
+                    if list.creator_handle == self.login {
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            if ui.small_button("Remove").clicked() {
+                                self.post_ui_message(RedskyUiMsg::PrepareListMemberAction {
+                                    list_uri: list.uri.clone(),
+                                    did: member.profile.did.clone(),
+                                    item_uri: Some(member.item_uri.clone()),
+                                });
+                            }
+                        });
+                    }
+                });
+                ui.separator();
+            }
+            if let Some(cursor) = cursor {
+                if ui.button("Load More").clicked() {
+                    self.post_message(BskyActorMsg::GetList {
+                        list_uri: list.uri.clone(),
+                        cursor: Some(cursor),
+                    });
+                    if let Some(entry) = self.list_members_cache.get_mut(&list.uri) {
+                        entry.2 = None; // Avoid duplicate requests
+                    }
+                }
+            }
+        });
+    }
+
+    fn make_lists_window(&mut self, ctx: &egui::Context) {
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("__lists"),
+            egui::ViewportBuilder::default()
+                .with_title("Lists")
+                .with_inner_size([700.0, 600.0]),
+            |ui, _| {
+                egui::Panel::left("lists_panel")
+                    .resizable(true)
+                    .default_size(220.0)
+                    .show_inside(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.heading("My lists");
+                            if ui.button("New").clicked() {
+                                self.open_list_form(None);
+                            }
+                        });
+                        ui.separator();
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            for list in self.own_lists.clone() {
+                                let selected = self.selected_list.as_deref() == Some(list.uri.as_str());
+                                let text = format!("{} ({})", list.name, list.item_count);
+                                if ui.selectable_label(selected, text).clicked() {
+                                    self.is_list_form_open = false;
+                                    self.selected_list = Some(list.uri.clone());
+                                    if !self.list_members_cache.contains_key(&list.uri) {
+                                        self.post_message(BskyActorMsg::GetList {
+                                            list_uri: list.uri.clone(),
+                                            cursor: None,
+                                        });
+                                    }
+                                }
+                            }
+                            if let Some(cursor) = self.own_lists_cursor.clone() {
+                                if ui.button("Load More").clicked() {
+                                    self.post_message(BskyActorMsg::GetLists { cursor: Some(cursor) });
+                                    self.own_lists_cursor = None;
+                                }
+                            }
+                        });
+                    });
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    if self.is_list_form_open {
+                        self.make_list_form(ui);
+                    } else if let Some(list_uri) = self.selected_list.clone() {
+                        self.make_list_details(ui, &list_uri);
+                    } else {
+                        ui.label(RichText::new("Select a list").weak());
+                    }
+                });
+
+                if ui.ctx().input(|i| i.viewport().close_requested()) {
+                    self.is_lists_window_open = false;
+                }
+            },
+        );
+    }
+}
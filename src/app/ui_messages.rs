@@ -0,0 +1,304 @@
+impl RedskyApp {
+    /// getLog is polled often while a conversation is on screen, and along with the
+    /// notifications otherwise.
+    fn poll_chat_log(&mut self, ctx: &egui::Context) {
+        if self.main_view_state == MainViewState::Login || self.is_offline {
+            return;
+        }
+        let interval = if self.main_view_state == MainViewState::Messages || !self.open_convos.is_empty() {
+            CHAT_LOG_ACTIVE_POLL_INTERVAL
+        } else {
+            UNREAD_POLL_INTERVAL
+        };
+        if self.last_chat_log_poll.elapsed() >= interval {
+            self.last_chat_log_poll = std::time::Instant::now();
+            self.post_message(BskyActorMsg::GetChatLog {
+                cursor: self.chat_log_cursor.clone(),
+            });
+        }
+        ctx.request_repaint_after(interval.saturating_sub(self.last_chat_log_poll.elapsed()));
+    }
+
+    fn unread_messages(&self) -> i64 {
+        self.convos
+            .iter()
+            .filter(|convo| !convo.muted)
+            .map(|convo| convo.unread_count)
+            .sum()
+    }
+
+    fn upsert_convo(&mut self, convo: Conversation) {
+        for member in &convo.members {
+            self.request_image(&member.avatar_uri);
+        }
+        match self.convos.iter_mut().find(|c| c.id == convo.id) {
+            Some(existing) => *existing = convo,
+            None => self.convos.insert(0, convo),
+        }
+    }
+
+    fn open_convo(&mut self, convo_id: &str) {
+        if !self.open_convos.iter().any(|id| id == convo_id) {
+            self.open_convos.push(convo_id.to_string());
+        }
+        if !self.convo_messages.contains_key(convo_id) {
+            self.post_message(BskyActorMsg::GetMessages {
+                convo_id: convo_id.to_string(),
+                cursor: None,
+            });
+        }
+        let unread = self
+            .convos
+            .iter()
+            .any(|convo| convo.id == convo_id && convo.unread_count > 0);
+        if unread {
+            self.post_message(BskyActorMsg::UpdateConvoRead {
+                convo_id: convo_id.to_string(),
+            });
+        }
+    }
+
+    fn remove_convo(&mut self, convo_id: &str) {
+        self.convos.retain(|convo| convo.id != convo_id);
+        self.open_convos.retain(|id| id != convo_id);
+        self.convo_messages.remove(convo_id);
+        self.chat_inputs.remove(convo_id);
+    }
+
+    fn add_chat_message(&mut self, convo_id: &str, message: ChatMessage) {
+        if let Some((messages, _)) = self.convo_messages.get_mut(convo_id) {
+            if !messages.iter().any(|m| m.id == message.id) {
+                messages.push(message.clone());
+            }
+        }
+        if let Some(convo) = self.convos.iter_mut().find(|convo| convo.id == convo_id) {
+            convo.last_message = Some(message);
+        }
+    }
+
+    fn apply_chat_log(&mut self, events: Vec<ChatLogEvent>) {
+        let mut refresh_convos = false;
+        for event in events {
+            match event {
+                ChatLogEvent::NewMessage { convo_id, message } => {
+                    if !self.convos.iter().any(|convo| convo.id == convo_id) {
+                        refresh_convos = true;
+                        continue;
+                    }
+                    let from_me = message.sender_did == self.session_did;
+                    let is_open = self.open_convos.contains(&convo_id);
+                    self.add_chat_message(&convo_id, message);
+                    if from_me {
+                        continue;
+                    }
+                    if is_open {
+                        self.post_message(BskyActorMsg::UpdateConvoRead { convo_id });
+                    } else if let Some(convo) = self.convos.iter_mut().find(|convo| convo.id == convo_id) {
+                        convo.unread_count += 1;
+                    }
+                }
+                ChatLogEvent::MessageDeleted { convo_id, message_id } => {
+                    if let Some((messages, _)) = self.convo_messages.get_mut(&convo_id) {
+                        for message in messages.iter_mut().filter(|m| m.id == message_id) {
+                            message.deleted = true;
+                            message.text.clear();
+                        }
+                    }
+                }
+                ChatLogEvent::ConvoLeft { convo_id } => self.remove_convo(&convo_id),
+                ChatLogEvent::ConvoChanged { convo_id } => {
+                    if !self.convos.iter().any(|convo| convo.id == convo_id) {
+                        refresh_convos = true;
+                    }
+                }
+            }
+        }
+        if refresh_convos {
+            self.post_message(BskyActorMsg::ListConvos { cursor: None });
+        }
+    }
+
+    fn make_convo_menu(&mut self, ui: &mut Ui, convo: &Conversation) {
+        ui.menu_button("…", |ui| {
+            let mute_text = if convo.muted { "Unmute conversation" } else { "Mute conversation" };
+            if ui
+                .button(mute_text)
+                .on_hover_text("Muted conversations do not count as unread")
+                .clicked()
+            {
+                self.post_message(BskyActorMsg::MuteConvo {
+                    convo_id: convo.id.clone(),
+                    mute: !convo.muted,
+                });
+                ui.close();
+            }
+            ui.menu_button("Leave conversation", |ui| {
+                if ui.button("Really leave? History will be removed").clicked() {
+                    self.post_message(BskyActorMsg::LeaveConvo {
+                        convo_id: convo.id.clone(),
+                    });
+                    self.remove_convo(&convo.id);
+                    ui.close();
+                }
+            });
+        });
+    }
+
+    fn make_messages_view(&mut self, ui: &mut Ui) {
+        crate::app::show_autoscroll_area(ui, "convos_scroll", false, |ui| {
+            if self.convos.is_empty() {
+                ui.label(RichText::new("No conversations. Use \"Message\" on a profile to start one.").weak());
+            }
+            for convo in self.convos.clone() {
+                ui.horizontal(|ui| {
+                    let other = convo
+                        .members
+                        .iter()
+                        .find(|member| member.handle != self.login)
+                        .or(convo.members.first());
+                    if let Some(Some(texture)) = other.and_then(|member| self.image_cache.get(&member.avatar_uri)) {
+                        ui.add(egui::Image::new(texture).max_width(32.0).max_height(32.0));
+                    }
+                    let response = ui
+                        .vertical(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(RichText::new(convo.title(&self.session_did)).strong());
+                                if convo.muted {
+                                    ui.label("🔇");
+                                }
+                                if convo.unread_count > 0 {
+                                    ui.label(
+                                        RichText::new(format!("{} new", convo.unread_count))
+                                            .color(egui::Color32::RED),
+                                    );
+                                }
+                            });
+                            let preview = match &convo.last_message {
+                                Some(message) if message.deleted => "Message deleted".to_string(),
+                                Some(message) => message.text.lines().next().unwrap_or_default().to_string(),
+                                None => String::new(),
+                            };
+                            ui.label(RichText::new(preview).small().weak());
+                        })
+                        .response
+                        .interact(Sense::click())
+                        .on_hover_text("Open conversation");
+                    if response.clicked() {
+                        self.open_convo(&convo.id);
+                    }
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        self.make_convo_menu(ui, &convo);
+                    });
+                });
+                ui.separator();
+            }
+            if let Some(cursor) = self.convos_cursor.clone() {
+                if ui.button("Load More").clicked() {
+                    self.post_message(BskyActorMsg::ListConvos { cursor: Some(cursor) });
+                    self.convos_cursor = None;
+                }
+            }
+        });
+    }
+
+    fn send_chat_input(&mut self, convo_id: &str) {
+        let Some(input) = self.chat_inputs.get_mut(convo_id) else { return };
+        let text = input.trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        input.clear();
+        self.post_message(BskyActorMsg::SendMessage {
+            convo_id: convo_id.to_string(),
+            text,
+        });
+    }
+
+    fn make_convo_windows(&mut self, ctx: &egui::Context) {
+        for convo_id in self.open_convos.clone() {
+            let Some(convo) = self.convos.iter().find(|convo| convo.id == convo_id).cloned() else {
+                continue;
+            };
+            ctx.show_viewport_immediate(
+                egui::ViewportId::from_hash_of(("convo", &convo_id)),
+                egui::ViewportBuilder::default()
+                    .with_title(format!("Chat with {}", convo.title(&self.session_did)))
+                    .with_inner_size([400.0, 600.0]),
+                |ui, _| {
+                    egui::Panel::bottom(format!("chat_input_{}", convo_id)).show_inside(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            let input = self.chat_inputs.entry(convo_id.clone()).or_default();
+                            let response = ui.add(
+                                egui::TextEdit::singleline(input)
+                                    .hint_text("Write a message")
+                                    .desired_width(ui.available_width() - 60.0),
+                            );
+                            let enter = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            if ui.button("Send").on_hover_text("Enter").clicked() || enter {
+                                self.send_chat_input(&convo_id);
+                                response.request_focus();
+                            }
+                        });
+                    });
+                    egui::CentralPanel::default().show_inside(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.heading(convo.title(&self.session_did));
+                            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                self.make_convo_menu(ui, &convo);
+                            });
+                        });
+                        ui.separator();
+                        let Some((messages, cursor)) = self.convo_messages.get(&convo_id).cloned() else {
+                            ui.spinner();
+                            return;
+                        };
+                        egui::ScrollArea::vertical()
+                            .auto_shrink([false, false])
+                            .stick_to_bottom(true)
+                            .show(ui, |ui| {
+                                if let Some(cursor) = cursor {
+                                    if ui.button("Load older messages").clicked() {
+                                        self.post_message(BskyActorMsg::GetMessages {
+                                            convo_id: convo_id.clone(),
+                                            cursor: Some(cursor),
+                                        });
+                                        if let Some(entry) = self.convo_messages.get_mut(&convo_id) {
+                                            entry.1 = None; // Avoid duplicate requests
+                                        }
+                                    }
+                                }
+                                for message in &messages {
+                                    let from_me = message.sender_did == self.session_did;
+                                    let layout = if from_me {
+                                        Layout::top_down(Align::Max)
+                                    } else {
+                                        Layout::top_down(Align::Min)
+                                    };
+                                    ui.with_layout(layout, |ui| {
+                                        let sender = convo
+                                            .members
+                                            .iter()
+                                            .find(|member| member.did == message.sender_did)
+                                            .map(|member| format!("@{}", member.handle))
+                                            .unwrap_or_default();
+                                        ui.label(RichText::new(format!("{} {}", sender, message.sent_at)).small().weak());
+                                        egui::Frame::group(ui.style()).show(ui, |ui| {
+                                            if message.deleted {
+                                                ui.label(RichText::new("Message deleted").italics().weak());
+                                            } else {
+                                                ui.label(&message.text);
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+
+                    if ui.ctx().input(|i| i.viewport().close_requested()) {
+                        self.open_convos.retain(|id| id != &convo_id);
+                    }
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+impl RedskyApp {
+    fn is_author_blocked(&self, post: &Post) -> bool {
+        self.block_overrides
+            .get(&post.author_did)
+            .copied()
+            .unwrap_or(post.author_blocked)
+    }
+
+    fn is_author_muted(&self, post: &Post) -> bool {
+        self.mute_overrides
+            .get(&post.author_did)
+            .copied()
+            .unwrap_or(post.author_muted)
+    }
+
+    fn post_hidden_reason(&self, post: &Post) -> Option<String> {
+        if self.revealed_posts.contains(&post.uri) {
+            return None;
+        }
+        if self.is_author_muted(post) {
+            return Some(format!("muted account @{}", post.author));
+        }
+        if post.author != self.login {
+            let now = unix_now();
+            if let Some(word) = self.settings.muted_words.iter().find(|word| {
+                !word.is_expired(now) && word.matches(&post.content, &post.tags)
+            }) {
+                return Some(format!("matched '{}'", word.value));
+            }
+        }
+        None
+    }
+
+    fn post_label_action(&self, post: &Post) -> Option<LabelAction> {
+        if post.author == self.login {
+            return None;
+        }
+        match self.settings.moderate_labels(&post.labels)? {
+            LabelAction::Warn(_) if self.revealed_posts.contains(&post.uri) => None,
+            LabelAction::BlurMedia(_) if self.revealed_media.contains(&post.uri) => None,
+            action => Some(action),
+        }
+    }
+
+    fn make_content_warning_stub(&mut self, ui: &mut Ui, post_uri: &str, label: &str) {
+        egui::Frame::new()
+            .inner_margin(8)
+            .corner_radius(8)
+            .fill(ui.visuals().faint_bg_color)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(format!("⚠ Content warning: {}", label)).strong());
+                    if ui.small_button("Show").clicked() {
+                        self.revealed_posts.insert(post_uri.to_string());
+                    }
+                });
+            });
+    }
+
+    fn make_blurred_media_placeholder(&mut self, ui: &mut Ui, post_uri: &str, label: &str, size: egui::Vec2) {
+        let button = egui::Button::new(RichText::new(format!("⚠ {}\nClick to reveal", label)).weak())
+            .min_size(size)
+            .fill(ui.visuals().extreme_bg_color);
+        if ui.add(button).on_hover_text("This media was labeled").clicked() {
+            self.revealed_media.insert(post_uri.to_string());
+        }
+    }
+
+    fn make_hidden_post_stub(&mut self, ui: &mut Ui, post_uri: &str, reason: &str) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("hidden: {}", reason)).italics().weak());
+            if ui.small_button("Show").clicked() {
+                self.revealed_posts.insert(post_uri.to_string());
+            }
+        });
+    }
+
+    fn make_moderation_window(&mut self, ctx: &egui::Context) {
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("__moderation"),
+            egui::ViewportBuilder::default()
+                .with_title("Moderation")
+                .with_inner_size([400.0, 500.0]),
+            |ui, _| {
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.selectable_value(
+                                &mut self.moderation_view_state,
+                                ModerationViewState::Blocked,
+                                RichText::new("Blocked accounts").heading(),
+                            );
+                            ui.selectable_value(
+                                &mut self.moderation_view_state,
+                                ModerationViewState::Muted,
+                                RichText::new("Muted accounts").heading(),
+                            );
+                        });
+                        ui.separator();
+
+                        match self.moderation_view_state {
+                            ModerationViewState::Blocked => {
+                                self.make_user_list_view(ui, "blocked_accounts", &self.blocked_accounts);
+                                if let Some(cursor) = self.blocked_accounts_cursor.clone() {
+                                    if ui.button("Load More").clicked() {
+                                        self.post_message(BskyActorMsg::GetBlocks { cursor: Some(cursor) });
+                                        self.blocked_accounts_cursor = None;
+                                    }
+                                }
+                            }
+                            ModerationViewState::Muted => {
+                                self.make_user_list_view(ui, "muted_accounts", &self.muted_accounts);
+                                if let Some(cursor) = self.muted_accounts_cursor.clone() {
+                                    if ui.button("Load More").clicked() {
+                                        self.post_message(BskyActorMsg::GetMutes { cursor: Some(cursor) });
+                                        self.muted_accounts_cursor = None;
+                                    }
+                                }
+                            }
+                        }
+                    });
+                });
+
+                if ui.ctx().input(|i| i.viewport().close_requested()) {
+                    self.is_moderation_window_open = false;
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,183 @@
+impl RedskyApp {
+    /// Polls the unread count in the background, keeps the window title in sync and marks
+    /// everything as seen while the notifications tab is on screen. The page is fetched before
+    /// updating seenAt, so the new notifications are still highlighted.
+    fn poll_notifications(&mut self, ctx: &egui::Context) {
+        if self.main_view_state == MainViewState::Login || self.is_offline {
+            return;
+        }
+        if self.last_unread_poll.elapsed() >= UNREAD_POLL_INTERVAL {
+            self.last_unread_poll = std::time::Instant::now();
+            self.post_message(BskyActorMsg::GetUnreadCount());
+        }
+        ctx.request_repaint_after(UNREAD_POLL_INTERVAL.saturating_sub(self.last_unread_poll.elapsed()));
+
+        if self.main_view_state == MainViewState::NotificationsFeed && self.unread_notifications > 0 {
+            self.unread_notifications = 0;
+            self.mark_seen_on_load = true;
+            self.post_message(BskyActorMsg::GetNotifications { cursor: None });
+        }
+
+        if self.title_unread_count != self.unread_notifications {
+            self.title_unread_count = self.unread_notifications;
+            let title = if self.unread_notifications > 0 {
+                format!("({}) Redsky", self.unread_notifications)
+            } else {
+                "Redsky".to_string()
+            };
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title));
+        }
+    }
+
+    fn mark_desktop_notified(&mut self, date: Option<&str>) {
+        let Some(date) = date else { return };
+        let newer = match &self.desktop_notified_at {
+            Some(since) => crate::desktop_notify::is_after(date, since),
+            None => true,
+        };
+        if newer {
+            self.desktop_notified_at = Some(date.to_string());
+        }
+    }
+
+    fn open_profile(&self, handle: &str) {
+        self.post_ui_message(RedskyUiMsg::PrepareUserView {
+            username: handle.to_string(),
+        });
+        self.post_message(BskyActorMsg::GetUserPosts {
+            username: handle.to_string(),
+            view: UserViewState::Posts,
+            cursor: None,
+        });
+    }
+
+    fn make_notification_preview(&self, ui: &mut Ui, text: &str, thread_ref: Option<StrongRef>) {
+        let frame = egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(RichText::new(text).weak());
+        });
+        if let Some(thread_ref) = thread_ref {
+            let response = frame
+                .response
+                .interact(Sense::click())
+                .on_hover_text("Open thread");
+            if response.clicked() {
+                self.post_ui_message(RedskyUiMsg::PrepareThreadView { thread_ref });
+            }
+        }
+    }
+
+    fn make_notification_group(&self, ui: &mut Ui, group: &NotificationGroup) {
+        let first = &group.notifications[0];
+        ui.horizontal(|ui| {
+            let mut seen_authors: Vec<&str> = Vec::new();
+            for notif in &group.notifications {
+                if seen_authors.contains(&notif.author_did.as_str()) || seen_authors.len() >= 6 {
+                    continue;
+                }
+                seen_authors.push(&notif.author_did);
+                if notif.author_avatar.is_empty() {
+                    continue;
+                }
+                if let Some(texture) = self.image_cache.get(&notif.author_avatar).unwrap_or(&None) {
+                    let avatar = ui
+                        .add(
+                            egui::Image::new(texture)
+                                .max_width(24.0)
+                                .max_height(24.0)
+                                .sense(Sense::click()),
+                        )
+                        .on_hover_text(format!("@{}", notif.author));
+                    if avatar.clicked() {
+                        self.open_profile(&notif.author);
+                    }
+                } else {
+                    ui.spinner();
+                }
+            }
+        });
+        ui.horizontal_wrapped(|ui| {
+            if ui.link(RichText::new(format!("@{}", first.author)).strong()).clicked() {
+                self.open_profile(&first.author);
+            }
+            // authors_text() starts with the first author, which is rendered as a link above
+            let authors = group.authors_text();
+            let first_author = format!("@{}", first.author);
+            if let Some(rest) = authors.strip_prefix(&first_author).filter(|rest| !rest.is_empty()) {
+                ui.label(RichText::new(rest).strong());
+            }
+            ui.label(notification_action_text(&group.reason));
+            ui.label(RichText::new(&first.date).small().weak());
+            if !group.is_read() {
+                ui.label(RichText::new("🔴 Unread").color(egui::Color32::RED));
+            }
+        });
+
+        match group.reason.as_str() {
+            "reply" | "mention" | "quote" => {
+                if !first.record_text.is_empty() {
+                    self.make_notification_preview(
+                        ui,
+                        &first.record_text,
+                        Some(StrongRef {
+                            uri: first.uri.clone(),
+                            cid: first.cid.clone(),
+                        }),
+                    );
+                }
+            }
+            "follow" => {}
+            _ => {
+                let subject = group
+                    .subject_uri
+                    .as_ref()
+                    .and_then(|uri| self.notification_subjects.get(uri));
+                if let Some(post) = subject {
+                    self.make_notification_preview(
+                        ui,
+                        &post.content,
+                        Some(StrongRef {
+                            uri: post.uri.clone(),
+                            cid: post.cid.clone(),
+                        }),
+                    );
+                }
+            }
+        }
+    }
+
+    fn make_notifications_view(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let filter = &mut self.notification_filter;
+            ui.selectable_value(filter, NotificationFilter::All, "All");
+            ui.selectable_value(filter, NotificationFilter::Mentions, "Mentions");
+            ui.selectable_value(filter, NotificationFilter::Likes, "Likes");
+            ui.selectable_value(filter, NotificationFilter::Reposts, "Reposts");
+            ui.selectable_value(filter, NotificationFilter::Follows, "Follows");
+        });
+        ui.separator();
+        crate::app::show_autoscroll_area(ui, "notifications_scroll", false, |ui| {
+            let mut shown = 0;
+            for group in &self.notification_groups {
+                if !self.notification_filter.matches(&group.reason) {
+                    continue;
+                }
+                shown += 1;
+                self.make_notification_group(ui, group);
+                ui.separator();
+            }
+            if shown == 0 {
+                ui.label(RichText::new("No notifications").weak());
+            }
+        });
+        if ui.button("Refresh notifications").clicked() {
+            self.post_message(BskyActorMsg::GetNotifications { cursor: None });
+        }
+        if let Some(cursor) = self.notifications_cursor.clone() {
+            if ui.button("Load More").clicked() {
+                self.post_message(BskyActorMsg::GetNotifications { cursor: Some(cursor) });
+                self.notifications_cursor = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+impl RedskyApp {
+    fn open_command_palette(&mut self) {
+        self.is_palette_open = true;
+        self.palette_query.clear();
+        self.palette_selected = 0;
+    }
+
+    /// The commands matching the palette query, best first. Recent commands come first when
+    /// nothing is typed and get a bonus otherwise.
+    fn palette_entries(&self) -> Vec<(PaletteCommand, String, bool)> {
+        let query = self.palette_query.trim();
+        let mut commands: Vec<PaletteCommand> = PaletteCommand::from_query(query);
+        commands.retain(|command| match command {
+            PaletteCommand::OpenAtUri(uri) => AtUriTarget::parse_link(uri).is_some(),
+            _ => true,
+        });
+        let from_query = commands.len();
+        commands.extend(self.settings.recent_commands(&self.session_did).iter().cloned());
+        commands.extend(PaletteCommand::STATIC);
+        for feed in self.saved_feeds.iter().filter(|feed| feed.kind == "feed" || feed.kind == "list") {
+            commands.push(PaletteCommand::OpenFeed(feed.uri.clone()));
+            commands.push(if feed.pinned {
+                PaletteCommand::UnpinFeed(feed.uri.clone())
+            } else {
+                PaletteCommand::PinFeed(feed.uri.clone())
+            });
+        }
+        if let MainViewState::CustomFeed(feed_uri) = &self.main_view_state {
+            if !self.is_feed_pinned(feed_uri) {
+                commands.push(PaletteCommand::PinFeed(feed_uri.clone()));
+            }
+        }
+
+        let mut entries: Vec<(PaletteCommand, String, bool, i32)> = Vec::new();
+        for (idx, command) in commands.into_iter().enumerate() {
+            if entries.iter().any(|(known, ..)| known == &command) {
+                continue;
+            }
+            let recent = self.settings.recent_commands(&self.session_did).contains(&command);
+            let title = command.title(|uri| self.feed_title(uri));
+            let score = if idx < from_query || query.is_empty() {
+                Some(i32::MAX - idx as i32)
+            } else {
+                fuzzy_score(query, &title).map(|score| if recent { score + 50 } else { score })
+            };
+            if let Some(score) = score {
+                entries.push((command, title, recent, score));
+            }
+        }
+        entries.sort_by_key(|(.., score)| std::cmp::Reverse(*score));
+        entries
+            .into_iter()
+            .take(MAX_PALETTE_ENTRIES)
+            .map(|(command, title, recent, _)| (command, title, recent))
+            .collect()
+    }
+
+    fn make_command_palette(&mut self, ctx: &egui::Context) {
+        let entries = self.palette_entries();
+        self.palette_selected = self.palette_selected.min(entries.len().saturating_sub(1));
+        let mut run = None;
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.key_pressed(egui::Key::Enter),
+                i.key_pressed(egui::Key::Escape),
+            )
+        });
+        if up {
+            self.palette_selected = self.palette_selected.saturating_sub(1);
+        }
+        if down && self.palette_selected + 1 < entries.len() {
+            self.palette_selected += 1;
+        }
+        if enter {
+            run = entries.get(self.palette_selected).map(|(command, ..)| command.clone());
+        }
+
+        egui::Window::new("Command palette")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .fixed_size([420.0, 0.0])
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 80.0))
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.palette_query)
+                        .hint_text("Type a command, a handle or an at:// uri")
+                        .desired_width(f32::INFINITY),
+                );
+                response.request_focus();
+                if response.changed() {
+                    self.palette_selected = 0;
+                }
+                ui.separator();
+                if entries.is_empty() {
+                    ui.label(RichText::new("No matching command").weak());
+                }
+                for (idx, (command, title, recent)) in entries.iter().enumerate() {
+                    let text = if *recent { format!("🕘 {}", title) } else { title.clone() };
+                    let entry = ui.selectable_label(idx == self.palette_selected, text);
+                    if idx == self.palette_selected && (up || down) {
+                        entry.scroll_to_me(None);
+                    }
+                    if entry.clicked() {
+                        run = Some(command.clone());
+                    }
+                }
+            });
+
+        if escape {
+            self.is_palette_open = false;
+        }
+        if let Some(command) = run {
+            self.is_palette_open = false;
+            self.settings.add_recent_command(&self.session_did, command.clone());
+            self.settings.save();
+            self.run_command(ctx, command);
+        }
+    }
+
+    /// Runs a command of the palette or of the menus.
+    fn run_command(&mut self, ctx: &egui::Context, command: PaletteCommand) {
+        match command {
+            PaletteCommand::NewPost => {
+                self.is_post_window_open = true;
+                self.reply_to = None;
+            }
+            PaletteCommand::SearchAccounts => self.is_search_window_open = true,
+            PaletteCommand::SearchPosts => self.is_search_posts_window_open = true,
+            PaletteCommand::SearchHistory => self.is_history_window_open = true,
+            PaletteCommand::OpenLink => {
+                self.open_link_input = Some(String::new());
+                self.open_link_error = None;
+            }
+            PaletteCommand::GoToProfile => self.main_view_state = MainViewState::OwnPostFeed,
+            PaletteCommand::GoToTimeline => self.main_view_state = MainViewState::TimelineFeed,
+            PaletteCommand::GoToNotifications => self.main_view_state = MainViewState::NotificationsFeed,
+            PaletteCommand::GoToBookmarks => self.main_view_state = MainViewState::BookmarksFeed,
+            PaletteCommand::GoToMessages => self.main_view_state = MainViewState::Messages,
+            PaletteCommand::RefreshTimeline => self.post_message(BskyActorMsg::GetTimeline { cursor: None }),
+            PaletteCommand::OpenSettings => self.is_settings_window_open = true,
+            PaletteCommand::OpenModeration => {
+                self.is_moderation_window_open = true;
+                self.post_message(BskyActorMsg::GetBlocks { cursor: None });
+                self.post_message(BskyActorMsg::GetMutes { cursor: None });
+            }
+            PaletteCommand::DiscoverFeeds => {
+                self.is_feeds_window_open = true;
+                if self.suggested_feeds.is_empty() {
+                    self.post_message(BskyActorMsg::GetSuggestedFeeds { cursor: None });
+                }
+            }
+            PaletteCommand::OpenLists => {
+                self.is_lists_window_open = true;
+                self.post_message(BskyActorMsg::GetLists { cursor: None });
+            }
+            PaletteCommand::EditProfile => self.post_message(BskyActorMsg::GetProfileRecord()),
+            PaletteCommand::ShowShortcuts => self.is_shortcut_help_open = true,
+            PaletteCommand::SwitchAccount => self.switch_account(ctx),
+            PaletteCommand::OpenProfile(actor) => self.open_link(&actor),
+            PaletteCommand::OpenAtUri(uri) => self.open_link(&uri),
+            PaletteCommand::OpenFeed(uri) => self.main_view_state = MainViewState::CustomFeed(uri),
+            PaletteCommand::PinFeed(uri) | PaletteCommand::UnpinFeed(uri) => {
+                let pinned = !self.is_feed_pinned(&uri);
+                let kind = if AtUriTarget::parse(&uri) == Some(AtUriTarget::List(uri.clone())) {
+                    "list"
+                } else {
+                    "feed"
+                };
+                self.save_feed_uri(&uri, kind, pinned);
+            }
+            PaletteCommand::DownloadMedia(handle) => self.pick_media_download_folder(ctx, handle),
+        }
+    }
+
+    /// Opens an at:// uri, a bsky.app url, a handle or a did. Everything but handles goes
+    /// through the actor, which answers with the view to open or with OpenLinkFailed.
+    fn open_link(&mut self, link: &str) {
+        match AtUriTarget::parse_link(link) {
+            Some(AtUriTarget::Profile(handle)) if !handle.starts_with("did:") => self.open_profile(&handle),
+            Some(target) => self.post_message(BskyActorMsg::ResolveLink {
+                link: link.to_string(),
+                target,
+            }),
+            None => {
+                self.open_link_input = Some(link.to_string());
+                self.open_link_error = Some("Not an at:// uri, bsky.app link, handle or did".to_string());
+            }
+        }
+    }
+
+    fn make_open_link_window(&mut self, ctx: &egui::Context) {
+        let mut open = None;
+        let mut close = false;
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("__open_link"),
+            egui::ViewportBuilder::default()
+                .with_title("Open link")
+                .with_inner_size([420.0, 130.0]),
+            |ui, _| {
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    let Some(input) = &mut self.open_link_input else { return };
+                    ui.label("Paste an at:// uri, a bsky.app link, a handle or a did");
+                    let response = ui.add(
+                        egui::TextEdit::singleline(input)
+                            .hint_text("https://bsky.app/profile/...")
+                            .desired_width(f32::INFINITY),
+                    );
+                    let enter = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if let Some(error) = &self.open_link_error {
+                        ui.label(RichText::new(error).color(egui::Color32::RED));
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!input.trim().is_empty(), egui::Button::new("Open")).clicked() || enter {
+                            open = Some(input.trim().to_string());
+                        }
+                        if ui.button("Cancel").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+                if ui.ctx().input(|i| i.viewport().close_requested()) {
+                    close = true;
+                }
+            },
+        );
+        if close || open.is_some() {
+            self.open_link_input = None;
+            self.open_link_error = None;
+        }
+        if let Some(link) = open {
+            self.open_link(&link);
+        }
+    }
+
+    /// Logs out and starts over from the login screen, forgetting the remembered credentials.
+    fn switch_account(&mut self, ctx: &egui::Context) {
+        self.post_message(BskyActorMsg::StopRealtime());
+        self.save_reading_positions(true);
+        if let Ok(entry) = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER) {
+            let _ = entry.delete_credential();
+        }
+        let rx = std::mem::replace(&mut self.rx, std::sync::mpsc::channel().1);
+        *self = RedskyApp::new(self.tx.clone(), self.ui_tx.clone(), rx, false, None);
+        self.awaiting_login = true;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Title("Redsky".to_string()));
+    }
+}
@@ -13,6 +13,41 @@ impl RedskyApp {
         }
     }
 
+    fn toggle_like(&self, post: &Post) {
+        self.post_ui_message(RedskyUiMsg::PrepareLikeAction {
+            post_uri: post.uri.clone(),
+            post_cid: post.cid.clone(),
+            unlike: post.viewer_like.is_some(),
+        });
+    }
+
+    fn toggle_repost(&self, post: &Post) {
+        self.post_ui_message(RedskyUiMsg::PrepareRepostAction {
+            post_uri: post.uri.clone(),
+            post_cid: post.cid.clone(),
+            unrepost: post.viewer_repost.is_some(),
+        });
+    }
+
+    fn reply_to_post(&mut self, post: &Post) {
+        self.is_post_window_open = true;
+        let parent_ref = StrongRef {
+            uri: post.uri.clone(),
+            cid: post.cid.clone(),
+        };
+        let root_ref = post.thread_root.clone().unwrap_or(parent_ref.clone());
+        self.reply_to = Some((root_ref, parent_ref));
+    }
+
+    fn open_thread(&self, post: &Post) {
+        self.post_ui_message(RedskyUiMsg::PrepareThreadView {
+            thread_ref: StrongRef {
+                uri: post.uri.clone(),
+                cid: post.cid.clone(),
+            },
+        });
+    }
+
     fn make_placeholder_post_view(&mut self, ui: &mut Ui, username: &str) {
         ui.vertical(|ui| {
             ui.heading(username);
@@ -79,6 +114,7 @@ impl RedskyApp {
                     });
                     self.post_message(BskyActorMsg::GetUserPosts {
                         username: post.author.clone(),
+                        view: UserViewState::Posts,
                         cursor: None,
                     });
                 };
@@ -88,42 +124,167 @@ impl RedskyApp {
         });
         ui.style_mut().spacing.item_spacing = vec2(16.0, 16.0);
         ui.label(&post.content);
+        if !post.tags.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing.x = 4.0;
+                for tag in &post.tags {
+                    let text = RichText::new(format!("#{}", tag)).color(ui.visuals().hyperlink_color);
+                    ui.menu_button(text, |ui| {
+                        if ui.button(format!("Search #{}", tag)).clicked() {
+                            self.post_ui_message(RedskyUiMsg::OpenPostSearch {
+                                search: PostSearch {
+                                    tags: tag.clone(),
+                                    sort: PostSearchSort::Latest,
+                                    ..Default::default()
+                                },
+                            });
+                            ui.close();
+                        }
+                        if ui
+                            .button("Pin as feed")
+                            .on_hover_text("Follow the latest posts with this tag in a tab")
+                            .clicked()
+                        {
+                            self.post_ui_message(RedskyUiMsg::PinSavedSearch {
+                                search: SavedSearch {
+                                    name: format!("#{}", tag),
+                                    query: format!("tag:{}", tag),
+                                    pinned: true,
+                                },
+                            });
+                            ui.close();
+                        }
+                    });
+                }
+            });
+        }
     }
 
                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                             
 
-    fn make_post_view(&mut self, ui: &mut Ui, username: &str, posts: &mut Vec<FeedItem>) {
+    fn make_post_view(&mut self, ui: &mut Ui, username: &str, posts: &mut [FeedItem]) {
         let mut scroll_top_reset = false;
         let mut scroll_offset_y = 0.0;
         let mut content_size_y = 0.0;
+        // The timeline and custom feeds keep a reading position, and keep the post being read
+        // in place when newer posts get prepended
+        let is_timeline = username == "Your timeline";
+        let tracks_position = is_timeline || username.starts_with("at://");
+        let tracks_top_visible = tracks_position || username.starts_with("search:");
+        let scroll_anchor = self.feed_scroll_anchors.get(username).cloned();
+        let unread_marker = self.unread_markers.get(username).cloned();
+        let mut anchor_reached = false;
+        let mut first_visible: Option<(String, f32)> = None;
+        // getAuthorFeed puts the pinned post first, it shows up again at its own date
+        let pinned_uri = self
+            .user_infos_cache
+            .get(username)
+            .and_then(|profile| profile.pinned_post.as_ref())
+            .map(|pinned| pinned.uri.clone());
+        let takes_shortcuts = ui.ctx().viewport_id() == egui::ViewportId::ROOT;
+        if takes_shortcuts {
+            self.apply_post_shortcut(posts);
+        }
 
         ui.vertical_centered_justified(|ui| {
+            if is_timeline && !self.pending_timeline_posts.is_empty() {
+                let count = self.pending_timeline_posts.len();
+                let text = if count == 1 {
+                    "↑ 1 new post".to_string()
+                } else {
+                    format!("↑ {} new posts", count)
+                };
+                if ui
+                    .button(RichText::new(text).strong())
+                    .on_hover_text("Show the posts that arrived live")
+                    .clicked()
+                {
+                    self.post_ui_message(RedskyUiMsg::ShowPendingTimelinePosts());
+                }
+            }
+            if is_timeline && !self.timeline_hidden.is_empty() {
+                let count = self.timeline_hidden.len();
+                let text = if count == 1 {
+                    "1 post hidden".to_string()
+                } else {
+                    format!("{} posts hidden", count)
+                };
+                if ui
+                    .link(RichText::new(text).small().weak())
+                    .on_hover_text("Hidden by the Following timeline preferences, click to change them")
+                    .clicked()
+                {
+                    self.is_settings_window_open = true;
+                }
+            }
             let scroll_output = crate::app::show_autoscroll_area(ui, "post_scroll", false, |ui| {
                 ui.vertical(|ui| {
+                    let clip_top = ui.clip_rect().top();
                     for (idx, item) in posts.iter_mut().enumerate() {
+                        if idx > 0 && unread_marker.is_some() && item.uri() == unread_marker.as_deref() {
+                            ui.label(RichText::new("▲ New posts since you last read").small().color(egui::Color32::RED));
+                            let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 2.0), Sense::hover());
+                            ui.painter().rect_filled(rect, 0.0, egui::Color32::RED);
+                        }
+                        let item_top = ui.cursor().top();
                         match item {
                             FeedItem::Full(post, ref mut height) => {
+                                let label_action = self.post_label_action(post);
+                                let is_blocked = self.is_author_blocked(post)
+                                    || matches!(label_action, Some(LabelAction::Hide(_)));
+                                let hidden_reason = self.post_hidden_reason(post);
+                                let warning_label = match &label_action {
+                                    Some(LabelAction::Warn(label)) => Some(label.clone()),
+                                    _ => None,
+                                };
                                 let post_block = ui.vertical(|ui| {
                                     if idx == 0 && self.scroll_to_top {
                                         ui.scroll_to_rect(ui.max_rect(), Some(egui::Align::TOP));
                                         scroll_top_reset = true;
                                     }
+                                    if is_blocked {
+                                        return;
+                                    }
+                                    if let Some(reason) = &hidden_reason {
+                                        self.make_hidden_post_stub(ui, &post.uri, reason);
+                                        ui.separator();
+                                        return;
+                                    }
+                                    if let Some(label) = &warning_label {
+                                        self.make_content_warning_stub(ui, &post.uri, label);
+                                        ui.separator();
+                                        return;
+                                    }
+                                    if idx == 0 && pinned_uri.as_deref() == Some(post.uri.as_str()) {
+                                        ui.label(RichText::new("📌 Pinned post").small().weak());
+                                    }
                                     self.make_post_inner_view(ui, post);
 
                                     if let Some(quoted_post) = &post.quoted_post {
-                                        egui::Frame::new()
-                                            .inner_margin(8)
-                                            .outer_margin(8)
-                                            .corner_radius(8)
-                                            .stroke(egui::Stroke::new(1.0, egui::Color32::GRAY))
-                                            .show(ui, |ui| {
-                                                self.make_post_inner_view(ui, quoted_post);
-                                            });
+                                        if let Some(LabelAction::Hide(label) | LabelAction::Warn(label)) =
+                                            self.post_label_action(quoted_post)
+                                        {
+                                            ui.label(RichText::new(format!("Quoted post hidden ({})", label)).italics().weak());
+                                        } else {
+                                            egui::Frame::new()
+                                                .inner_margin(8)
+                                                .outer_margin(8)
+                                                .corner_radius(8)
+                                                .stroke(egui::Stroke::new(1.0, egui::Color32::GRAY))
+                                                .show(ui, |ui| {
+                                                    self.make_post_inner_view(ui, quoted_post);
+                                                });
+                                        }
                                     }
 
                                     if !&post.embeds.is_empty() {
                                         ui.horizontal_wrapped(|ui| {
                                             ui.set_min_height(200f32);
+                                            if let Some(LabelAction::BlurMedia(label)) = &label_action {
+                                                let width = self.settings.max_image_size.min(ui.available_width());
+                                                self.make_blurred_media_placeholder(ui, &post.uri, label, vec2(width, 200.0));
+                                                return;
+                                            }
                                             for embed in &post.embeds {
                                                 if let Some(img_data) = self.image_cache.get(&embed.thumbnail_url) {
                                                     self.make_buffer_image_view(
@@ -145,13 +306,7 @@ impl RedskyApp {
                                         };
                                         let like_btn = ui.button(like_text).on_hover_text("Right-click to see likers");
                                         if like_btn.clicked() {
-                                            let post_uri = post.uri.clone();
-                                            let post_cid = post.cid.clone();
-                                            self.post_ui_message(RedskyUiMsg::PrepareLikeAction {
-                                                post_uri,
-                                                post_cid,
-                                                unlike: post.viewer_like.is_some(),
-                                            });
+                                            self.toggle_like(post);
                                         }
                                         like_btn.context_menu(|ui| {
                                             self.make_post_context_menu_item(
@@ -170,15 +325,7 @@ impl RedskyApp {
                                         };
                                         let repost_btn = ui.button(repost_text).on_hover_text("Right-click to see reposters");
                                         if repost_btn.clicked() {
-                                            let post_uri = post.uri.clone();
-                                            let post_cid = post.cid.clone();
-                                            self.post_ui_message(
-                                                RedskyUiMsg::PrepareRepostAction {
-                                                    post_uri,
-                                                    post_cid,
-                                                    unrepost: post.viewer_repost.is_some(),
-                                                },
-                                            );
+                                            self.toggle_repost(post);
                                         }
                                         repost_btn.context_menu(|ui| {
                                             self.make_post_context_menu_item(
@@ -193,13 +340,7 @@ impl RedskyApp {
                                         });
 
                                         if ui.button("Reply").clicked() {
-                                            self.is_post_window_open = true;
-                                            let parent_ref = StrongRef {
-                                                uri: post.uri.clone(),
-                                                cid: post.cid.clone(),
-                                            };
-                                            let root_ref = post.thread_root.clone().unwrap_or(parent_ref.clone());
-                                            self.reply_to = Some((root_ref, parent_ref));
+                                            self.reply_to_post(post);
                                         }
 
                                         ui.menu_button("…", |ui| {
@@ -213,6 +354,19 @@ impl RedskyApp {
                                                 });
                                                 ui.close();
                                             }
+                                            let bookmark_text = if post.bookmarked {
+                                                "Remove Bookmark"
+                                            } else {
+                                                "Bookmark"
+                                            };
+                                            if ui.button(bookmark_text).clicked() {
+                                                self.post_ui_message(RedskyUiMsg::PrepareBookmarkAction {
+                                                    post_uri: post.uri.clone(),
+                                                    post_cid: post.cid.clone(),
+                                                    unbookmark: post.bookmarked,
+                                                });
+                                                ui.close();
+                                            }
                                             if ui.button("Raw View").clicked() {
                                                 self.post_ui_message(RedskyUiMsg::ShowRawPostView {
                                                     post_uri: post.uri.clone(),
@@ -220,18 +374,52 @@ impl RedskyApp {
                                                 });
                                                 ui.close();
                                             }
+                                            if post.author != self.login {
+                                                ui.separator();
+                                                let muted = self.is_author_muted(post);
+                                                let mute_text = if muted {
+                                                    format!("Unmute @{}", post.author)
+                                                } else {
+                                                    format!("Mute @{}", post.author)
+                                                };
+                                                if ui.button(mute_text).clicked() {
+                                                    self.post_ui_message(RedskyUiMsg::PrepareMuteAction {
+                                                        did: post.author_did.clone(),
+                                                        unmute: muted,
+                                                    });
+                                                    ui.close();
+                                                }
+                                                if ui.button(format!("Block @{}", post.author)).clicked() {
+                                                    self.post_ui_message(RedskyUiMsg::PrepareBlockAction {
+                                                        did: post.author_did.clone(),
+                                                        unblock: false,
+                                                    });
+                                                    ui.close();
+                                                }
+                                            }
                                         });
                                     });
                                     ui.separator();
                                 });
 
-                                if post_block.response.interact(Sense::click()).clicked() {
-                                    self.post_ui_message(RedskyUiMsg::PrepareThreadView {
-                                        thread_ref: StrongRef {
-                                            uri: post.uri.clone(),
-                                            cid: post.cid.clone(),
-                                        },
-                                    });
+                                if !is_blocked
+                                    && hidden_reason.is_none()
+                                    && warning_label.is_none()
+                                    && post_block.response.interact(Sense::click()).clicked()
+                                {
+                                    self.open_thread(post);
+                                }
+                                if takes_shortcuts && self.selected_post.as_deref() == Some(post.uri.as_str()) {
+                                    let rect = post_block.response.rect;
+                                    ui.painter().rect_stroke(
+                                        rect,
+                                        4.0,
+                                        ui.visuals().selection.stroke,
+                                        egui::StrokeKind::Outside,
+                                    );
+                                    if std::mem::take(&mut self.scroll_to_selected) {
+                                        ui.scroll_to_rect(rect, None);
+                                    }
                                 }
                                 *height = Some(post_block.response.rect.height());
                             }
@@ -247,6 +435,38 @@ impl RedskyApp {
                                     });
                                 });
                             }
+                            FeedItem::Gap { cursor, loading } => {
+                                ui.separator();
+                                if *loading {
+                                    ui.spinner();
+                                } else if ui
+                                    .button("Load missing posts")
+                                    .on_hover_text("The last refresh did not reach the posts below")
+                                    .clicked()
+                                {
+                                    *loading = true;
+                                    self.post_message(BskyActorMsg::GetTimelineGap {
+                                        cursor: cursor.clone(),
+                                    });
+                                }
+                                ui.separator();
+                            }
+                        }
+
+                        if let Some(uri) = item.uri() {
+                            if tracks_top_visible && first_visible.is_none() && ui.cursor().top() > clip_top {
+                                first_visible = Some((uri.to_string(), clip_top - item_top));
+                            }
+                            if let Some((anchor_uri, offset)) = &scroll_anchor {
+                                if anchor_uri == uri {
+                                    let anchor_rect = egui::Rect::from_min_size(
+                                        egui::pos2(ui.max_rect().left(), item_top + offset),
+                                        egui::vec2(1.0, 1.0),
+                                    );
+                                    ui.scroll_to_rect(anchor_rect, Some(egui::Align::TOP));
+                                    anchor_reached = true;
+                                }
+                            }
                         }
 
                         // Rehydration check
@@ -273,17 +493,14 @@ impl RedskyApp {
             if username == "Your timeline" {
                 if let Some(cursor) = self.timeline_cursor.clone() {
                     if ui.button("Load More").clicked() {
-                        self.post_message(BskyActorMsg::GetTimeline {
-                            cursor: Some(cursor),
-                        });
-                        self.timeline_cursor = None; // Avoid duplicate requests
+                        self.request_feed_page(username, Some(cursor));
                     }
                 }
             } else if username == "Search Results" {
                 if let Some(cursor) = self.search_posts_cursor.clone() {
                     if ui.button("Load More").clicked() {
                         self.post_message(BskyActorMsg::SearchPosts {
-                            query: self.search_posts_query.clone(),
+                            search: self.search_posts_active.clone(),
                             cursor: Some(cursor),
                         });
                         self.search_posts_cursor = None; // Avoid duplicate requests
@@ -298,32 +515,50 @@ impl RedskyApp {
                         self.bookmarks_cursor = None; // Avoid duplicate requests
                     }
                 }
-            } else if username != "Thread" {
-                // It's a user view
-                if let Some(cursor) = self.user_cursors.get(username).cloned().flatten() {
-                    if ui.button("Load More (Posts)").clicked() {
-                        self.post_message(BskyActorMsg::GetUserPosts {
-                            username: username.to_string(),
-                            cursor: Some(cursor),
-                        });
-                        self.user_cursors.insert(username.to_string(), None); // Avoid duplicate requests
+            } else if username.starts_with("at://") {
+                // It's a custom feed, keyed by its uri
+                if let Some(cursor) = self.custom_feed_cursors.get(username).cloned().flatten() {
+                    if ui.button("Load More").clicked() {
+                        self.request_feed_page(username, Some(cursor));
                     }
                 }
-                if let Some(cursor) = self.user_likes_cursors.get(username).cloned().flatten() {
-                    if ui.button("Load More (Likes)").clicked() {
-                        self.post_message(BskyActorMsg::GetUserLikes {
-                            username: username.to_string(),
+            } else if let Some(query) = username.strip_prefix("search:") {
+                // It's a saved search, keyed by its query
+                if let Some(cursor) = self.saved_search_cursors.get(query).cloned().flatten() {
+                    if ui.button("Load More").clicked() {
+                        self.post_message(BskyActorMsg::GetSavedSearch {
+                            query: query.to_string(),
                             cursor: Some(cursor),
                         });
-                        self.user_likes_cursors.insert(username.to_string(), None); // Avoid duplicate requests
+                        self.saved_search_cursors.insert(query.to_string(), None); // Avoid duplicate requests
                     }
                 }
+            } else if username != "Thread" {
+                // It's a user view
+                self.make_user_feed_load_more(ui, username);
             }
         });
 
         if scroll_top_reset {
             self.scroll_to_top = false;
         }
+        if anchor_reached {
+            self.feed_scroll_anchors.remove(username);
+        }
+        if tracks_position {
+            if let Some((uri, _)) = &first_visible {
+                self.update_reading_position(username, uri);
+            }
+        }
+        if tracks_top_visible {
+            if scroll_offset_y > 0.0 {
+                if let Some(top_visible) = first_visible {
+                    self.feed_top_visible.insert(username.to_string(), top_visible);
+                }
+            } else {
+                self.feed_top_visible.remove(username);
+            }
+        }
 
         // Dehydration logic
         if self.settings.allow_dehydration {
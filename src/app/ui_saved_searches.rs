@@ -0,0 +1,124 @@
+impl RedskyApp {
+    /// Refreshes the pinned saved searches in the background, new posts count as unread
+    /// until their tab is opened.
+    fn poll_saved_searches(&mut self, ctx: &egui::Context) {
+        if self.main_view_state == MainViewState::Login || self.is_offline {
+            return;
+        }
+        if self.last_saved_search_poll.elapsed() >= SAVED_SEARCH_POLL_INTERVAL {
+            self.last_saved_search_poll = std::time::Instant::now();
+            let pinned: Vec<String> = self
+                .settings
+                .saved_searches
+                .iter()
+                .filter(|search| search.pinned)
+                .map(|search| search.query.clone())
+                .collect();
+            for query in pinned {
+                self.post_message(BskyActorMsg::GetSavedSearch { query, cursor: None });
+            }
+        }
+        ctx.request_repaint_after(SAVED_SEARCH_POLL_INTERVAL.saturating_sub(self.last_saved_search_poll.elapsed()));
+    }
+
+    fn run_post_search(&mut self) {
+        self.search_posts.apply_inline_operators();
+        let mut active = self.search_posts.clone();
+        active.resolve_own_account(&self.session_did);
+        self.search_posts_active = active;
+        self.search_posts_results = Some(vec![]);
+        self.search_posts_cursor = None;
+        self.search_posts_total = None;
+        self.post_message(BskyActorMsg::SearchPosts {
+            search: self.search_posts_active.clone(),
+            cursor: None,
+        });
+    }
+
+    fn pin_saved_search(&mut self, search: SavedSearch) {
+        match self.settings.saved_searches.iter_mut().find(|saved| saved.query == search.query) {
+            Some(saved) => saved.pinned = true,
+            None => self.settings.saved_searches.push(search.clone()),
+        }
+        self.settings.save();
+        self.main_view_state = MainViewState::SavedSearch(search.query);
+    }
+
+    fn make_saved_search_tabs(&mut self, ui: &mut Ui) {
+        for search in self.settings.saved_searches.clone() {
+            if !search.pinned {
+                continue;
+            }
+            let title = match self.saved_search_unread.get(&search.query) {
+                Some(unread) if *unread > 0 => format!("{} ({})", search.name, unread),
+                _ => search.name.clone(),
+            };
+            let tab = ui.selectable_value(
+                &mut self.main_view_state,
+                MainViewState::SavedSearch(search.query.clone()),
+                RichText::new(title).heading(),
+            );
+            tab.context_menu(|ui| {
+                if ui.button("Unpin").clicked() {
+                    if let Some(saved) = self.settings.saved_searches.iter_mut().find(|s| s.query == search.query) {
+                        saved.pinned = false;
+                    }
+                    self.settings.save();
+                    if self.main_view_state == MainViewState::SavedSearch(search.query.clone()) {
+                        self.main_view_state = MainViewState::TimelineFeed;
+                    }
+                    ui.close();
+                }
+            });
+        }
+    }
+
+    fn make_saved_search_view(&mut self, ui: &mut Ui, query: &str) {
+        if !self.saved_search_posts.contains_key(query) {
+            self.saved_search_posts.insert(query.to_string(), Vec::new());
+            self.post_message(BskyActorMsg::GetSavedSearch {
+                query: query.to_string(),
+                cursor: None,
+            });
+        }
+        self.saved_search_unread.remove(query);
+        let mut items = self.saved_search_posts.remove(query).unwrap_or_default();
+        self.make_post_view(ui, &format!("search:{}", query), &mut items);
+        self.saved_search_posts.insert(query.to_string(), items);
+    }
+
+    /// Saved searches in the search window: load one, pin it as a tab or delete it.
+    fn make_saved_searches_list(&mut self, ui: &mut Ui) {
+        egui::CollapsingHeader::new(format!("Saved searches ({})", self.settings.saved_searches.len())).show(ui, |ui| {
+            let mut changed = false;
+            let mut load = None;
+            self.settings.saved_searches.retain_mut(|search| {
+                let mut keep = true;
+                ui.horizontal(|ui| {
+                    if ui.link(&search.name).on_hover_text(&search.query).clicked() {
+                        load = Some(search.query.clone());
+                    }
+                    changed |= ui.checkbox(&mut search.pinned, "Pinned as tab").changed();
+                    if ui.button("🗑").on_hover_text("Delete saved search").clicked() {
+                        keep = false;
+                        changed = true;
+                    }
+                });
+                keep
+            });
+            if self.settings.saved_searches.is_empty() {
+                ui.label(RichText::new("No saved searches").weak());
+            }
+            if changed {
+                self.settings.save();
+            }
+            if let Some(query) = load {
+                self.search_posts = PostSearch {
+                    query,
+                    ..Default::default()
+                };
+                self.run_post_search();
+            }
+        });
+    }
+}
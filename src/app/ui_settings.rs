@@ -4,7 +4,7 @@ impl RedskyApp {
             egui::ViewportId::from_hash_of("__settings"),
             egui::ViewportBuilder::default()
                 .with_title("Settings")
-                .with_inner_size([300.0, 200.0]),
+                .with_inner_size([420.0, 700.0]),
             |ui, _| {
                 egui::CentralPanel::default().show_inside(ui, |ui| {
                     ui.vertical(|ui| {
@@ -50,6 +50,63 @@ impl RedskyApp {
                             }
                         });
 
+                        ui.horizontal(|ui| {
+                            let response = ui
+                                .checkbox(&mut self.settings.realtime_updates, "Live updates")
+                                .on_hover_text("Stream new posts and likes from the accounts you follow through Jetstream");
+                            if response.changed() {
+                                settings_changed = true;
+                                self.post_message(if self.settings.realtime_updates {
+                                    BskyActorMsg::StartRealtime()
+                                } else {
+                                    BskyActorMsg::StopRealtime()
+                                });
+                            }
+                        });
+
+                        ui.separator();
+                        if self.make_desktop_notification_settings(ui) {
+                            settings_changed = true;
+                        }
+
+                        ui.separator();
+                        if self.make_content_filter_settings(ui) {
+                            settings_changed = true;
+                            self.post_message(BskyActorMsg::PutContentPrefs {
+                                adult_content_enabled: self.settings.adult_content_enabled,
+                                label_visibility: self.settings.label_visibility.clone(),
+                            });
+                        }
+
+                        ui.separator();
+                        if self.make_timeline_view_settings(ui) {
+                            settings_changed = true;
+                            // Refetch the timeline with the new filters
+                            self.timeline_hidden.clear();
+                            self.timeline.clear();
+                            self.timeline_cursor = None;
+                            self.post_message(BskyActorMsg::PutFeedViewPref {
+                                pref: self.settings.timeline_view_pref,
+                            });
+                            self.post_message(BskyActorMsg::GetTimeline { cursor: None });
+                        }
+
+                        ui.separator();
+                        if self.make_shortcut_settings(ui) {
+                            settings_changed = true;
+                        }
+
+                        ui.separator();
+                        self.make_link_handler_settings(ui);
+
+                        ui.separator();
+                        if self.make_muted_words_settings(ui) {
+                            settings_changed = true;
+                            self.post_message(BskyActorMsg::PutMutedWords {
+                                words: self.settings.muted_words.clone(),
+                            });
+                        }
+
                         if settings_changed {
                             self.settings.save();
 
@@ -75,4 +132,240 @@ impl RedskyApp {
             },
         );
     }
+
+    /// Returns true when the desktop notification reasons were modified.
+    fn make_desktop_notification_settings(&mut self, ui: &mut Ui) -> bool {
+        let mut changed = false;
+        ui.label(RichText::new("Desktop notifications").strong())
+            .on_hover_text("Shown while Redsky is in the background");
+        ui.horizontal_wrapped(|ui| {
+            for (reason, text) in DESKTOP_NOTIFICATION_REASONS {
+                let mut enabled = self.settings.desktop_notification_reasons.contains(reason);
+                if ui.checkbox(&mut enabled, text).changed() {
+                    if enabled {
+                        self.settings.desktop_notification_reasons.insert(reason.to_string());
+                    } else {
+                        self.settings.desktop_notification_reasons.remove(reason);
+                    }
+                    changed = true;
+                }
+            }
+        });
+        if !crate::desktop_notify::OPENS_CLICKED_TARGET {
+            ui.label(
+                RichText::new("Clicking a notification brings Redsky to the front, it does not open the post on this system")
+                    .small()
+                    .weak(),
+            );
+        }
+        changed
+    }
+
+    /// Returns true when the content filtering preferences were modified.
+    fn make_content_filter_settings(&mut self, ui: &mut Ui) -> bool {
+        let mut changed = false;
+        ui.label(RichText::new("Content filtering").strong());
+        if ui
+            .checkbox(&mut self.settings.adult_content_enabled, "Enable adult content")
+            .changed()
+        {
+            changed = true;
+        }
+        egui::Grid::new("content_labels_grid").num_columns(2).show(ui, |ui| {
+            for (label, description, adult, _) in CONTENT_LABELS {
+                ui.label(description);
+                let enabled = !adult || self.settings.adult_content_enabled;
+                let mut visibility = self.settings.visibility_for_label(label);
+                ui.add_enabled_ui(enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        for (value, text) in [
+                            (LabelVisibility::Show, "Show"),
+                            (LabelVisibility::Warn, "Warn"),
+                            (LabelVisibility::Hide, "Hide"),
+                        ] {
+                            if ui.selectable_value(&mut visibility, value, text).changed() {
+                                self.settings.label_visibility.insert(label.to_string(), visibility);
+                                changed = true;
+                            }
+                        }
+                    });
+                });
+                ui.end_row();
+            }
+        });
+        changed
+    }
+
+    /// Returns true when the Following timeline filters were modified.
+    fn make_timeline_view_settings(&mut self, ui: &mut Ui) -> bool {
+        let mut changed = false;
+        ui.label(RichText::new("Following timeline").strong())
+            .on_hover_text("Synced with your account, applies to the other Bluesky apps too");
+        let pref = &mut self.settings.timeline_view_pref;
+        changed |= ui.checkbox(&mut pref.hide_reposts, "Hide reposts").changed();
+        changed |= ui.checkbox(&mut pref.hide_replies, "Hide replies").changed();
+        ui.add_enabled_ui(!pref.hide_replies, |ui| {
+            changed |= ui
+                .checkbox(&mut pref.hide_replies_by_unfollowed, "Hide replies to people you don't follow")
+                .changed();
+        });
+        changed |= ui.checkbox(&mut pref.hide_quote_posts, "Hide quote posts").changed();
+        changed
+    }
+
+    /// Returns true when a keyboard shortcut was modified.
+    fn make_shortcut_settings(&mut self, ui: &mut Ui) -> bool {
+        let mut changed = false;
+        egui::CollapsingHeader::new(RichText::new("Keyboard shortcuts").strong())
+            .id_salt("shortcut_settings")
+            .show(ui, |ui| {
+                ui.label(RichText::new("Separate the keys of a sequence with a space, leave empty to disable").small().weak());
+                let conflicts = self.settings.shortcut_conflicts();
+                egui::Grid::new("shortcut_settings_grid").num_columns(3).show(ui, |ui| {
+                    for action in ShortcutAction::ALL {
+                        ui.label(action.description());
+                        let mut keys = self.settings.shortcut_keys(action).to_string();
+                        if ui.add(egui::TextEdit::singleline(&mut keys).desired_width(60.0)).changed() {
+                            if keys == action.default_keys() {
+                                self.settings.shortcuts.remove(&action);
+                            } else {
+                                self.settings.shortcuts.insert(action, keys);
+                            }
+                            changed = true;
+                        }
+                        if conflicts.contains(&action) {
+                            ui.label(RichText::new("⚠").color(egui::Color32::RED))
+                                .on_hover_text("Conflicts with another shortcut");
+                        } else {
+                            ui.label("");
+                        }
+                        ui.end_row();
+                    }
+                });
+                if ui
+                    .add_enabled(!self.settings.shortcuts.is_empty(), egui::Button::new("Reset to defaults"))
+                    .clicked()
+                {
+                    self.settings.shortcuts.clear();
+                    changed = true;
+                }
+            });
+        changed
+    }
+
+    fn make_link_handler_settings(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Links").strong());
+        ui.horizontal(|ui| {
+            if ui.button("Open at:// links with Redsky").clicked() {
+                self.link_handler_status = Some(match crate::url_handler::register() {
+                    Ok(()) => "Redsky now opens at:// links".to_string(),
+                    Err(err) => format!("Could not register Redsky: {}", err),
+                });
+            }
+            if let Some(status) = &self.link_handler_status {
+                ui.label(RichText::new(status).small().weak());
+            }
+        });
+    }
+
+    /// Returns true when the muted words list was modified.
+    fn make_muted_words_settings(&mut self, ui: &mut Ui) -> bool {
+        let mut changed = false;
+        ui.label(RichText::new("Muted words and tags").strong());
+
+        let now = unix_now();
+        let mut to_remove = None;
+        egui::ScrollArea::vertical()
+            .id_salt("muted_words_scroll")
+            .max_height(200.0)
+            .show(ui, |ui| {
+                if self.settings.muted_words.is_empty() {
+                    ui.label(RichText::new("No muted words").weak());
+                }
+                for (i, word) in self.settings.muted_words.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(&word.value);
+                        let targets = match word.targets {
+                            MutedWordTargets::Content => "text",
+                            MutedWordTargets::Tags => "tags",
+                            MutedWordTargets::Both => "text and tags",
+                        };
+                        let expiry = match word.expires_at {
+                            None => "forever".to_string(),
+                            Some(_) if word.is_expired(now) => "expired".to_string(),
+                            Some(expires_at) => {
+                                format!("{}h left", (expires_at - now + 3599) / 3600)
+                            }
+                        };
+                        ui.label(RichText::new(format!("{}, {}", targets, expiry)).small().weak());
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            if ui.small_button("Remove").clicked() {
+                                to_remove = Some(i);
+                            }
+                        });
+                    });
+                }
+            });
+        if let Some(i) = to_remove {
+            self.settings.muted_words.remove(i);
+            changed = true;
+        }
+
+        let mut add = false;
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.muted_word_input)
+                    .hint_text("word, phrase or #tag")
+                    .desired_width(160.0),
+            );
+            if response.changed() && self.muted_word_input.starts_with('#') {
+                self.muted_word_targets = MutedWordTargets::Tags;
+            }
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                add = true;
+            }
+            if ui.button("Mute").clicked() {
+                add = true;
+            }
+        });
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("muted_word_targets_combo")
+                .selected_text(match self.muted_word_targets {
+                    MutedWordTargets::Content => "Text only",
+                    MutedWordTargets::Tags => "Tags only",
+                    MutedWordTargets::Both => "Text and tags",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.muted_word_targets, MutedWordTargets::Both, "Text and tags");
+                    ui.selectable_value(&mut self.muted_word_targets, MutedWordTargets::Content, "Text only");
+                    ui.selectable_value(&mut self.muted_word_targets, MutedWordTargets::Tags, "Tags only");
+                });
+            egui::ComboBox::from_id_salt("muted_word_duration_combo")
+                .selected_text(match self.muted_word_duration_days {
+                    None => "Forever".to_string(),
+                    Some(1) => "24 hours".to_string(),
+                    Some(days) => format!("{} days", days),
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.muted_word_duration_days, None, "Forever");
+                    ui.selectable_value(&mut self.muted_word_duration_days, Some(1), "24 hours");
+                    ui.selectable_value(&mut self.muted_word_duration_days, Some(7), "7 days");
+                    ui.selectable_value(&mut self.muted_word_duration_days, Some(30), "30 days");
+                });
+        });
+
+        let value = self.muted_word_input.trim().to_string();
+        if add && !value.is_empty() {
+            self.settings.muted_words.push(MutedWord {
+                id: None,
+                value,
+                targets: self.muted_word_targets,
+                expires_at: self.muted_word_duration_days.map(|days| now + days * 24 * 3600),
+                actor_target: None,
+            });
+            self.muted_word_input.clear();
+            changed = true;
+        }
+        changed
+    }
 }
@@ -0,0 +1,139 @@
+impl RedskyApp {
+    /// Keyboard shortcuts of the main window, ignored while a text field has the focus.
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        if self.main_view_state != MainViewState::Login
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::K))
+        {
+            if self.is_palette_open {
+                self.is_palette_open = false;
+            } else {
+                self.open_command_palette();
+            }
+        }
+        if self.main_view_state == MainViewState::Login || ctx.egui_wants_keyboard_input() {
+            self.shortcut_input.clear();
+            return;
+        }
+        let (keys, escape) = ctx.input(|i| {
+            let keys: Vec<String> = if i.modifiers.command || i.modifiers.ctrl || i.modifiers.alt {
+                Vec::new()
+            } else {
+                i.events
+                    .iter()
+                    .filter_map(|event| match event {
+                        egui::Event::Text(text) => Some(text.chars().map(|c| c.to_string()).collect::<Vec<_>>()),
+                        _ => None,
+                    })
+                    .flatten()
+                    .collect()
+            };
+            (keys, i.key_pressed(egui::Key::Escape))
+        });
+        if escape {
+            self.shortcut_input.clear();
+        }
+        for key in keys {
+            if let Some(action) = self.shortcut_input.key_typed(&self.settings, &key) {
+                self.run_shortcut(ctx, action);
+            }
+        }
+    }
+
+    fn run_shortcut(&mut self, ctx: &egui::Context, action: ShortcutAction) {
+        let command = match action {
+            _ if action.targets_post() => {
+                self.post_shortcut = Some(action);
+                return;
+            }
+            ShortcutAction::ShowHelp => {
+                self.is_shortcut_help_open = !self.is_shortcut_help_open;
+                return;
+            }
+            ShortcutAction::NewPost => PaletteCommand::NewPost,
+            ShortcutAction::Search => PaletteCommand::SearchPosts,
+            ShortcutAction::GoToTimeline => PaletteCommand::GoToTimeline,
+            ShortcutAction::GoToNotifications => PaletteCommand::GoToNotifications,
+            ShortcutAction::GoToBookmarks => PaletteCommand::GoToBookmarks,
+            _ => return,
+        };
+        self.run_command(ctx, command);
+    }
+
+    /// Moves the selection or acts on the selected post, for the post view of the main window.
+    fn apply_post_shortcut(&mut self, posts: &[FeedItem]) {
+        let Some(action) = self.post_shortcut.take() else { return };
+        let selectable: Vec<&Post> = posts
+            .iter()
+            .filter_map(|item| match item {
+                FeedItem::Full(post, _) => Some(post),
+                _ => None,
+            })
+            .filter(|post| {
+                !self.is_author_blocked(post) && !matches!(self.post_label_action(post), Some(LabelAction::Hide(_)))
+            })
+            .collect();
+        let selected_idx = self
+            .selected_post
+            .as_ref()
+            .and_then(|uri| selectable.iter().position(|post| &post.uri == uri));
+        match (action, selected_idx) {
+            (ShortcutAction::NextPost | ShortcutAction::PreviousPost, None) => {
+                if let Some(post) = selectable.first() {
+                    self.selected_post = Some(post.uri.clone());
+                    self.scroll_to_selected = true;
+                }
+            }
+            (ShortcutAction::NextPost, Some(idx)) | (ShortcutAction::PreviousPost, Some(idx)) => {
+                let next_idx = if action == ShortcutAction::NextPost {
+                    (idx + 1).min(selectable.len() - 1)
+                } else {
+                    idx.saturating_sub(1)
+                };
+                self.selected_post = Some(selectable[next_idx].uri.clone());
+                self.scroll_to_selected = true;
+            }
+            (_, None) => {}
+            (_, Some(idx)) => {
+                let post = selectable[idx];
+                match action {
+                    ShortcutAction::Like => self.toggle_like(post),
+                    ShortcutAction::Reply => self.reply_to_post(post),
+                    ShortcutAction::Repost => self.toggle_repost(post),
+                    ShortcutAction::OpenThread => self.open_thread(post),
+                    ShortcutAction::OpenProfile => self.open_profile(&post.author),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn make_shortcut_help_window(&mut self, ctx: &egui::Context) {
+        let mut open = true;
+        let mut edit = false;
+        egui::Window::new("Keyboard shortcuts")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                egui::Grid::new("shortcut_help_grid").num_columns(2).striped(true).show(ui, |ui| {
+                    for action in ShortcutAction::ALL {
+                        let keys = self.settings.shortcut_keys(action);
+                        let keys = if keys.trim().is_empty() { "-".to_string() } else { keys.replace(' ', " then ") };
+                        ui.label(RichText::new(keys).monospace().strong());
+                        ui.label(action.description());
+                        ui.end_row();
+                    }
+                });
+                ui.separator();
+                if ui.link("Change shortcuts...").clicked() {
+                    edit = true;
+                }
+            });
+        if edit {
+            self.is_settings_window_open = true;
+            open = false;
+        }
+        self.is_shortcut_help_open = open;
+    }
+}
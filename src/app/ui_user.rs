@@ -1,5 +1,95 @@
 impl RedskyApp {
 
+    fn make_profile_actions(&self, ui: &mut Ui, profile: &UserProfile) {
+        if profile.handle == self.login {
+            if ui.button("Edit profile...").clicked() {
+                self.post_message(BskyActorMsg::GetProfileRecord());
+            }
+            return;
+        }
+        if profile.did.is_empty() {
+            return;
+        }
+        ui.horizontal(|ui| {
+            let block_pending = profile.viewer_blocking == Some(RecordState::Pending);
+            if profile.viewer_blocking.is_some() {
+                if ui.add_enabled(!block_pending, egui::Button::new("Unblock")).clicked() {
+                    self.post_ui_message(RedskyUiMsg::PrepareBlockAction {
+                        did: profile.did.clone(),
+                        unblock: true,
+                    });
+                }
+            } else {
+                let unfollow = profile.viewer_following.is_some();
+                let pending = profile.viewer_following == Some(RecordState::Pending);
+                let follow_text = if unfollow { "Unfollow" } else { "Follow" };
+                if ui.add_enabled(!pending, egui::Button::new(follow_text)).clicked() {
+                    self.post_ui_message(RedskyUiMsg::PrepareFollowAction {
+                        did: profile.did.clone(),
+                        unfollow,
+                    });
+                }
+                if ui.button("Message").on_hover_text("Start a conversation").clicked() {
+                    self.post_message(BskyActorMsg::GetConvoForMembers {
+                        did: profile.did.clone(),
+                    });
+                }
+            }
+            if profile.viewer_muted && ui.button("Unmute").clicked() {
+                self.post_ui_message(RedskyUiMsg::PrepareMuteAction {
+                    did: profile.did.clone(),
+                    unmute: true,
+                });
+            }
+            ui.menu_button("…", |ui| {
+                let mute_text = if profile.viewer_muted { "Unmute" } else { "Mute" };
+                if ui.button(mute_text).clicked() {
+                    self.post_ui_message(RedskyUiMsg::PrepareMuteAction {
+                        did: profile.did.clone(),
+                        unmute: profile.viewer_muted,
+                    });
+                    ui.close();
+                }
+                let block_text = if profile.viewer_blocking.is_some() { "Unblock" } else { "Block" };
+                if ui.add_enabled(!block_pending, egui::Button::new(block_text)).clicked() {
+                    self.post_ui_message(RedskyUiMsg::PrepareBlockAction {
+                        did: profile.did.clone(),
+                        unblock: profile.viewer_blocking.is_some(),
+                    });
+                    ui.close();
+                }
+                ui.separator();
+                self.make_add_to_list_menu(ui, profile);
+            });
+            if profile.viewer_followed_by {
+                ui.label(
+                    RichText::new("Follows you")
+                        .small()
+                        .background_color(ui.visuals().faint_bg_color),
+                );
+            }
+        });
+    }
+
+    /// Account labels as badges, the ones moderation acts on are highlighted.
+    fn make_profile_labels(&self, ui: &mut Ui, profile: &UserProfile) {
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            for label in &profile.labels {
+                let moderated = self.settings.moderate_labels(std::slice::from_ref(label)).is_some();
+                let text = RichText::new(label.trim_start_matches('!'))
+                    .small()
+                    .background_color(ui.visuals().faint_bg_color);
+                let text = if moderated {
+                    text.color(ui.visuals().warn_fg_color)
+                } else {
+                    text
+                };
+                ui.label(text).on_hover_text("Label on this account");
+            }
+        });
+    }
+
     fn make_maybe_user_profile_view(
         &self,
         ui: &mut Ui,
@@ -8,6 +98,25 @@ impl RedskyApp {
     ) {
         match maybe_profile {
             Some(profile) => {
+                if !profile.banner_uri.is_empty() {
+                    match self.image_cache.get(&profile.banner_uri) {
+                        Some(Some(texture)) => {
+                            ui.add(
+                                egui::Image::new(texture)
+                                    .max_height(PROFILE_BANNER_HEIGHT)
+                                    .max_width(ui.available_width()),
+                            );
+                        }
+                        Some(None) => {
+                            ui.allocate_ui(vec2(ui.available_width(), PROFILE_BANNER_HEIGHT), |ui| ui.spinner());
+                        }
+                        None => {
+                            self.post_message(BskyActorMsg::LoadImage {
+                                url: profile.banner_uri.clone(),
+                            });
+                        }
+                    }
+                }
                 ui.horizontal(|ui| {
                     ui.set_max_height(120f32);
                     match self.image_cache.get(&profile.avatar_uri) {
@@ -37,11 +146,34 @@ impl RedskyApp {
                         ui.set_max_height(120f32);
                         ui.heading(&profile.display_name);
                         ui.small(&profile.handle);
+                        if !profile.labels.is_empty() {
+                            self.make_profile_labels(ui, profile);
+                        }
                         ui.label(&profile.bio);
-                        ui.label(format!(
-                            "{} post(s), {} follower(s), {} follow(s)",
-                            &profile.post_count, &profile.follower_count, &profile.follow_count
-                        ));
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{} post(s),", &profile.post_count));
+                            if ui.link(format!("{} follower(s),", &profile.follower_count)).clicked() {
+                                self.post_message(BskyActorMsg::GetFollowers {
+                                    username: profile.handle.clone(),
+                                    cursor: None,
+                                });
+                            }
+                            if ui.link(format!("{} follow(s)", &profile.follow_count)).clicked() {
+                                self.post_message(BskyActorMsg::GetFollows {
+                                    username: profile.handle.clone(),
+                                    cursor: None,
+                                });
+                            }
+                            if profile.handle != self.login
+                                && ui.link("followers you know").clicked()
+                            {
+                                self.post_message(BskyActorMsg::GetKnownFollowers {
+                                    username: profile.handle.clone(),
+                                    cursor: None,
+                                });
+                            }
+                        });
+                        self.make_profile_actions(ui, profile);
                     });
                     ui.allocate_space(ui.available_size());
                 });
@@ -62,8 +194,13 @@ impl RedskyApp {
         posts: &mut Option<Vec<FeedItem>>,
         liked_posts: &mut Option<Vec<FeedItem>>,
     ) {
+        let header_height = self.user_infos_cache.get(username).map_or(150.0, |profile| {
+            let banner = if profile.banner_uri.is_empty() { 0.0 } else { PROFILE_BANNER_HEIGHT };
+            let labels = if profile.labels.is_empty() { 0.0 } else { 20.0 };
+            150.0 + banner + labels
+        });
         StripBuilder::new(ui)
-            .size(Size::exact(150.0))
+            .size(Size::exact(header_height))
             .size(Size::remainder())
             .vertical(|mut strip| {
                 strip.cell(|ui| {
@@ -73,20 +210,22 @@ impl RedskyApp {
                         self.user_infos_cache.get(username),
                     );
 
-                    let mut current_view = self.user_view_states.get(username).cloned().unwrap_or(UserViewState::Posts);
+                    let mut current_view = self.user_view_states.get(username).copied().unwrap_or(UserViewState::Posts);
                     ui.horizontal(|ui| {
                         ui.selectable_value(&mut current_view, UserViewState::Posts, "Posts");
+                        ui.selectable_value(&mut current_view, UserViewState::Replies, "Replies");
                         ui.selectable_value(&mut current_view, UserViewState::Media, "Media");
+                        ui.selectable_value(&mut current_view, UserViewState::Videos, "Videos");
                         ui.selectable_value(&mut current_view, UserViewState::Liked, "Liked");
                     });
-                    self.user_view_states.insert(username.to_string(), current_view.clone());
+                    self.user_view_states.insert(username.to_string(), current_view);
 
                     ui.separator();
                 });
                 strip.strip(|builder| {
                     builder.sizes(Size::remainder(), 1).horizontal(|mut strip| {
                         strip.cell(|ui| {
-                            let current_view = self.user_view_states.get(username).unwrap().clone();
+                            let current_view = self.user_view_states.get(username).copied().unwrap();
                             match current_view {
                                 UserViewState::Posts => {
                                     if let Some(posts_vec) = posts {
@@ -95,12 +234,8 @@ impl RedskyApp {
                                         self.make_placeholder_post_view(ui, username);
                                     }
                                 }
-                                UserViewState::Media => {
-                                    if let Some(posts_vec) = posts {
-                                        self.make_user_media_view(ui, username, posts_vec);
-                                    } else {
-                                        self.make_placeholder_post_view(ui, username);
-                                    }
+                                UserViewState::Replies | UserViewState::Media | UserViewState::Videos => {
+                                    self.make_user_tab_view(ui, username, current_view);
                                 }
                                 UserViewState::Liked => {
                                     if let Some(likes_vec) = liked_posts {
@@ -128,7 +263,33 @@ impl RedskyApp {
                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                      
                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                
 
-    fn make_user_media_view(&mut self, ui: &mut Ui, username: &str, posts: &mut Vec<FeedItem>) {
+    /// The tabs backed by a filtered getAuthorFeed, loaded the first time they are shown.
+    fn make_user_tab_view(&mut self, ui: &mut Ui, username: &str, view: UserViewState) {
+        let key = (username.to_string(), view);
+        match self.user_tabs.posts.remove(&key) {
+            Some(Some(mut items)) => {
+                if view == UserViewState::Replies {
+                    self.make_post_view(ui, username, &mut items);
+                } else {
+                    self.make_user_media_view(ui, username, &mut items);
+                }
+                self.user_tabs.posts.insert(key, Some(items));
+            }
+            loading => {
+                if loading.is_none() {
+                    self.post_message(BskyActorMsg::GetUserPosts {
+                        username: username.to_string(),
+                        view,
+                        cursor: None,
+                    });
+                }
+                self.user_tabs.posts.insert(key, None);
+                self.make_placeholder_post_view(ui, username);
+            }
+        }
+    }
+
+    fn make_user_media_view(&mut self, ui: &mut Ui, username: &str, posts: &mut [FeedItem]) {
         let mut current_size = self.media_image_sizes.get(username).cloned().unwrap_or(200.0);
 
         ui.horizontal(|ui| {
@@ -156,6 +317,21 @@ impl RedskyApp {
 
                     match item {
                         FeedItem::Full(post, _) => {
+                            match self.post_label_action(post) {
+                                Some(LabelAction::Hide(_)) => continue,
+                                Some(LabelAction::Warn(label) | LabelAction::BlurMedia(label)) => {
+                                    for _ in &post.embeds {
+                                        self.make_blurred_media_placeholder(
+                                            ui,
+                                            &post.uri,
+                                            &label,
+                                            vec2(current_size, current_size),
+                                        );
+                                    }
+                                    continue;
+                                }
+                                None => {}
+                            }
                             for embed in &post.embeds {
                                 match self.image_cache.get(&embed.thumbnail_url) {
                                     Some(Some(texture)) => {
@@ -178,7 +354,7 @@ impl RedskyApp {
                                 }
                             }
                         }
-                        FeedItem::Dehydrated { uri: _, height: _ } => {}
+                        FeedItem::Dehydrated { uri: _, height: _ } | FeedItem::Gap { .. } => {}
                     }
                 }
             });
@@ -191,42 +367,45 @@ impl RedskyApp {
         if username == "Your timeline" {
             if let Some(cursor) = self.timeline_cursor.clone() {
                 if ui.button("Load More").clicked() {
-                    self.post_message(BskyActorMsg::GetTimeline {
-                        cursor: Some(cursor),
-                    });
-                    self.timeline_cursor = None;
+                    self.request_feed_page(username, Some(cursor));
                 }
             }
         } else if username != "Thread" {
-            let current_view = self.user_view_states.get(username).cloned().unwrap_or(UserViewState::Posts);
-            match current_view {
-                UserViewState::Posts | UserViewState::Media => {
-                    if let Some(cursor) = self.user_cursors.get(username).cloned().flatten() {
-                        if ui.button("Load More").clicked() {
-                            self.post_message(BskyActorMsg::GetUserPosts {
-                                username: username.to_string(),
-                                cursor: Some(cursor),
-                            });
-                            self.user_cursors.insert(username.to_string(), None);
-                        }
-                    }
-                }
-                UserViewState::Liked => {
-                    if let Some(cursor) = self.user_likes_cursors.get(username).cloned().flatten() {
-                        if ui.button("Load More").clicked() {
-                            self.post_message(BskyActorMsg::GetUserLikes {
-                                username: username.to_string(),
-                                cursor: Some(cursor),
-                            });
-                            self.user_likes_cursors.insert(username.to_string(), None);
-                        }
-                    }
-                }
-            }
+            self.make_user_feed_load_more(ui, username);
         }
 
     }
 
+    /// Load More for the tab currently shown in a user view.
+    fn make_user_feed_load_more(&mut self, ui: &mut Ui, username: &str) {
+        let view = self.user_view_states.get(username).copied().unwrap_or(UserViewState::Posts);
+        let has_more = match view {
+            UserViewState::Posts => matches!(self.user_cursors.get(username), Some(Some(_))),
+            UserViewState::Liked => matches!(self.user_likes_cursors.get(username), Some(Some(_))),
+            _ => self.user_tabs.has_more(username, view),
+        };
+        if !has_more || !ui.button("Load More").clicked() {
+            return;
+        }
+        // Taken to avoid duplicate requests
+        let cursor = match view {
+            UserViewState::Posts => self.user_cursors.get_mut(username).and_then(Option::take),
+            UserViewState::Liked => self.user_likes_cursors.get_mut(username).and_then(Option::take),
+            _ => self.user_tabs.take_cursor(username, view),
+        };
+        match view {
+            UserViewState::Liked => self.post_message(BskyActorMsg::GetUserLikes {
+                username: username.to_string(),
+                cursor,
+            }),
+            _ => self.post_message(BskyActorMsg::GetUserPosts {
+                username: username.to_string(),
+                view,
+                cursor,
+            }),
+        }
+    }
+
     fn make_user_timelines_views(&mut self, ctx: &egui::Context) {
         let mut to_drop = Vec::new();
         let mut to_download = Vec::new();
@@ -258,8 +437,10 @@ impl RedskyApp {
                                 ui.menu_button("View", |ui| {
                                     if ui.button("Refresh posts").clicked() {
                                         self.user_cursors.remove(&username);
+                                        self.user_tabs.drop_user(&username);
                                         self.post_message(BskyActorMsg::GetUserPosts {
                                             username: username.clone(),
+                                            view: UserViewState::Posts,
                                             cursor: None,
                                         });
                                         ui.close();
@@ -286,18 +467,116 @@ impl RedskyApp {
         }
 
         for username in to_download {
-            let ui_tx = self.ui_tx.clone();
-            let ctx = ctx.clone();
-            std::thread::spawn(move || {
-                if let Some(path) = rfd::FileDialog::new().pick_folder() {
-                    let path_str = path.display().to_string();
-                    let _ = ui_tx.send(RedskyUiMsg::StartDownloadJob {
-                        username,
-                        path: path_str,
+            self.pick_media_download_folder(ctx, username);
+        }
+    }
+
+    /// Asks for the destination folder off the UI thread, then starts downloading the user's media.
+    fn pick_media_download_folder(&self, ctx: &egui::Context, username: String) {
+        let ui_tx = self.ui_tx.clone();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                let path_str = path.display().to_string();
+                let _ = ui_tx.send(RedskyUiMsg::StartDownloadJob {
+                    username,
+                    path: path_str,
+                });
+                ctx.request_repaint();
+            }
+        });
+    }
+
+    fn make_profile_image_picker(ui: &mut Ui, path: &mut Option<String>) {
+        ui.horizontal(|ui| {
+            if ui.button("Choose...").clicked() {
+                if let Some(picked) = rfd::FileDialog::new()
+                    .add_filter("Image", &["png", "jpg", "jpeg", "webp"])
+                    .pick_file()
+                {
+                    *path = picked.to_str().map(|picked| picked.to_string());
+                }
+            }
+            match path.clone() {
+                Some(picked) => {
+                    let file_name = std::path::Path::new(&picked)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or(picked);
+                    ui.label(file_name);
+                    if ui.small_button("🗙").on_hover_text("Keep the current image").clicked() {
+                        *path = None;
+                    }
+                }
+                None => {
+                    ui.label(RichText::new("unchanged").weak());
+                }
+            }
+        });
+    }
+
+    fn make_profile_edit_window(&mut self, ctx: &egui::Context) {
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("__edit_profile"),
+            egui::ViewportBuilder::default()
+                .with_title("Edit profile")
+                .with_inner_size([420.0, 360.0]),
+            |ui, _| {
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    let Some(edit) = self.profile_edit.as_mut() else { return };
+                    ui.add_enabled_ui(!self.profile_edit_saving, |ui| {
+                        egui::Grid::new("profile_edit_grid").num_columns(2).show(ui, |ui| {
+                            ui.label("Display name");
+                            ui.text_edit_singleline(&mut edit.display_name);
+                            ui.end_row();
+                            ui.label("Description");
+                            ui.add(egui::TextEdit::multiline(&mut edit.description).desired_rows(4));
+                            ui.end_row();
+                            ui.label("Avatar");
+                            Self::make_profile_image_picker(ui, &mut edit.avatar_path);
+                            ui.end_row();
+                            ui.label("Banner");
+                            Self::make_profile_image_picker(ui, &mut edit.banner_path);
+                            ui.end_row();
+                        });
                     });
-                    ctx.request_repaint();
+                    ui.label(
+                        RichText::new("Avatars are cropped to a square and banners to 3:1, around the center.")
+                            .small()
+                            .weak(),
+                    );
+                    if let Some(error) = &self.profile_edit_error {
+                        ui.colored_label(egui::Color32::RED, error);
+                    }
+                    let edit = edit.clone();
+                    let mut save = false;
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if self.profile_edit_saving {
+                            ui.spinner();
+                        } else if ui.button("Save").clicked() {
+                            self.profile_edit_saving = true;
+                            self.profile_edit_error = None;
+                            save = true;
+                        }
+                        if ui
+                            .button("Reload")
+                            .on_hover_text("Discard these changes and load the latest profile")
+                            .clicked()
+                        {
+                            self.post_message(BskyActorMsg::GetProfileRecord());
+                        }
+                    });
+                    if save {
+                        self.post_message(BskyActorMsg::UpdateProfile { edit });
+                    }
+                });
+
+                if ui.ctx().input(|i| i.viewport().close_requested()) {
+                    self.profile_edit = None;
+                    self.profile_edit_error = None;
                 }
-            });
-        }
+            },
+        );
     }
 }
@@ -1,6 +1,28 @@
 impl RedskyApp {
                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                    
 
+    fn make_user_list_view(&self, ui: &mut Ui, id_source: &str, users: &[UserProfile]) {
+        let filter_id = egui::Id::new(id_source).with("filter");
+        let mut filter = ui.data_mut(|d| d.get_temp::<String>(filter_id).unwrap_or_default());
+        ui.horizontal(|ui| {
+            ui.label("🔍");
+            ui.add(egui::TextEdit::singleline(&mut filter).hint_text("Search in list"));
+        });
+        ui.data_mut(|d| d.insert_temp(filter_id, filter.clone()));
+
+        let needle = filter.to_lowercase();
+        crate::app::show_autoscroll_area(ui, format!("{}_scroll", id_source), false, |ui| {
+            for user in users.iter().filter(|user| {
+                needle.is_empty()
+                    || user.handle.to_lowercase().contains(&needle)
+                    || user.display_name.to_lowercase().contains(&needle)
+            }) {
+                self.make_maybe_user_profile_view(ui, &user.handle, Some(user));
+                ui.separator();
+            }
+        });
+    }
+
     fn make_user_list_viewports(&self, ctx: &egui::Context) {
         for (post_ref, (likers, cursor)) in &self.post_likers_cache {
             ctx.show_viewport_immediate(
@@ -10,12 +32,7 @@ impl RedskyApp {
                     .with_inner_size([300.0, 400.0]),
                 |ui, _| {
                     egui::CentralPanel::default().show_inside(ui, |ui| {
-                        crate::app::show_autoscroll_area(ui, "likers_scroll", false, |ui| {
-                            for user in likers {
-                                self.make_maybe_user_profile_view(ui, &user.handle, Some(user));
-                                ui.separator();
-                            }
-                        });
+                        self.make_user_list_view(ui, "likers", likers);
                         if let Some(c) = cursor.clone() {
                             if ui.button("Load More").clicked() {
                                 self.post_message(BskyActorMsg::GetPostLikers { post_ref: post_ref.clone(), cursor: Some(c) });
@@ -38,12 +55,7 @@ impl RedskyApp {
                     .with_inner_size([300.0, 400.0]),
                 |ui, _| {
                     egui::CentralPanel::default().show_inside(ui, |ui| {
-                        crate::app::show_autoscroll_area(ui, "reposters_scroll", false, |ui| {
-                            for user in reposters {
-                                self.make_maybe_user_profile_view(ui, &user.handle, Some(user));
-                                ui.separator();
-                            }
-                        });
+                        self.make_user_list_view(ui, "reposters", reposters);
                         if let Some(c) = cursor.clone() {
                             if ui.button("Load More").clicked() {
                                 self.post_message(BskyActorMsg::GetPostRepostedBy { post_ref: post_ref.clone(), cursor: Some(c) });
@@ -58,6 +70,43 @@ impl RedskyApp {
                 },
             );
         }
+        for ((username, kind), (users, cursor)) in &self.user_lists_cache {
+            let title = match kind {
+                UserListKind::Followers => format!("Followers of {}", username),
+                UserListKind::Follows => format!("Followed by {}", username),
+                UserListKind::KnownFollowers => format!("Followers of {} you know", username),
+            };
+            ctx.show_viewport_immediate(
+                egui::ViewportId::from_hash_of(format!("user_list_{:?}_{}", kind, username)),
+                egui::ViewportBuilder::default()
+                    .with_title(title)
+                    .with_inner_size([300.0, 400.0]),
+                |ui, _| {
+                    egui::CentralPanel::default().show_inside(ui, |ui| {
+                        self.make_user_list_view(ui, &format!("{:?}_{}", kind, username), users);
+                        if let Some(c) = cursor.clone() {
+                            if ui.button("Load More").clicked() {
+                                let username = username.clone();
+                                let cursor = Some(c);
+                                self.post_message(match kind {
+                                    UserListKind::Followers => BskyActorMsg::GetFollowers { username, cursor },
+                                    UserListKind::Follows => BskyActorMsg::GetFollows { username, cursor },
+                                    UserListKind::KnownFollowers => {
+                                        BskyActorMsg::GetKnownFollowers { username, cursor }
+                                    }
+                                });
+                            }
+                        }
+                    });
+                    if ui.ctx().input(|i| i.viewport().close_requested()) {
+                        self.post_ui_message(RedskyUiMsg::CloseUserListView {
+                            username: username.clone(),
+                            kind: *kind,
+                        });
+                    }
+                },
+            );
+        }
     }
 
                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                
@@ -237,7 +286,7 @@ impl RedskyApp {
             egui::ViewportId::from_hash_of("__new_post"),
             egui::ViewportBuilder::default()
                 .with_title("Post to bsky")
-                .with_inner_size([200.0, 120.0]),
+                .with_inner_size([320.0, 240.0]),
             |ui, _| {
                 egui::CentralPanel::default().show_inside(ui, |ui| {
                     ui.vertical(|ui| {
@@ -247,7 +296,7 @@ impl RedskyApp {
                             ui.heading("New post");
                         }
 
-                        ui.add(egui::TextEdit::multiline(&mut self.msg).hint_text("What's on your mind?"));
+                        self.make_post_editor(ui);
 
                         if !self.new_post_images.is_empty() {
                             ui.label(format!("{} image(s) selected", self.new_post_images.len()));
@@ -296,6 +345,86 @@ impl RedskyApp {
 
                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                     
 
+    /// Post text editor completing the @-mention under the cursor, Tab picks the first suggestion.
+    fn make_post_editor(&mut self, ui: &mut Ui) {
+        let has_suggestions = self
+            .typeaheads
+            .get("composer")
+            .is_some_and(|typeahead| !typeahead.suggestions.is_empty());
+        let tab = has_suggestions && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Tab));
+        let mut output = egui::TextEdit::multiline(&mut self.msg)
+            .hint_text("What's on your mind?")
+            .show(ui);
+        let cursor = output.cursor_range.map(|range| range.primary.index);
+        let mention = cursor.and_then(|cursor| Some((mention_at(&self.msg, cursor)?, cursor)));
+        let typeahead = self.typeaheads.entry("composer".to_string()).or_default();
+        match &mention {
+            Some(((_, partial), _)) => typeahead.set_query(partial, std::time::Instant::now()),
+            None => typeahead.set_query("", std::time::Instant::now()),
+        }
+
+        let mut picked = self.make_actor_typeahead(ui, "composer");
+        if tab {
+            picked = self.typeaheads["composer"].suggestions.first().cloned();
+        }
+        let (Some(profile), Some(((at, _), cursor))) = (picked, mention) else {
+            return;
+        };
+        let completion = format!("@{} ", profile.handle);
+        let mut chars: Vec<char> = self.msg.chars().collect();
+        chars.splice(at..cursor, completion.chars());
+        self.msg = chars.into_iter().collect();
+        let new_cursor = egui::text::CCursor::new(at + completion.chars().count());
+        output
+            .state
+            .cursor
+            .set_char_range(Some(egui::text::CCursorRange::one(new_cursor)));
+        output.state.store(ui.ctx(), output.response.id);
+        output.response.request_focus();
+        if let Some(typeahead) = self.typeaheads.get_mut("composer") {
+            typeahead.query = profile.handle;
+            typeahead.clear();
+        }
+    }
+
+    /// Suggestions of the typeahead named `target`, sending its request once typing pauses.
+    /// Returns the account that was clicked.
+    fn make_actor_typeahead(&mut self, ui: &mut Ui, target: &str) -> Option<UserProfile> {
+        let now = std::time::Instant::now();
+        let typeahead = self.typeaheads.entry(target.to_string()).or_default();
+        if let Some((query, seq)) = typeahead.poll(now, TYPEAHEAD_DEBOUNCE) {
+            self.post_message(BskyActorMsg::SearchActorsTypeahead {
+                target: target.to_string(),
+                query,
+                seq,
+            });
+        } else if typeahead.is_pending() {
+            ui.ctx().request_repaint_after(TYPEAHEAD_DEBOUNCE);
+        }
+
+        let mut clicked = None;
+        for profile in &self.typeaheads[target].suggestions {
+            let response = ui
+                .horizontal(|ui| {
+                    if let Some(Some(texture)) = self.image_cache.get(&profile.avatar_uri) {
+                        ui.add(egui::Image::new(texture).max_width(20.0).max_height(20.0));
+                    }
+                    ui.label(RichText::new(&profile.display_name).strong());
+                    ui.label(RichText::new(format!("@{}", profile.handle)).small().weak());
+                })
+                .response
+                .interact(Sense::click());
+            if response.hovered() {
+                ui.painter()
+                    .rect_stroke(response.rect, 2.0, ui.visuals().selection.stroke, egui::StrokeKind::Inside);
+            }
+            if response.clicked() {
+                clicked = Some(profile.clone());
+            }
+        }
+        clicked
+    }
+
     fn make_search_window(&mut self, ctx: &egui::Context) {
         ctx.show_viewport_immediate(
             egui::ViewportId::from_hash_of("__search_actors"),
@@ -306,14 +435,28 @@ impl RedskyApp {
                 egui::CentralPanel::default().show_inside(ui, |ui| {
                     ui.vertical(|ui| {
                         ui.heading("Search Accounts");
-                        if ui.text_edit_singleline(&mut self.search_query).changed() {
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut self.search_query)
+                                .hint_text("Press Enter for all results"),
+                        );
+                        let typeahead = self.typeaheads.entry("search".to_string()).or_default();
+                        if response.changed() {
+                            typeahead.set_query(self.search_query.trim(), std::time::Instant::now());
+                        }
+                        let enter = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        if enter && !self.search_query.trim().is_empty() {
+                            typeahead.clear();
+                            self.search_results_query = self.search_query.trim().to_string();
+                            self.search_results.clear();
+                            self.search_results_cursor = None;
                             self.post_message(BskyActorMsg::SearchActors {
-                                query: self.search_query.clone(),
+                                query: self.search_results_query.clone(),
+                                cursor: None,
                             });
                         }
+                        let mut clicked_profile = self.make_actor_typeahead(ui, "search");
                         ui.separator();
 
-                        let mut clicked_profile = None;
                         crate::app::show_autoscroll_area(ui, "search_results_scroll", false, |ui| {
                             for profile in &self.search_results {
                                 let (rect, response) = ui.allocate_at_least(
@@ -353,8 +496,20 @@ impl RedskyApp {
                                         ui.label(RichText::new(&profile.display_name).strong());
                                         ui.small(&profile.handle);
                                     });
+                                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                        self.make_profile_actions(ui, profile);
+                                    });
                                 });
                             }
+                            if let Some(cursor) = self.search_results_cursor.clone() {
+                                if ui.button("Load More").clicked() {
+                                    self.post_message(BskyActorMsg::SearchActors {
+                                        query: self.search_results_query.clone(),
+                                        cursor: Some(cursor),
+                                    });
+                                    self.search_results_cursor = None;
+                                }
+                            }
                         });
                         if let Some(profile) = clicked_profile {
                             self.post_ui_message(RedskyUiMsg::PrepareUserView {
@@ -362,6 +517,7 @@ impl RedskyApp {
                             });
                             self.post_message(BskyActorMsg::GetUserPosts {
                                 username: profile.handle.clone(),
+                                view: UserViewState::Posts,
                                 cursor: None,
                             });
                             self.is_search_window_open = false;
@@ -376,6 +532,44 @@ impl RedskyApp {
         );
     }
 
+    /// Returns true when the sort order changed, which searches again right away.
+    fn make_search_posts_filters(&mut self, ui: &mut Ui) -> bool {
+        let mut sort_changed = false;
+        ui.horizontal(|ui| {
+            ui.label("Sort:");
+            let sort = &mut self.search_posts.sort;
+            sort_changed |= ui.selectable_value(sort, PostSearchSort::Top, "Top").clicked();
+            sort_changed |= ui.selectable_value(sort, PostSearchSort::Latest, "Latest").clicked();
+        });
+        egui::CollapsingHeader::new("Filters").show(ui, |ui| {
+            egui::Grid::new("search_posts_filters").num_columns(2).show(ui, |ui| {
+                let search = &mut self.search_posts;
+                let fields: [(&str, &mut String, &str); 8] = [
+                    ("From", &mut search.author, "handle, or me"),
+                    ("Mentions", &mut search.mentions, "handle"),
+                    ("Since", &mut search.since, "YYYY-MM-DD"),
+                    ("Until", &mut search.until, "YYYY-MM-DD"),
+                    ("Language", &mut search.lang, "en"),
+                    ("Domain", &mut search.domain, "example.com"),
+                    ("URL", &mut search.url, "https://…"),
+                    ("Tags", &mut search.tags, "rust egui"),
+                ];
+                for (label, value, hint) in fields {
+                    ui.label(label);
+                    ui.add(egui::TextEdit::singleline(value).hint_text(hint));
+                    ui.end_row();
+                }
+            });
+            if ui.button("Clear filters").clicked() {
+                self.search_posts = PostSearch {
+                    query: std::mem::take(&mut self.search_posts.query),
+                    ..Default::default()
+                };
+            }
+        });
+        sort_changed && self.search_posts_results.is_some()
+    }
+
     fn make_search_posts_window(&mut self, ctx: &egui::Context) {
         ctx.show_viewport_immediate(
             egui::ViewportId::from_hash_of("__search_posts"),
@@ -386,18 +580,41 @@ impl RedskyApp {
                 egui::CentralPanel::default().show_inside(ui, |ui| {
                     ui.vertical(|ui| {
                         ui.heading("Search Posts");
+                        let mut search = false;
                         ui.horizontal(|ui| {
-                            let text_edit_response = ui.text_edit_singleline(&mut self.search_posts_query);
+                            let text_edit_response = ui.add(
+                                egui::TextEdit::singleline(&mut self.search_posts.query)
+                                    .hint_text("from:alice since:2026-01-01 rust"),
+                            );
                             let enter_pressed = text_edit_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
-                            if ui.button("Search").clicked() || enter_pressed {
-                                self.search_posts_results = Some(vec![]);
-                                self.search_posts_cursor = None;
-                                self.post_message(BskyActorMsg::SearchPosts {
-                                    query: self.search_posts_query.clone(),
-                                    cursor: None,
-                                });
+                            search = ui.button("Search").clicked() || enter_pressed;
+                            if ui
+                                .button("Save search")
+                                .on_hover_text("Pin this search as a tab, sorted by latest")
+                                .clicked()
+                            {
+                                self.search_posts.apply_inline_operators();
+                                let query = self.search_posts.to_inline_query();
+                                if !query.is_empty() {
+                                    self.pin_saved_search(SavedSearch {
+                                        name: query.clone(),
+                                        query,
+                                        pinned: true,
+                                    });
+                                }
                             }
                         });
+                        search |= self.make_search_posts_filters(ui);
+                        self.make_saved_searches_list(ui);
+                        if search {
+                            self.run_post_search();
+                        }
+                        if let (Some(total), Some(results)) = (self.search_posts_total, &self.search_posts_results) {
+                            ui.label(
+                                RichText::new(format!("Showing {} of {} posts", results.len(), total.max(results.len() as i64)))
+                                    .weak(),
+                            );
+                        }
                         ui.separator();
 
                         let results = self.search_posts_results.take();
@@ -417,4 +634,56 @@ impl RedskyApp {
             },
         );
     }
+
+    fn make_history_window(&mut self, ctx: &egui::Context) {
+        ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("__search_history"),
+            egui::ViewportBuilder::default()
+                .with_title("Search My History")
+                .with_inner_size([500.0, 700.0]),
+            |ui, _| {
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.heading("Search My History");
+                        ui.label(RichText::new("Every post Redsky has loaded, even offline").weak());
+                        let mut search = false;
+                        ui.horizontal(|ui| {
+                            let text_edit_response = ui.text_edit_singleline(&mut self.history_query);
+                            let enter_pressed = text_edit_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            search = ui.button("Search").clicked() || enter_pressed;
+                        });
+                        ui.horizontal(|ui| {
+                            let filter = &mut self.history_filter;
+                            search |= ui.selectable_value(filter, HistoryFilter::All, "All").clicked();
+                            search |= ui.selectable_value(filter, HistoryFilter::Liked, "My likes").clicked();
+                            search |= ui.selectable_value(filter, HistoryFilter::Bookmarked, "My bookmarks").clicked();
+                        });
+                        if search && !self.history_query.trim().is_empty() {
+                            self.history_results = Some(vec![]);
+                            self.post_message(BskyActorMsg::SearchHistory {
+                                query: self.history_query.clone(),
+                                filter: self.history_filter,
+                            });
+                        }
+                        ui.separator();
+
+                        let results = self.history_results.take();
+                        if let Some(mut posts) = results {
+                            if posts.is_empty() {
+                                ui.label("No matching posts.");
+                            }
+                            self.make_post_view(ui, "History", &mut posts);
+                            self.history_results = Some(posts);
+                        } else {
+                            ui.label("Enter a query and click Search.");
+                        }
+                    });
+                });
+
+                if ui.ctx().input(|i| i.viewport().close_requested()) {
+                    self.is_history_window_open = false;
+                }
+            },
+        );
+    }
 }
@@ -29,24 +29,7 @@ impl BskyJob {
             .data
             .likes
             .iter()
-            .map(|like_data| {
-                let profile = &like_data.actor;
-                UserProfile {
-                    handle: profile.handle.clone().into(),
-                    display_name: profile
-                        .display_name
-                        .clone()
-                        .unwrap_or("(no display name)".to_string()),
-                    bio: profile
-                        .description
-                        .clone()
-                        .unwrap_or("(No bio)".to_string()),
-                    avatar_uri: profile.avatar.clone().unwrap_or("".to_string()),
-                    follower_count: 0,
-                    follow_count: 0,
-                    post_count: 0,
-                }
-            })
+            .map(|like_data| extract_profile(&like_data.actor))
             .collect();
 
         Ok(RedskyUiMsg::NotifyLikesLoaded {
@@ -85,21 +68,7 @@ impl BskyJob {
             .data
             .reposted_by
             .iter()
-            .map(|profile| UserProfile {
-                handle: profile.handle.clone().into(),
-                display_name: profile
-                    .display_name
-                    .clone()
-                    .unwrap_or("(no display name)".to_string()),
-                bio: profile
-                    .description
-                    .clone()
-                    .unwrap_or("(No bio)".to_string()),
-                avatar_uri: profile.avatar.clone().unwrap_or("".to_string()),
-                follower_count: 0,
-                follow_count: 0,
-                post_count: 0,
-            })
+            .map(extract_profile)
             .collect();
 
         Ok(RedskyUiMsg::NotifyRepostersLoaded {
@@ -248,6 +217,485 @@ impl BskyJob {
         Ok(RedskyUiMsg::ActionSucceeded())
     }
 
+    async fn follow(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("following", &did);
+        let response = self
+            .bsky_agent
+            .create_record(atrium_api::app::bsky::graph::follow::RecordData {
+                created_at: Datetime::now(),
+                subject: did.parse().map_err(|e| format!("Invalid DID: {}", e))?,
+            })
+            .await?;
+        Ok(RedskyUiMsg::NotifyFollowActionSucceeded {
+            did: did.clone(),
+            follow_uri: response.data.uri,
+        })
+    }
+
+    async fn unfollow(
+        &self,
+        _did: String,
+        follow_record_uri: String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("unfollowing");
+        let parts: Vec<&str> = follow_record_uri.split('/').collect();
+        let rkey = parts.last().ok_or("Invalid follow record URI")?;
+
+        let session = self.bsky_agent.api.com.atproto.server.get_session().await?;
+
+        self.bsky_agent
+            .api
+            .com
+            .atproto
+            .repo
+            .delete_record(
+                atrium_api::com::atproto::repo::delete_record::InputData {
+                    collection: "app.bsky.graph.follow".parse()?,
+                    repo: AtIdentifier::Did(session.data.did),
+                    rkey: RecordKey::new(rkey.to_string()).map_err(|e| e.to_string())?,
+                    swap_commit: None,
+                    swap_record: None,
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn block(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("blocking", &did);
+        let response = self
+            .bsky_agent
+            .create_record(atrium_api::app::bsky::graph::block::RecordData {
+                created_at: Datetime::now(),
+                subject: did.parse().map_err(|e| format!("Invalid DID: {}", e))?,
+            })
+            .await?;
+        Ok(RedskyUiMsg::NotifyBlockActionSucceeded {
+            did: did.clone(),
+            block_uri: response.data.uri,
+        })
+    }
+
+    async fn unblock(
+        &self,
+        _did: String,
+        block_record_uri: String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("unblocking");
+        let parts: Vec<&str> = block_record_uri.split('/').collect();
+        let rkey = parts.last().ok_or("Invalid block record URI")?;
+
+        let session = self.bsky_agent.api.com.atproto.server.get_session().await?;
+
+        self.bsky_agent
+            .api
+            .com
+            .atproto
+            .repo
+            .delete_record(
+                atrium_api::com::atproto::repo::delete_record::InputData {
+                    collection: "app.bsky.graph.block".parse()?,
+                    repo: AtIdentifier::Did(session.data.did),
+                    rkey: RecordKey::new(rkey.to_string()).map_err(|e| e.to_string())?,
+                    swap_commit: None,
+                    swap_record: None,
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn mute(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("muting", &did);
+        self.bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .mute_actor(
+                atrium_api::app::bsky::graph::mute_actor::InputData {
+                    actor: AtIdentifier::Did(
+                        did.parse().map_err(|e| format!("Invalid DID: {}", e))?,
+                    ),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn unmute(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("unmuting", &did);
+        self.bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .unmute_actor(
+                atrium_api::app::bsky::graph::unmute_actor::InputData {
+                    actor: AtIdentifier::Did(
+                        did.parse().map_err(|e| format!("Invalid DID: {}", e))?,
+                    ),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn get_blocks(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get blocks");
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_blocks(
+                atrium_api::app::bsky::graph::get_blocks::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyBlocksLoaded {
+            users: response.data.blocks.iter().map(extract_profile).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_mutes(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get mutes");
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_mutes(
+                atrium_api::app::bsky::graph::get_mutes::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyMutesLoaded {
+            users: response.data.mutes.iter().map(extract_profile).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_preferences(
+        &self,
+    ) -> Result<atrium_api::app::bsky::actor::defs::Preferences, Box<dyn std::error::Error + Send + Sync>>
+    {
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .actor
+            .get_preferences(atrium_api::app::bsky::actor::get_preferences::ParametersData {}.into())
+            .await?;
+        Ok(response.data.preferences)
+    }
+
+    async fn update_preferences<F>(
+        &self,
+        update_fn: F,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+    where
+        F: FnOnce(&mut atrium_api::app::bsky::actor::defs::Preferences),
+    {
+        read_modify_write(
+            &self.preferences_lock,
+            || self.get_preferences(),
+            update_fn,
+            |preferences| async move {
+                self.bsky_agent
+                    .api
+                    .app
+                    .bsky
+                    .actor
+                    .put_preferences(
+                        atrium_api::app::bsky::actor::put_preferences::InputData { preferences }.into(),
+                    )
+                    .await?;
+                Ok(())
+            },
+        )
+        .await
+    }
+
+    async fn get_muted_words(
+        &self,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get muted words");
+        let words = self
+            .get_preferences()
+            .await?
+            .iter()
+            .filter_map(|pref| match pref {
+                Union::Refs(PreferencesItem::MutedWordsPref(muted_words)) => Some(
+                    muted_words
+                        .items
+                        .iter()
+                        .map(|word| extract_muted_word(word))
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        Ok(RedskyUiMsg::NotifyMutedWordsLoaded { words })
+    }
+
+    async fn put_muted_words(
+        &self,
+        words: &[MutedWord],
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("put muted words");
+        let items: Vec<_> = words.iter().map(muted_word_to_pref).collect();
+        // Reply with the written words, so the ids given to new words are kept
+        let words = items.iter().map(extract_muted_word).collect();
+        let items = items.into_iter().map(Into::into).collect();
+        self.update_preferences(|preferences| {
+            preferences.retain(|pref| {
+                !matches!(pref, Union::Refs(PreferencesItem::MutedWordsPref(_)))
+            });
+            preferences.push(Union::Refs(PreferencesItem::MutedWordsPref(Box::new(
+                atrium_api::app::bsky::actor::defs::MutedWordsPrefData { items }.into(),
+            ))));
+        })
+        .await?;
+        Ok(RedskyUiMsg::NotifyMutedWordsLoaded { words })
+    }
+
+    async fn get_content_prefs(
+        &self,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get content prefs");
+        let mut adult_content_enabled = false;
+        let mut label_visibility = std::collections::BTreeMap::new();
+        for pref in self.get_preferences().await? {
+            match pref {
+                Union::Refs(PreferencesItem::AdultContentPref(adult)) => {
+                    adult_content_enabled = adult.enabled;
+                }
+                Union::Refs(PreferencesItem::ContentLabelPref(label_pref))
+                    if label_pref.labeler_did.is_none() =>
+                {
+                    label_visibility.insert(
+                        label_pref.label.clone(),
+                        LabelVisibility::from_pref(&label_pref.visibility),
+                    );
+                }
+                _ => {}
+            }
+        }
+        Ok(RedskyUiMsg::NotifyContentPrefsLoaded {
+            adult_content_enabled,
+            label_visibility,
+        })
+    }
+
+    async fn put_content_prefs(
+        &self,
+        adult_content_enabled: bool,
+        label_visibility: &std::collections::BTreeMap<String, LabelVisibility>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("put content prefs");
+        self.update_preferences(|preferences| {
+            preferences.retain(|pref| match pref {
+                Union::Refs(PreferencesItem::AdultContentPref(_)) => false,
+                Union::Refs(PreferencesItem::ContentLabelPref(label_pref)) => {
+                    label_pref.labeler_did.is_some()
+                        || !label_visibility.contains_key(&label_pref.label)
+                }
+                _ => true,
+            });
+            preferences.push(Union::Refs(PreferencesItem::AdultContentPref(Box::new(
+                atrium_api::app::bsky::actor::defs::AdultContentPrefData {
+                    enabled: adult_content_enabled,
+                }
+                .into(),
+            ))));
+            for (label, visibility) in label_visibility {
+                preferences.push(Union::Refs(PreferencesItem::ContentLabelPref(Box::new(
+                    atrium_api::app::bsky::actor::defs::ContentLabelPrefData {
+                        label: label.clone(),
+                        labeler_did: None,
+                        visibility: visibility.to_pref().to_string(),
+                    }
+                    .into(),
+                ))));
+            }
+        })
+        .await?;
+        Ok(RedskyUiMsg::NotifyContentPrefsLoaded {
+            adult_content_enabled,
+            label_visibility: label_visibility.clone(),
+        })
+    }
+
+    async fn load_feed_view_pref(&self) -> Result<FeedViewPref, Box<dyn std::error::Error + Send + Sync>> {
+        let mut pref = FeedViewPref::default();
+        for item in self.get_preferences().await? {
+            if let Union::Refs(PreferencesItem::FeedViewPref(feed_pref)) = item {
+                if feed_pref.feed == HOME_FEED_PREF {
+                    let defaults = FeedViewPref::default();
+                    pref = FeedViewPref {
+                        hide_reposts: feed_pref.hide_reposts.unwrap_or(defaults.hide_reposts),
+                        hide_replies: feed_pref.hide_replies.unwrap_or(defaults.hide_replies),
+                        hide_replies_by_unfollowed: feed_pref
+                            .hide_replies_by_unfollowed
+                            .unwrap_or(defaults.hide_replies_by_unfollowed),
+                        hide_quote_posts: feed_pref.hide_quote_posts.unwrap_or(defaults.hide_quote_posts),
+                    };
+                }
+            }
+        }
+        *self.feed_view_pref.write().await = Some(pref);
+        Ok(pref)
+    }
+
+    async fn get_feed_view_pref(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get feed view pref");
+        let pref = self.load_feed_view_pref().await?;
+        Ok(RedskyUiMsg::NotifyFeedViewPrefLoaded { pref })
+    }
+
+    async fn put_feed_view_pref(
+        &self,
+        pref: FeedViewPref,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("put feed view pref");
+        self.update_preferences(|preferences| {
+            // Edit the existing entry in place to keep the settings we don't expose
+            let existing = preferences.iter_mut().find_map(|item| match item {
+                Union::Refs(PreferencesItem::FeedViewPref(feed_pref)) if feed_pref.feed == HOME_FEED_PREF => {
+                    Some(feed_pref)
+                }
+                _ => None,
+            });
+            match existing {
+                Some(feed_pref) => {
+                    feed_pref.hide_reposts = Some(pref.hide_reposts);
+                    feed_pref.hide_replies = Some(pref.hide_replies);
+                    feed_pref.hide_replies_by_unfollowed = Some(pref.hide_replies_by_unfollowed);
+                    feed_pref.hide_quote_posts = Some(pref.hide_quote_posts);
+                }
+                None => preferences.push(Union::Refs(PreferencesItem::FeedViewPref(Box::new(
+                    atrium_api::app::bsky::actor::defs::FeedViewPrefData {
+                        feed: HOME_FEED_PREF.to_string(),
+                        hide_quote_posts: Some(pref.hide_quote_posts),
+                        hide_replies: Some(pref.hide_replies),
+                        hide_replies_by_like_count: None,
+                        hide_replies_by_unfollowed: Some(pref.hide_replies_by_unfollowed),
+                        hide_reposts: Some(pref.hide_reposts),
+                    }
+                    .into(),
+                )))),
+            }
+        })
+        .await?;
+        *self.feed_view_pref.write().await = Some(pref);
+        Ok(RedskyUiMsg::NotifyFeedViewPrefLoaded { pref })
+    }
+
+    /// Rewrites an at:// uri naming its repo by handle to use the did instead.
+    async fn uri_with_did(&self, uri: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let path = uri.strip_prefix("at://").ok_or("Not an at:// uri")?;
+        let (authority, rest) = path.split_once('/').unwrap_or((path, ""));
+        if authority.starts_with("did:") {
+            return Ok(uri.to_string());
+        }
+        let handle = authority.parse().map_err(|e| format!("Invalid handle: {}", e))?;
+        let response = self
+            .bsky_agent
+            .api
+            .com
+            .atproto
+            .identity
+            .resolve_handle(atrium_api::com::atproto::identity::resolve_handle::ParametersData { handle }.into())
+            .await?;
+        Ok(format!("at://{}/{}", response.data.did.as_str(), rest))
+    }
+
+    /// Finds the view to open for a pasted link: the thread of a post needs its cid, feeds and
+    /// lists are keyed by their did uri and profile views by handle.
+    async fn resolve_link(&self, target: &AtUriTarget) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("resolve link", target);
+        match target {
+            AtUriTarget::Profile(actor) => {
+                let response = self
+                    .bsky_agent
+                    .api
+                    .app
+                    .bsky
+                    .actor
+                    .get_profile(
+                        atrium_api::app::bsky::actor::get_profile::ParametersData {
+                            actor: actor.parse().map_err(|e| format!("Invalid handle or did: {}", e))?,
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(RedskyUiMsg::OpenUserView {
+                    username: response.data.handle.to_string(),
+                })
+            }
+            AtUriTarget::Post(uri) => {
+                let uri = self.uri_with_did(uri).await?;
+                let response = self
+                    .bsky_agent
+                    .api
+                    .app
+                    .bsky
+                    .feed
+                    .get_posts(atrium_api::app::bsky::feed::get_posts::ParametersData { uris: vec![uri] }.into())
+                    .await?;
+                let post = response.data.posts.first().ok_or("Post not found")?;
+                Ok(RedskyUiMsg::PrepareThreadView {
+                    thread_ref: StrongRef {
+                        uri: post.uri.clone(),
+                        cid: post.cid.clone(),
+                    },
+                })
+            }
+            AtUriTarget::Feed(uri) | AtUriTarget::List(uri) => Ok(RedskyUiMsg::OpenFeedView {
+                feed_uri: self.uri_with_did(uri).await?,
+            }),
+        }
+    }
+
     async fn get_post_thread(
         &self,
         strong_ref: &StrongRef,
@@ -333,10 +781,53 @@ impl BskyJob {
 
     async fn search_posts(
         &self,
-        query: &String,
+        search: &PostSearch,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let (posts, next_cursor, hits_total) = self.fetch_search_posts(search, cursor).await?;
+        Ok(RedskyUiMsg::ShowSearchPostsResults {
+            posts,
+            cursor: next_cursor,
+            append: cursor.is_some(),
+            hits_total,
+        })
+    }
+
+    async fn get_saved_search(
+        &self,
+        query: &str,
         cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
-        dbg!("search posts", &query);
+        let own_did = self.bsky_agent.did().await.map(|did| did.to_string()).unwrap_or_default();
+        let search = PostSearch::saved(query, &own_did);
+        let (posts, next_cursor, _) = self.fetch_search_posts(&search, cursor).await?;
+        Ok(RedskyUiMsg::RefreshSavedSearchMsg {
+            query: query.to_string(),
+            posts,
+            cursor: next_cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn fetch_search_posts(
+        &self,
+        search: &PostSearch,
+        cursor: &Option<String>,
+    ) -> Result<(Vec<Post>, Option<String>, Option<i64>), Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("search posts", &search);
+        let non_empty = |value: &String| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+        let identifier = |value: &String| -> Result<Option<AtIdentifier>, Box<dyn std::error::Error + Send + Sync>> {
+            match non_empty(value) {
+                Some(value) => Ok(Some(
+                    value
+                        .trim_start_matches('@')
+                        .parse()
+                        .map_err(|e| format!("Invalid handle {}: {}", value, e))?,
+                )),
+                None => Ok(None),
+            }
+        };
+        let tags = search.tag_list();
         let response = self
             .bsky_agent
             .api
@@ -345,40 +836,69 @@ impl BskyJob {
             .feed
             .search_posts(
                 atrium_api::app::bsky::feed::search_posts::ParametersData {
-                    q: query.clone(),
+                    q: search.query.clone(),
                     limit: 30.try_into().ok(),
                     cursor: cursor.clone(),
-                    author: None,
-                    domain: None,
-                    lang: None,
-                    mentions: None,
-                    since: None,
-                    sort: None,
-                    tag: None,
-                    until: None,
-                    url: None,
+                    author: identifier(&search.author)?,
+                    domain: non_empty(&search.domain),
+                    lang: match non_empty(&search.lang) {
+                        Some(lang) => Some(lang.parse().map_err(|e| format!("Invalid language {}: {}", lang, e))?),
+                        None => None,
+                    },
+                    mentions: identifier(&search.mentions)?,
+                    since: non_empty(&search.since),
+                    sort: Some(match search.sort {
+                        PostSearchSort::Top => "top".to_string(),
+                        PostSearchSort::Latest => "latest".to_string(),
+                    }),
+                    tag: (!tags.is_empty()).then_some(tags),
+                    until: non_empty(&search.until),
+                    url: non_empty(&search.url),
                 }
                 .into(),
             )
             .await?;
 
-        Ok(RedskyUiMsg::ShowSearchPostsResults {
-            posts: response
-                .data
-                .posts
-                .iter()
-                .filter_map(|post_view| extract_post(post_view))
-                .collect(),
-            cursor: response.data.cursor,
-            append: cursor.is_some(),
-        })
+        let posts = response.data.posts.iter().filter_map(extract_post).collect();
+        Ok((posts, response.data.cursor, response.data.hits_total))
     }
 
     async fn search_actors(
         &self,
         query: &String,
+        cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         dbg!("search actors", &query);
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .actor
+            .search_actors(
+                atrium_api::app::bsky::actor::search_actors::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 25.try_into().ok(),
+                    q: Some(query.clone()),
+                    term: None, // DEPRECATED: use 'q' instead.
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::ShowSearchResults {
+            query: query.clone(),
+            results: response.data.actors.iter().map(extract_profile).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn search_actors_typeahead(
+        &self,
+        target: &str,
+        query: &str,
+        seq: u64,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         let response = self
             .bsky_agent
             .api
@@ -387,8 +907,8 @@ impl BskyJob {
             .actor
             .search_actors_typeahead(
                 atrium_api::app::bsky::actor::search_actors_typeahead::ParametersData {
-                    limit: 10.try_into().ok(),
-                    q: Some(query.clone()),
+                    limit: 8.try_into().ok(),
+                    q: Some(query.to_string()),
                     term: None, // DEPRECATED: use 'q' instead.
                 }
                 .into(),
@@ -400,6 +920,7 @@ impl BskyJob {
             .actors
             .iter()
             .map(|actor| UserProfile {
+                did: actor.did.to_string(),
                 handle: actor.handle.to_string(),
                 display_name: actor
                     .display_name
@@ -410,10 +931,28 @@ impl BskyJob {
                 follower_count: 0,
                 follow_count: 0,
                 post_count: 0,
+                viewer_following: actor.viewer.as_ref().and_then(|v| v.following.clone()).map(RecordState::Created),
+                viewer_followed_by: actor
+                    .viewer
+                    .as_ref()
+                    .is_some_and(|v| v.followed_by.is_some()),
+                viewer_blocking: actor.viewer.as_ref().and_then(|v| v.blocking.clone()).map(RecordState::Created),
+                viewer_muted: actor
+                    .viewer
+                    .as_ref()
+                    .and_then(|v| v.muted)
+                    .unwrap_or(false),
+                banner_uri: String::new(),
+                labels: extract_profile_labels(&actor.labels),
+                pinned_post: None,
             })
             .collect();
 
-        Ok(RedskyUiMsg::ShowSearchResults { results })
+        Ok(RedskyUiMsg::ShowActorSuggestions {
+            target: target.to_string(),
+            seq,
+            actors: results,
+        })
     }
 
     async fn get_user_likes(
@@ -462,6 +1001,7 @@ impl BskyJob {
     async fn get_user_posts(
         &self,
         username: &String,
+        view: UserViewState,
         cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         dbg!("get user posts");
@@ -481,8 +1021,9 @@ impl BskyJob {
                             .map_err(|e| format!("Invalid handle: {}", e))?,
                     ),
                     cursor: cursor.clone(),
-                    filter: None,
-                    include_pins: Some(true),
+                    filter: view.author_feed_filter().map(|filter| filter.to_string()),
+                    // Only the first page carries the pinned post
+                    include_pins: Some(matches!(view, UserViewState::Posts | UserViewState::Replies)),
                     limit: 30.try_into().ok(),
                 }
                 .into(),
@@ -491,6 +1032,7 @@ impl BskyJob {
 
         Ok(RedskyUiMsg::ShowUserPostsMsg {
             username: username.to_string(),
+            view,
             posts: response
                 .data
                 .feed
@@ -498,7 +1040,13 @@ impl BskyJob {
                 .filter_map(
                     |post_el: &atrium_api::types::Object<
                         atrium_api::app::bsky::feed::defs::FeedViewPostData,
-                    >| { extract_post(&post_el.post) },
+                    >| {
+                        let mut post = extract_post(&post_el.post)?;
+                        if view == UserViewState::Videos {
+                            post.embeds.extend(extract_video_thumbnail(&post_el.post));
+                        }
+                        Some(post)
+                    },
                 )
                 .collect(),
             cursor: response.data.cursor,
@@ -532,6 +1080,7 @@ impl BskyJob {
 
         Ok(RedskyUiMsg::ShowUserProfile {
             profile: UserProfile {
+                did: profile.did.to_string(),
                 handle: username.clone(),
                 display_name: profile
                     .display_name
@@ -545,41 +1094,856 @@ impl BskyJob {
                 follower_count: profile.followers_count.unwrap_or_default(),
                 follow_count: profile.follows_count.unwrap_or_default(),
                 post_count: profile.posts_count.unwrap_or_default(),
+                viewer_following: profile.viewer.as_ref().and_then(|v| v.following.clone()).map(RecordState::Created),
+                viewer_followed_by: profile
+                    .viewer
+                    .as_ref()
+                    .is_some_and(|v| v.followed_by.is_some()),
+                viewer_blocking: profile.viewer.as_ref().and_then(|v| v.blocking.clone()).map(RecordState::Created),
+                viewer_muted: profile
+                    .viewer
+                    .as_ref()
+                    .and_then(|v| v.muted)
+                    .unwrap_or(false),
+                banner_uri: profile.banner.clone().unwrap_or_default(),
+                labels: extract_profile_labels(&profile.labels),
+                pinned_post: profile.pinned_post.as_ref().map(|pinned| StrongRef {
+                    uri: pinned.uri.clone(),
+                    cid: pinned.cid.clone(),
+                }),
+            },
+        })
+    }
+
+    async fn get_followers(
+        &self,
+        username: &String,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get followers", &username);
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_followers(
+                atrium_api::app::bsky::graph::get_followers::ParametersData {
+                    actor: AtIdentifier::Handle(
+                        username
+                            .parse()
+                            .map_err(|e| format!("Invalid handle: {}", e))?,
+                    ),
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyUserListLoaded {
+            username: username.clone(),
+            kind: UserListKind::Followers,
+            users: response.data.followers.iter().map(extract_profile).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_follows(
+        &self,
+        username: &String,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get follows", &username);
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_follows(
+                atrium_api::app::bsky::graph::get_follows::ParametersData {
+                    actor: AtIdentifier::Handle(
+                        username
+                            .parse()
+                            .map_err(|e| format!("Invalid handle: {}", e))?,
+                    ),
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyUserListLoaded {
+            username: username.clone(),
+            kind: UserListKind::Follows,
+            users: response.data.follows.iter().map(extract_profile).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_known_followers(
+        &self,
+        username: &String,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get known followers", &username);
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_known_followers(
+                atrium_api::app::bsky::graph::get_known_followers::ParametersData {
+                    actor: AtIdentifier::Handle(
+                        username
+                            .parse()
+                            .map_err(|e| format!("Invalid handle: {}", e))?,
+                    ),
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyUserListLoaded {
+            username: username.clone(),
+            kind: UserListKind::KnownFollowers,
+            users: response.data.followers.iter().map(extract_profile).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_timeline_posts(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get tl");
+        let (posts, next_cursor, hidden) = self.fetch_timeline_page(cursor).await?;
+        Ok(RedskyUiMsg::RefreshTimelineMsg {
+            posts,
+            cursor: next_cursor,
+            append: cursor.is_some(),
+            hidden,
+        })
+    }
+
+    async fn get_timeline_gap(&self, cursor: &str) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let (posts, next_cursor, hidden) = self.fetch_timeline_page(&Some(cursor.to_string())).await?;
+        Ok(RedskyUiMsg::FillTimelineGapMsg {
+            gap_cursor: cursor.to_string(),
+            posts,
+            cursor: next_cursor,
+            hidden,
+        })
+    }
+
+    /// Returns the posts left after applying the timeline view preferences, and the uris of the hidden ones.
+    async fn fetch_timeline_page(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<(Vec<Post>, Option<String>, Vec<String>), Box<dyn std::error::Error + Send + Sync>> {
+        let cached_pref = *self.feed_view_pref.read().await;
+        let pref = match cached_pref {
+            Some(pref) => pref,
+            None => self.load_feed_view_pref().await?,
+        };
+        let own_did = self.bsky_agent.did().await;
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_timeline(
+                atrium_api::app::bsky::feed::get_timeline::ParametersData {
+                    algorithm: None,
+                    cursor: cursor.clone(),
+                    limit: 30.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        let mut posts = Vec::new();
+        let mut hidden = Vec::new();
+        for feed_element in &response.data.feed {
+            let is_repost = matches!(
+                feed_element.reason,
+                Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(_)))
+            );
+            // Reposted replies count as reposts, like in the official app
+            let reply_to_followed = feed_element.reply.as_ref().filter(|_| !is_repost).map(|reply| {
+                match &reply.parent {
+                    Union::Refs(ReplyRefParentRefs::PostView(parent)) => {
+                        parent.author.viewer.as_ref().is_some_and(|viewer| viewer.following.is_some())
+                            || own_did.as_ref() == Some(&parent.author.did)
+                    }
+                    _ => false,
+                }
+            });
+            if pref.hides(is_repost, reply_to_followed, is_quote(&feed_element.post)) {
+                hidden.push(feed_element.post.uri.clone());
+            } else if let Some(post) = extract_post(&feed_element.post) {
+                posts.push(post);
+            }
+        }
+        Ok((posts, response.data.cursor, hidden))
+    }
+
+    async fn get_feed(
+        &self,
+        feed_uri: &str,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get feed", feed_uri);
+
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_feed(
+                atrium_api::app::bsky::feed::get_feed::ParametersData {
+                    cursor: cursor.clone(),
+                    feed: feed_uri.to_string(),
+                    limit: 30.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::RefreshCustomFeedMsg {
+            feed_uri: feed_uri.to_string(),
+            posts: response
+                .data
+                .feed
+                .iter()
+                .filter_map(|feed_element| extract_post(&feed_element.post))
+                .collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_list_feed(
+        &self,
+        list_uri: &str,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get list feed", list_uri);
+
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_list_feed(
+                atrium_api::app::bsky::feed::get_list_feed::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 30.try_into().ok(),
+                    list: list_uri.to_string(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::RefreshCustomFeedMsg {
+            feed_uri: list_uri.to_string(),
+            posts: response
+                .data
+                .feed
+                .iter()
+                .filter_map(|feed_element| extract_post(&feed_element.post))
+                .collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_lists(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get lists");
+        let session = self.bsky_agent.api.com.atproto.server.get_session().await?;
+
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_lists(
+                atrium_api::app::bsky::graph::get_lists::ParametersData {
+                    actor: AtIdentifier::Did(session.data.did),
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                    purposes: None,
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyListsLoaded {
+            lists: response.data.lists.iter().map(extract_user_list).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_list(
+        &self,
+        list_uri: &str,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get list", list_uri);
+
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_list(
+                atrium_api::app::bsky::graph::get_list::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 50.try_into().ok(),
+                    list: list_uri.to_string(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyListMembersLoaded {
+            list: extract_user_list(&response.data.list),
+            members: response
+                .data
+                .items
+                .iter()
+                .map(|item| ListMember {
+                    item_uri: item.uri.clone(),
+                    profile: extract_profile(&item.subject),
+                })
+                .collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_list_memberships(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get list memberships", did);
+
+        let mut memberships = Vec::new();
+        let mut cursor = None;
+        loop {
+            let response = self
+                .bsky_agent
+                .api
+                .app
+                .bsky
+                .graph
+                .get_lists_with_membership(
+                    atrium_api::app::bsky::graph::get_lists_with_membership::ParametersData {
+                        actor: AtIdentifier::Did(
+                            did.parse().map_err(|e| format!("Invalid DID: {}", e))?,
+                        ),
+                        cursor,
+                        limit: 50.try_into().ok(),
+                        purposes: None,
+                    }
+                    .into(),
+                )
+                .await?;
+            memberships.extend(response.data.lists_with_membership.iter().map(|membership| ListMembership {
+                list: extract_user_list(&membership.list),
+                item_uri: membership.list_item.as_ref().map(|item| RecordState::Created(item.uri.clone())),
+            }));
+            cursor = response.data.cursor;
+            if cursor.is_none() || response.data.lists_with_membership.is_empty() {
+                break;
+            }
+        }
+
+        Ok(RedskyUiMsg::NotifyListMembershipsLoaded {
+            did: did.clone(),
+            memberships,
+        })
+    }
+
+    async fn create_list(
+        &self,
+        name: &str,
+        description: &str,
+        purpose: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("creating list", name);
+        let session = self.bsky_agent.api.com.atproto.server.get_session().await?;
+        let response = self
+            .bsky_agent
+            .create_record(atrium_api::app::bsky::graph::list::RecordData {
+                avatar: None,
+                created_at: Datetime::now(),
+                description: (!description.is_empty()).then(|| description.to_string()),
+                description_facets: None,
+                labels: None,
+                name: name.to_string(),
+                purpose: purpose.to_string(),
+            })
+            .await?;
+        Ok(RedskyUiMsg::NotifyListCreated {
+            list: UserList {
+                uri: response.data.uri,
+                name: name.to_string(),
+                description: description.to_string(),
+                purpose: purpose.to_string(),
+                avatar_uri: String::new(),
+                item_count: 0,
+                creator_handle: session.data.handle.to_string(),
             },
         })
     }
 
-    async fn get_timeline_posts(
+    async fn update_list(
+        &self,
+        list_uri: &str,
+        name: &str,
+        description: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        use bsky_sdk::record::Record;
+        dbg!("updating list", list_uri);
+        let rkey = RecordKey::new(
+            list_uri
+                .rsplit('/')
+                .next()
+                .ok_or("Invalid list URI")?
+                .to_string(),
+        )
+        .map_err(|e| e.to_string())?;
+
+        let output =
+            atrium_api::app::bsky::graph::list::RecordData::get(&self.bsky_agent, rkey.clone())
+                .await?;
+        let mut record = atrium_api::app::bsky::graph::list::RecordData::try_from_unknown(
+            output.data.value,
+        )?;
+        record.name = name.to_string();
+        record.description = (!description.is_empty()).then(|| description.to_string());
+        record.description_facets = None;
+        record.put(&self.bsky_agent, rkey).await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn delete_list(
+        &self,
+        list_uri: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("deleting list", list_uri);
+        use atrium_api::com::atproto::repo::apply_writes::{DeleteData, InputData, InputWritesItem};
+
+        // The list items are records of their own, delete them first
+        let mut item_uris = Vec::new();
+        let mut cursor = None;
+        loop {
+            let response = self
+                .bsky_agent
+                .api
+                .app
+                .bsky
+                .graph
+                .get_list(
+                    atrium_api::app::bsky::graph::get_list::ParametersData {
+                        cursor,
+                        limit: 100.try_into().ok(),
+                        list: list_uri.to_string(),
+                    }
+                    .into(),
+                )
+                .await?;
+            item_uris.extend(response.data.items.iter().map(|item| item.uri.clone()));
+            cursor = response.data.cursor;
+            if cursor.is_none() || response.data.items.is_empty() {
+                break;
+            }
+        }
+
+        let session = self.bsky_agent.api.com.atproto.server.get_session().await?;
+        // applyWrites takes at most 200 writes
+        for chunk in item_uris.chunks(200) {
+            let writes = chunk
+                .iter()
+                .map(|uri| {
+                    let rkey = uri.rsplit('/').next().unwrap_or_default();
+                    Ok(InputWritesItem::Delete(Box::new(
+                        DeleteData {
+                            collection: "app.bsky.graph.listitem".parse()?,
+                            rkey: RecordKey::new(rkey.to_string())?,
+                        }
+                        .into(),
+                    )))
+                })
+                .collect::<Result<Vec<_>, Box<dyn std::error::Error + Send + Sync>>>()?;
+            self.bsky_agent
+                .api
+                .com
+                .atproto
+                .repo
+                .apply_writes(
+                    InputData {
+                        repo: AtIdentifier::Did(session.did.clone()),
+                        swap_commit: None,
+                        validate: None,
+                        writes,
+                    }
+                    .into(),
+                )
+                .await?;
+        }
+
+        self.bsky_agent.delete_record(list_uri).await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    /// Our profile record and its CID, accounts that never saved a profile have none.
+    async fn fetch_profile_record(
+        &self,
+    ) -> Result<(Option<Cid>, atrium_api::app::bsky::actor::profile::RecordData), Box<dyn std::error::Error + Send + Sync>>
+    {
+        use bsky_sdk::record::Record;
+        let rkey = RecordKey::new("self".to_string()).map_err(|e| e.to_string())?;
+        match atrium_api::app::bsky::actor::profile::RecordData::get(&self.bsky_agent, rkey).await {
+            Ok(output) => Ok((
+                output.data.cid,
+                atrium_api::app::bsky::actor::profile::RecordData::try_from_unknown(output.data.value)?,
+            )),
+            Err(err) if err.to_string().contains("RecordNotFound") => Ok((
+                None,
+                atrium_api::app::bsky::actor::profile::RecordData {
+                    avatar: None,
+                    banner: None,
+                    created_at: Some(Datetime::now()),
+                    description: None,
+                    display_name: None,
+                    joined_via_starter_pack: None,
+                    labels: None,
+                    pinned_post: None,
+                    pronouns: None,
+                    website: None,
+                },
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn get_profile_record(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get profile record");
+        let (cid, record) = self.fetch_profile_record().await?;
+        Ok(RedskyUiMsg::ShowProfileRecord {
+            edit: ProfileEdit {
+                cid,
+                display_name: record.display_name.unwrap_or_default(),
+                description: record.description.unwrap_or_default(),
+                avatar_path: None,
+                banner_path: None,
+            },
+        })
+    }
+
+    async fn upload_profile_image(
+        &self,
+        path: &str,
+        kind: ProfileImageKind,
+    ) -> Result<atrium_api::types::BlobRef, Box<dyn std::error::Error + Send + Sync>> {
+        let bytes = tokio::fs::read(path).await?;
+        let jpeg = tokio::task::spawn_blocking(move || crate::profile_image::prepare(&bytes, kind)).await??;
+        let output = self.bsky_agent.api.com.atproto.repo.upload_blob(jpeg).await?;
+        Ok(output.data.blob)
+    }
+
+    /// Writes the edited fields over the latest record, keeping the fields Redsky does not
+    /// edit. The write is swapped against the CID the edit started from.
+    async fn update_profile(
+        &self,
+        edit: &ProfileEdit,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        use atrium_api::types::TryIntoUnknown;
+        dbg!("update profile");
+        const CONFLICT: &str = "Your profile was changed in another client, reload it before saving";
+        let (cid, mut record) = self.fetch_profile_record().await?;
+        if cid != edit.cid {
+            return Err(CONFLICT.into());
+        }
+        record.display_name = (!edit.display_name.trim().is_empty()).then(|| edit.display_name.trim().to_string());
+        record.description = (!edit.description.trim().is_empty()).then(|| edit.description.trim().to_string());
+        if let Some(path) = &edit.avatar_path {
+            record.avatar = Some(self.upload_profile_image(path, ProfileImageKind::Avatar).await?);
+        }
+        if let Some(path) = &edit.banner_path {
+            record.banner = Some(self.upload_profile_image(path, ProfileImageKind::Banner).await?);
+        }
+
+        let session = self.bsky_agent.api.com.atproto.server.get_session().await?;
+        let result = self
+            .bsky_agent
+            .api
+            .com
+            .atproto
+            .repo
+            .put_record(
+                atrium_api::com::atproto::repo::put_record::InputData {
+                    collection: "app.bsky.actor.profile".parse()?,
+                    record: record.try_into_unknown()?,
+                    repo: AtIdentifier::Did(session.data.did.clone()),
+                    rkey: RecordKey::new("self".to_string()).map_err(|e| e.to_string())?,
+                    swap_commit: None,
+                    swap_record: edit.cid.clone(),
+                    validate: None,
+                }
+                .into(),
+            )
+            .await;
+        match result {
+            Err(err) if err.to_string().contains("InvalidSwap") => return Err(CONFLICT.into()),
+            other => other?,
+        };
+
+        match self.get_user_profile(&session.data.handle.to_string()).await? {
+            RedskyUiMsg::ShowUserProfile { profile } => Ok(RedskyUiMsg::ProfileUpdated { profile }),
+            other => Ok(other),
+        }
+    }
+
+    async fn add_list_member(
+        &self,
+        list_uri: &str,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("adding to list", list_uri, did);
+        let response = self
+            .bsky_agent
+            .create_record(atrium_api::app::bsky::graph::listitem::RecordData {
+                created_at: Datetime::now(),
+                list: list_uri.to_string(),
+                subject: did.parse().map_err(|e| format!("Invalid DID: {}", e))?,
+            })
+            .await?;
+        Ok(RedskyUiMsg::NotifyListMemberAdded {
+            list_uri: list_uri.to_string(),
+            did: did.clone(),
+            item_uri: response.data.uri,
+        })
+    }
+
+    async fn remove_list_member(
+        &self,
+        item_uri: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("removing from list", item_uri);
+        self.bsky_agent.delete_record(item_uri).await?;
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn get_saved_feeds(
+        &self,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get saved feeds");
+        let saved: Vec<SavedFeed> = self
+            .get_preferences()
+            .await?
+            .iter()
+            .filter_map(|pref| match pref {
+                Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved_feeds)) => Some(
+                    saved_feeds
+                        .items
+                        .iter()
+                        .map(|item| SavedFeed {
+                            id: item.id.clone(),
+                            kind: item.r#type.clone(),
+                            uri: item.value.clone(),
+                            pinned: item.pinned,
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        let feed_uris: Vec<String> = saved
+            .iter()
+            .filter(|feed| feed.kind == "feed")
+            .map(|feed| feed.uri.clone())
+            .collect();
+        let generators = if feed_uris.is_empty() {
+            vec![]
+        } else {
+            self.bsky_agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_feed_generators(
+                    atrium_api::app::bsky::feed::get_feed_generators::ParametersData {
+                        feeds: feed_uris,
+                    }
+                    .into(),
+                )
+                .await?
+                .data
+                .feeds
+                .iter()
+                .map(extract_feed_generator)
+                .collect()
+        };
+
+        Ok(RedskyUiMsg::NotifySavedFeedsLoaded { saved, generators })
+    }
+
+    async fn put_saved_feeds(
+        &self,
+        saved: &[SavedFeed],
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("put saved feeds");
+        let items = saved
+            .iter()
+            .map(|feed| {
+                atrium_api::app::bsky::actor::defs::SavedFeedData {
+                    id: feed.id.clone(),
+                    pinned: feed.pinned,
+                    r#type: feed.kind.clone(),
+                    value: feed.uri.clone(),
+                }
+                .into()
+            })
+            .collect();
+        self.update_preferences(|preferences| {
+            preferences.retain(|pref| {
+                !matches!(pref, Union::Refs(PreferencesItem::SavedFeedsPrefV2(_)))
+            });
+            preferences.push(Union::Refs(PreferencesItem::SavedFeedsPrefV2(Box::new(
+                atrium_api::app::bsky::actor::defs::SavedFeedsPrefV2Data { items }.into(),
+            ))));
+        })
+        .await?;
+        Ok(RedskyUiMsg::NotifySavedFeedsLoaded {
+            saved: saved.to_vec(),
+            generators: vec![],
+        })
+    }
+
+    async fn get_suggested_feeds(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get suggested feeds");
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_suggested_feeds(
+                atrium_api::app::bsky::feed::get_suggested_feeds::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 30.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyFeedGeneratorsLoaded {
+            kind: FeedDiscoveryKind::Suggested,
+            generators: response.data.feeds.iter().map(extract_feed_generator).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_popular_feeds(
+        &self,
+        query: &Option<String>,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("get popular feeds", query);
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .unspecced
+            .get_popular_feed_generators(
+                atrium_api::app::bsky::unspecced::get_popular_feed_generators::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: 30.try_into().ok(),
+                    query: query.clone(),
+                }
+                .into(),
+            )
+            .await?;
+
+        Ok(RedskyUiMsg::NotifyFeedGeneratorsLoaded {
+            kind: FeedDiscoveryKind::Popular,
+            generators: response.data.feeds.iter().map(extract_feed_generator).collect(),
+            cursor: response.data.cursor,
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn bookmark(
+        &self,
+        post_ref: &StrongRef,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        dbg!("bookmarking", &post_ref.uri);
+        self.bsky_agent
+            .api
+            .app
+            .bsky
+            .bookmark
+            .create_bookmark(
+                atrium_api::app::bsky::bookmark::create_bookmark::InputData {
+                    cid: post_ref.cid.clone(),
+                    uri: post_ref.uri.clone(),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyBookmarkActionSucceeded {
+            post_uri: post_ref.uri.clone(),
+            bookmarked: true,
+        })
+    }
+
+    async fn unbookmark(
         &self,
-        cursor: &Option<String>,
+        post_uri: &String,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
-        dbg!("get tl");
-
-        let response = self
-            .bsky_agent
+        dbg!("removing bookmark", post_uri);
+        self.bsky_agent
             .api
             .app
             .bsky
-            .feed
-            .get_timeline(
-                atrium_api::app::bsky::feed::get_timeline::ParametersData {
-                    algorithm: None,
-                    cursor: cursor.clone(),
-                    limit: 30.try_into().ok(),
+            .bookmark
+            .delete_bookmark(
+                atrium_api::app::bsky::bookmark::delete_bookmark::InputData {
+                    uri: post_uri.clone(),
                 }
                 .into(),
             )
             .await?;
-
-        Ok(RedskyUiMsg::RefreshTimelineMsg {
-            posts: response
-                .data
-                .feed
-                .iter()
-                .filter_map(|feed_element| extract_post(&feed_element.post))
-                .collect(),
-            cursor: response.data.cursor,
-            append: cursor.is_some(),
+        Ok(RedskyUiMsg::NotifyBookmarkActionSucceeded {
+            post_uri: post_uri.clone(),
+            bookmarked: false,
         })
     }
 
@@ -594,7 +1958,7 @@ impl BskyJob {
             .bookmark
             .get_bookmarks(
                 atrium_api::app::bsky::bookmark::get_bookmarks::ParametersData {
-                    cursor: None,
+                    cursor: cursor.clone(),
                     limit: 30.try_into().ok(),
                 }
                 .into(),
@@ -619,7 +1983,14 @@ impl BskyJob {
         pass: &String,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         dbg!("loggin in");
-        let _ = self.bsky_agent.login(login, pass).await?;
+        let session = self.bsky_agent.login(login, pass).await?;
+        // The cached timeline filters belonged to the previous account
+        *self.feed_view_pref.write().await = None;
+        self.open_post_db(&session.did).await;
+        // The login may be an email or a did, the UI identifies us by the session did
+        self.post_to_ui(RedskyUiMsg::NotifySessionLoaded {
+            did: session.did.to_string(),
+        });
         Ok(RedskyUiMsg::LogInSucceededMsg())
     }
 
@@ -821,13 +2192,18 @@ impl BskyJob {
             None
         };
 
+        // Mentions, links and tags become facets, mentioned handles get resolved to DIDs
+        let facets = bsky_sdk::rich_text::RichText::new_with_detect_facets(msg)
+            .await
+            .ok()
+            .and_then(|rich_text| rich_text.facets);
         let _ = self
             .bsky_agent
             .create_record(atrium_api::app::bsky::feed::post::RecordData {
                 created_at: Datetime::now(),
                 embed,
                 entities: None,
-                facets: None,
+                facets,
                 labels: None,
                 langs: None,
                 reply,
@@ -860,6 +2236,157 @@ impl BskyJob {
         Ok(RedskyUiMsg::NotifyUnreadCount { count })
     }
 
+    /// Raises a desktop notification for each notification newer than `since` with one of the
+    /// given reasons. When nothing has been announced yet, only the watermark is returned so the
+    /// backlog does not pop up at once.
+    async fn raise_desktop_notifications(
+        &self,
+        reasons: &[String],
+        since: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        const MAX_DESKTOP_NOTIFICATIONS: usize = 5;
+
+        let response = self
+            .bsky_agent
+            .api
+            .app
+            .bsky
+            .notification
+            .list_notifications(
+                atrium_api::app::bsky::notification::list_notifications::ParametersData {
+                    cursor: None,
+                    limit: Some(30.try_into().unwrap()),
+                    priority: None,
+                    reasons: None,
+                    seen_at: None,
+                }
+                .into(),
+            )
+            .await?;
+        let notifications = response.data.notifications;
+        let latest = notifications
+            .first()
+            .map(|notif| notif.indexed_at.as_str().to_string())
+            .or(since.clone());
+        let Some(since) = since else {
+            return Ok(RedskyUiMsg::NotifyDesktopNotificationsRaised { latest });
+        };
+        let mut fresh: Vec<_> = notifications
+            .iter()
+            .filter(|notif| reasons.contains(&notif.reason))
+            .filter(|notif| crate::desktop_notify::is_after(notif.indexed_at.as_str(), since))
+            .collect();
+        if fresh.is_empty() {
+            return Ok(RedskyUiMsg::NotifyDesktopNotificationsRaised { latest });
+        }
+        fresh.reverse(); // oldest first
+
+        let ui_tx = self.tx.clone();
+        let ctx = self.ctx.clone();
+        let notifier = self
+            .desktop_notifier
+            .get_or_init(|| async move {
+                let on_click = Box::new(move |target| {
+                    let _ = ui_tx.send(RedskyUiMsg::DesktopNotificationClicked { target });
+                    ctx.request_repaint();
+                });
+                DesktopNotifier::connect_session(on_click)
+                    .await
+                    .map_err(|err| println!("desktop notifications unavailable: {}", err))
+                    .ok()
+            })
+            .await;
+        let Some(notifier) = notifier else {
+            return Ok(RedskyUiMsg::NotifyDesktopNotificationsRaised { latest });
+        };
+
+        if fresh.len() > MAX_DESKTOP_NOTIFICATIONS {
+            notifier
+                .notify(DesktopNotification {
+                    summary: format!("{} new notifications", fresh.len()),
+                    body: String::new(),
+                    target: NotificationTarget::Notifications,
+                })
+                .await?;
+            return Ok(RedskyUiMsg::NotifyDesktopNotificationsRaised { latest });
+        }
+
+        // Likes and reposts open the post they are about, which needs its CID
+        let subject_uris: Vec<String> = fresh
+            .iter()
+            .filter_map(|notif| notif.reason_subject.clone())
+            .filter(|uri| uri.contains("/app.bsky.feed.post/"))
+            .collect();
+        let mut subjects: HashMap<String, Post> = HashMap::new();
+        if !subject_uris.is_empty() {
+            let posts = self
+                .bsky_agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_posts(
+                    atrium_api::app::bsky::feed::get_posts::ParametersData { uris: subject_uris }.into(),
+                )
+                .await?;
+            for post in posts.data.posts.iter().filter_map(extract_post) {
+                subjects.insert(post.uri.clone(), post);
+            }
+        }
+
+        for notif in fresh {
+            let handle = notif.author.handle.to_string();
+            let record_text = post::RecordData::try_from_unknown(notif.record.clone())
+                .map(|record| record.text)
+                .unwrap_or_default();
+            let subject = notif
+                .reason_subject
+                .as_ref()
+                .and_then(|uri| subjects.get(uri));
+            let (body, target) = match (notif.reason.as_str(), subject) {
+                ("mention" | "reply" | "quote", _) => (
+                    record_text,
+                    NotificationTarget::Thread(StrongRef {
+                        uri: notif.uri.clone(),
+                        cid: notif.cid.clone(),
+                    }),
+                ),
+                ("follow", _) | (_, None) => (String::new(), NotificationTarget::Profile(handle.clone())),
+                (_, Some(post)) => (
+                    post.content.clone(),
+                    NotificationTarget::Thread(StrongRef {
+                        uri: post.uri.clone(),
+                        cid: post.cid.clone(),
+                    }),
+                ),
+            };
+            notifier
+                .notify(DesktopNotification {
+                    summary: format!("@{} {}", handle, crate::app::notification_action_text(&notif.reason)),
+                    body,
+                    target,
+                })
+                .await?;
+        }
+        Ok(RedskyUiMsg::NotifyDesktopNotificationsRaised { latest })
+    }
+
+    async fn update_seen(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        self.bsky_agent
+            .api
+            .app
+            .bsky
+            .notification
+            .update_seen(
+                atrium_api::app::bsky::notification::update_seen::InputData {
+                    seen_at: Datetime::now(),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyUnreadCount { count: 0 })
+    }
+
     async fn get_notifications(
         &self,
         cursor: &Option<String>,
@@ -872,7 +2399,7 @@ impl BskyJob {
             .notification
             .list_notifications(
                 atrium_api::app::bsky::notification::list_notifications::ParametersData {
-                    cursor: None,
+                    cursor: cursor.clone(),
                     limit: Some(30.try_into().unwrap()),
                     priority: None,
                     reasons: None,
@@ -882,23 +2409,417 @@ impl BskyJob {
             )
             .await?;
 
-        let notifications = response
+        let notifications: Vec<crate::app::AppNotification> = response
             .data
             .notifications
             .into_iter()
             .map(|notif| crate::app::AppNotification {
                 uri: notif.uri.clone(),
+                cid: notif.cid.clone(),
                 author: notif.author.handle.to_string(),
+                author_did: notif.author.did.to_string(),
                 author_avatar: notif.author.avatar.clone().unwrap_or_default(),
                 reason: notif.reason.clone(),
+                reason_subject: notif.reason_subject.clone(),
+                record_text: post::RecordData::try_from_unknown(notif.record.clone())
+                    .map(|record| record.text)
+                    .unwrap_or_default(),
+                date: notif.indexed_at.as_str().to_string(),
                 is_read: notif.is_read,
             })
             .collect();
 
+        // Hydrate the posts that were liked or reposted, for the inline previews
+        let mut subject_uris: Vec<String> = notifications
+            .iter()
+            .filter_map(|notif| notif.reason_subject.clone())
+            .filter(|uri| uri.contains("/app.bsky.feed.post/"))
+            .collect();
+        subject_uris.sort();
+        subject_uris.dedup();
+        let mut subject_posts = Vec::new();
+        for uris in subject_uris.chunks(25) {
+            let posts = self
+                .bsky_agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_posts(
+                    atrium_api::app::bsky::feed::get_posts::ParametersData {
+                        uris: uris.to_vec(),
+                    }
+                    .into(),
+                )
+                .await?;
+            subject_posts.extend(posts.data.posts.iter().filter_map(extract_post));
+        }
+
         Ok(RedskyUiMsg::RefreshNotificationsMsg {
             notifications,
+            subject_posts,
             cursor: response.data.cursor,
             append: cursor.is_some(),
         })
     }
+
+
+    async fn list_convos(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .list_convos(
+                atrium_api::chat::bsky::convo::list_convos::ParametersData {
+                    cursor: cursor.clone(),
+                    limit: Some(30.try_into().unwrap()),
+                    read_state: None,
+                    status: None,
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::RefreshConvosMsg {
+            convos: response.data.convos.iter().map(|convo| extract_conversation(convo)).collect(),
+            cursor: response.data.cursor.clone(),
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn get_messages(
+        &self,
+        convo_id: &str,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        use atrium_api::chat::bsky::convo::get_messages::OutputMessagesItem;
+
+        let response = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .get_messages(
+                atrium_api::chat::bsky::convo::get_messages::ParametersData {
+                    convo_id: convo_id.to_string(),
+                    cursor: cursor.clone(),
+                    limit: Some(50.try_into().unwrap()),
+                }
+                .into(),
+            )
+            .await?;
+        // Messages come newest first
+        let messages = response
+            .data
+            .messages
+            .iter()
+            .rev()
+            .filter_map(|message| match message {
+                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsMessageView(message)) => {
+                    Some(extract_chat_message(message))
+                }
+                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsDeletedMessageView(message)) => {
+                    Some(extract_deleted_chat_message(message))
+                }
+                Union::Unknown(_) => None,
+            })
+            .collect();
+        Ok(RedskyUiMsg::RefreshMessagesMsg {
+            convo_id: convo_id.to_string(),
+            messages,
+            cursor: response.data.cursor.clone(),
+            append: cursor.is_some(),
+        })
+    }
+
+    async fn send_message(
+        &self,
+        convo_id: &str,
+        text: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let message = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .send_message(
+                atrium_api::chat::bsky::convo::send_message::InputData {
+                    convo_id: convo_id.to_string(),
+                    message: atrium_api::chat::bsky::convo::defs::MessageInputData {
+                        embed: None,
+                        facets: None,
+                        text: text.to_string(),
+                    }
+                    .into(),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyMessageSent {
+            convo_id: convo_id.to_string(),
+            message: extract_chat_message(&message),
+        })
+    }
+
+    async fn get_convo_for_members(
+        &self,
+        did: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .get_convo_for_members(
+                atrium_api::chat::bsky::convo::get_convo_for_members::ParametersData {
+                    members: vec![did.parse()?],
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyConvoOpened {
+            convo: extract_conversation(&response.data.convo),
+        })
+    }
+
+    async fn mute_convo(
+        &self,
+        convo_id: &str,
+        mute: bool,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let chat = self.chat_api()?;
+        let convo = if mute {
+            chat.chat
+                .bsky
+                .convo
+                .mute_convo(
+                    atrium_api::chat::bsky::convo::mute_convo::InputData {
+                        convo_id: convo_id.to_string(),
+                    }
+                    .into(),
+                )
+                .await?
+                .data
+                .convo
+        } else {
+            chat.chat
+                .bsky
+                .convo
+                .unmute_convo(
+                    atrium_api::chat::bsky::convo::unmute_convo::InputData {
+                        convo_id: convo_id.to_string(),
+                    }
+                    .into(),
+                )
+                .await?
+                .data
+                .convo
+        };
+        Ok(RedskyUiMsg::NotifyConvoUpdated {
+            convo: extract_conversation(&convo),
+        })
+    }
+
+    async fn leave_convo(&self, convo_id: &str) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .leave_convo(
+                atrium_api::chat::bsky::convo::leave_convo::InputData {
+                    convo_id: convo_id.to_string(),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyConvoLeft {
+            convo_id: response.data.convo_id.clone(),
+        })
+    }
+
+    async fn update_convo_read(
+        &self,
+        convo_id: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .update_read(
+                atrium_api::chat::bsky::convo::update_read::InputData {
+                    convo_id: convo_id.to_string(),
+                    message_id: None,
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyConvoUpdated {
+            convo: extract_conversation(&response.data.convo),
+        })
+    }
+
+    async fn get_chat_log(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .chat_api()?
+            .chat
+            .bsky
+            .convo
+            .get_log(
+                atrium_api::chat::bsky::convo::get_log::ParametersData {
+                    cursor: cursor.clone(),
+                }
+                .into(),
+            )
+            .await?;
+        Ok(RedskyUiMsg::NotifyChatLog {
+            events: response.data.logs.iter().filter_map(extract_chat_log_event).collect(),
+            cursor: response.data.cursor.clone(),
+        })
+    }
+
+
+    /// Follows Jetstream for the accounts we follow, until the job is cancelled. New posts are
+    /// hydrated through the AppView, which needs a moment to index them.
+    async fn run_realtime(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        const INDEXING_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+        let own_did = self.bsky_agent.api.com.atproto.server.get_session().await?.data.did;
+        let mut dids = vec![own_did.to_string()];
+        let mut cursor = None;
+        loop {
+            let response = self
+                .bsky_agent
+                .api
+                .app
+                .bsky
+                .graph
+                .get_follows(
+                    atrium_api::app::bsky::graph::get_follows::ParametersData {
+                        actor: AtIdentifier::Did(own_did.clone()),
+                        cursor,
+                        limit: 100.try_into().ok(),
+                    }
+                    .into(),
+                )
+                .await?;
+            dids.extend(response.data.follows.iter().map(|follow| follow.did.to_string()));
+            cursor = response.data.cursor.clone();
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        let own_did = own_did.to_string();
+        // Live posts only come from these accounts, so a reply is to someone we follow when its parent is by one of them
+        let followed: std::collections::HashSet<String> = dids.iter().cloned().collect();
+        let (events_tx, mut events_rx) = tokio::sync::mpsc::unbounded_channel();
+        let subscription = crate::realtime::subscribe(crate::realtime::JETSTREAM_URL, &dids, events_tx);
+        let mut counted = crate::realtime::CountedRecords::default();
+        let handle_events = async {
+            while let Some(event) = events_rx.recv().await {
+                let is_like = matches!(event, crate::realtime::RealtimeEvent::Like { .. });
+                match event {
+                    crate::realtime::RealtimeEvent::Post {
+                        did,
+                        uri,
+                        reply_parent,
+                        mentions,
+                    } => {
+                        let about_me = reply_parent.as_ref().is_some_and(|parent| parent.contains(&own_did))
+                            || mentions.contains(&own_did);
+                        if about_me && did != own_did {
+                            self.post_to_ui(RedskyUiMsg::NotifyRealtimeInteraction());
+                        }
+                        let reply_to_followed = reply_parent.map(|parent| {
+                            uri_author_did(&parent).is_some_and(|parent_did| followed.contains(parent_did))
+                        });
+                        // Filtered like the timeline pages, with the filters of the moment
+                        let cached_pref = *self.feed_view_pref.read().await;
+                        let pref = match cached_pref {
+                            Some(pref) => pref,
+                            None => self.load_feed_view_pref().await.unwrap_or_default(),
+                        };
+                        let tx = self.tx.clone();
+                        let ctx = self.ctx.clone();
+                        let bsky_agent = self.bsky_agent.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(INDEXING_DELAY).await;
+                            let posts = bsky_agent
+                                .api
+                                .app
+                                .bsky
+                                .feed
+                                .get_posts(atrium_api::app::bsky::feed::get_posts::ParametersData { uris: vec![uri] }.into())
+                                .await;
+                            let Some(post_view) = posts.ok().and_then(|posts| posts.data.posts.into_iter().next()) else {
+                                return;
+                            };
+                            if pref.hides(false, reply_to_followed, is_quote(&post_view)) {
+                                let _ = tx.send(RedskyUiMsg::NotifyRealtimePostHidden { uri: post_view.uri.clone() });
+                            } else if let Some(post) = extract_post(&post_view) {
+                                let _ = tx.send(RedskyUiMsg::NotifyRealtimePost { post });
+                            }
+                            ctx.request_repaint();
+                        });
+                    }
+                    crate::realtime::RealtimeEvent::Like { did, rkey, subject_uri }
+                    | crate::realtime::RealtimeEvent::Repost { did, rkey, subject_uri } => {
+                        // Our own likes and reposts were already counted when we made them
+                        if did == own_did {
+                            continue;
+                        }
+                        let key = crate::realtime::CountedRecords::key(&did, is_like, &rkey);
+                        if !counted.insert(key, subject_uri.clone()) {
+                            continue; // replayed after a reconnect
+                        }
+                        if subject_uri.contains(&own_did) {
+                            self.post_to_ui(RedskyUiMsg::NotifyRealtimeInteraction());
+                        }
+                        self.post_to_ui(RedskyUiMsg::NotifyRealtimeCount {
+                            subject_uri,
+                            is_like,
+                            delta: 1,
+                        });
+                    }
+                    crate::realtime::RealtimeEvent::Undo { did, rkey, is_like } => {
+                        // Only records counted while live are taken back
+                        let key = crate::realtime::CountedRecords::key(&did, is_like, &rkey);
+                        if let Some(subject_uri) = counted.remove(&key) {
+                            self.post_to_ui(RedskyUiMsg::NotifyRealtimeCount {
+                                subject_uri,
+                                is_like,
+                                delta: -1,
+                            });
+                        }
+                    }
+                }
+            }
+        };
+        tokio::select! {
+            _ = subscription => {},
+            _ = handle_events => {},
+        }
+        Err("live updates stopped".into())
+    }
+
+    /// Chat endpoints live on the chat service, reached through the PDS with an atproto-proxy header.
+    fn chat_api(
+        &self,
+    ) -> Result<
+        atrium_api::client::Service<impl atrium_api::xrpc::XrpcClient>,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
+        let did = atrium_api::agent::bluesky::BSKY_CHAT_DID.parse()?;
+        Ok(self
+            .bsky_agent
+            .api_with_proxy(did, atrium_api::agent::bluesky::AtprotoServiceType::BskyChat))
+    }
 }
@@ -5,6 +5,7 @@ impl BskyJob {
         _cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         let likers = vec![UserProfile {
+            did: "did:plc:testliker".to_string(),
             handle: "testliker.bsky.social".to_string(),
             display_name: "Test Liker".to_string(),
             bio: "I like tests".to_string(),
@@ -12,6 +13,13 @@ impl BskyJob {
             follower_count: 5,
             follow_count: 5,
             post_count: 5,
+            viewer_following: None,
+            viewer_followed_by: false,
+            viewer_blocking: None,
+            viewer_muted: false,
+            banner_uri: "".to_string(),
+            labels: vec![],
+            pinned_post: None,
         }];
         Ok(RedskyUiMsg::NotifyLikesLoaded {
             post_uri: strong_ref.clone(),
@@ -27,6 +35,7 @@ impl BskyJob {
         _cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         let reposters = vec![UserProfile {
+            did: "did:plc:testreposter".to_string(),
             handle: "testreposter.bsky.social".to_string(),
             display_name: "Test Reposter".to_string(),
             bio: "I repost tests".to_string(),
@@ -34,6 +43,13 @@ impl BskyJob {
             follower_count: 5,
             follow_count: 5,
             post_count: 5,
+            viewer_following: None,
+            viewer_followed_by: false,
+            viewer_blocking: None,
+            viewer_muted: false,
+            banner_uri: "".to_string(),
+            labels: vec![],
+            pinned_post: None,
         }];
         Ok(RedskyUiMsg::NotifyRepostersLoaded {
             post_uri: strong_ref.clone(),
@@ -79,6 +95,141 @@ impl BskyJob {
         Ok(RedskyUiMsg::ActionSucceeded())
     }
 
+    async fn follow(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyFollowActionSucceeded {
+            did: did.clone(),
+            follow_uri: "mock-follow-uri".to_string(),
+        })
+    }
+
+    async fn unfollow(
+        &self,
+        _did: String,
+        _follow_record_uri: String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn block(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyBlockActionSucceeded {
+            did: did.clone(),
+            block_uri: "mock-block-uri".to_string(),
+        })
+    }
+
+    async fn unblock(
+        &self,
+        _did: String,
+        _block_record_uri: String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn mute(
+        &self,
+        _did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn unmute(
+        &self,
+        _did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn get_blocks(
+        &self,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyBlocksLoaded {
+            users: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_mutes(
+        &self,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyMutesLoaded {
+            users: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_muted_words(
+        &self,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyMutedWordsLoaded { words: vec![] })
+    }
+
+    async fn put_muted_words(
+        &self,
+        words: &[MutedWord],
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyMutedWordsLoaded {
+            words: words.to_vec(),
+        })
+    }
+
+    async fn get_content_prefs(
+        &self,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyContentPrefsLoaded {
+            adult_content_enabled: false,
+            label_visibility: std::collections::BTreeMap::new(),
+        })
+    }
+
+    async fn put_content_prefs(
+        &self,
+        adult_content_enabled: bool,
+        label_visibility: &std::collections::BTreeMap<String, LabelVisibility>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyContentPrefsLoaded {
+            adult_content_enabled,
+            label_visibility: label_visibility.clone(),
+        })
+    }
+
+    async fn get_feed_view_pref(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyFeedViewPrefLoaded {
+            pref: FeedViewPref::default(),
+        })
+    }
+
+    async fn put_feed_view_pref(
+        &self,
+        pref: FeedViewPref,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyFeedViewPrefLoaded { pref })
+    }
+
+    async fn resolve_link(&self, target: &AtUriTarget) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(match target {
+            AtUriTarget::Profile(actor) => RedskyUiMsg::OpenUserView {
+                username: actor.clone(),
+            },
+            AtUriTarget::Post(uri) => RedskyUiMsg::PrepareThreadView {
+                thread_ref: StrongRef {
+                    uri: uri.clone(),
+                    cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
+                },
+            },
+            AtUriTarget::Feed(uri) | AtUriTarget::List(uri) => RedskyUiMsg::OpenFeedView { feed_uri: uri.clone() },
+        })
+    }
+
     async fn get_post_thread(
         &self,
         strong_ref: &StrongRef,
@@ -88,6 +239,7 @@ impl BskyJob {
             cid: strong_ref.cid.clone(),
             content: "Mock post thread root content".to_string(),
             author: "mockauthor.bsky.social".to_string(),
+            author_did: "did:plc:mockauthor".to_string(),
             display_name: "Mock Author".to_string(),
             avatar_img: "".to_string(),
             date: "2024-01-01T00:00:00Z".to_string(),
@@ -98,7 +250,12 @@ impl BskyJob {
             is_reply: false,
             viewer_like: None,
             viewer_repost: None,
+            bookmarked: false,
+            author_muted: false,
+            author_blocked: false,
             thread_root: None,
+            tags: vec![],
+            labels: vec![],
             raw_json: "{}".to_string(),
         };
         let reply = Post {
@@ -106,6 +263,7 @@ impl BskyJob {
             cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
             content: "Mock post thread reply content".to_string(),
             author: "mockauthor2.bsky.social".to_string(),
+            author_did: "did:plc:mockauthor2".to_string(),
             display_name: "Mock Author 2".to_string(),
             avatar_img: "".to_string(),
             date: "2024-01-01T00:01:00Z".to_string(),
@@ -116,7 +274,12 @@ impl BskyJob {
             is_reply: true,
             viewer_like: None,
             viewer_repost: None,
+            bookmarked: false,
+            author_muted: false,
+            author_blocked: false,
             thread_root: Some(strong_ref.clone()),
+            tags: vec![],
+            labels: vec![],
             raw_json: "{}".to_string(),
         };
         Ok(RedskyUiMsg::NotifyPostAndRepliesLoaded {
@@ -127,21 +290,54 @@ impl BskyJob {
 
     async fn search_posts(
         &self,
-        _query: &String,
+        _search: &PostSearch,
         _cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         Ok(RedskyUiMsg::ShowSearchPostsResults {
             posts: vec![],
             cursor: None,
             append: false,
+            hits_total: Some(0),
+        })
+    }
+
+    async fn get_saved_search(
+        &self,
+        query: &str,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::RefreshSavedSearchMsg {
+            query: query.to_string(),
+            posts: vec![],
+            cursor: None,
+            append: false,
         })
     }
 
     async fn search_actors(
         &self,
-        _query: &String,
+        query: &String,
+        _cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
-        Ok(RedskyUiMsg::ShowSearchResults { results: vec![] })
+        Ok(RedskyUiMsg::ShowSearchResults {
+            query: query.clone(),
+            results: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn search_actors_typeahead(
+        &self,
+        target: &str,
+        _query: &str,
+        seq: u64,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ShowActorSuggestions {
+            target: target.to_string(),
+            seq,
+            actors: vec![],
+        })
     }
 
     async fn get_user_likes(
@@ -160,10 +356,12 @@ impl BskyJob {
     async fn get_user_posts(
         &self,
         username: &String,
+        view: UserViewState,
         _cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         Ok(RedskyUiMsg::ShowUserPostsMsg {
             username: username.clone(),
+            view,
             posts: vec![],
             cursor: None,
             append: false,
@@ -176,6 +374,7 @@ impl BskyJob {
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         Ok(RedskyUiMsg::ShowUserProfile {
             profile: UserProfile {
+                did: "did:plc:mockuser".to_string(),
                 handle: username.clone(),
                 display_name: "Mock User".to_string(),
                 bio: "Mock profile bio".to_string(),
@@ -183,10 +382,59 @@ impl BskyJob {
                 follower_count: 100,
                 follow_count: 50,
                 post_count: 200,
+                viewer_following: None,
+                viewer_followed_by: true,
+                viewer_blocking: None,
+                viewer_muted: false,
+                banner_uri: "".to_string(),
+                labels: vec!["!no-unauthenticated".to_string()],
+                pinned_post: None,
             },
         })
     }
 
+    async fn get_followers(
+        &self,
+        username: &String,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyUserListLoaded {
+            username: username.clone(),
+            kind: UserListKind::Followers,
+            users: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_follows(
+        &self,
+        username: &String,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyUserListLoaded {
+            username: username.clone(),
+            kind: UserListKind::Follows,
+            users: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_known_followers(
+        &self,
+        username: &String,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyUserListLoaded {
+            username: username.clone(),
+            kind: UserListKind::KnownFollowers,
+            users: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
     async fn get_timeline_posts(
         &self,
         _cursor: &Option<String>,
@@ -195,6 +443,238 @@ impl BskyJob {
             posts: vec![],
             cursor: None,
             append: false,
+            hidden: vec![],
+        })
+    }
+
+    async fn get_timeline_gap(&self, cursor: &str) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::FillTimelineGapMsg {
+            gap_cursor: cursor.to_string(),
+            posts: vec![],
+            cursor: None,
+            hidden: vec![],
+        })
+    }
+
+    async fn get_feed(
+        &self,
+        feed_uri: &str,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::RefreshCustomFeedMsg {
+            feed_uri: feed_uri.to_string(),
+            posts: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_list_feed(
+        &self,
+        list_uri: &str,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::RefreshCustomFeedMsg {
+            feed_uri: list_uri.to_string(),
+            posts: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    fn mock_list() -> UserList {
+        UserList {
+            uri: "at://did:plc:mockuser/app.bsky.graph.list/mock".to_string(),
+            name: "Mock List".to_string(),
+            description: "A list of mock users".to_string(),
+            purpose: "app.bsky.graph.defs#curatelist".to_string(),
+            avatar_uri: "".to_string(),
+            item_count: 0,
+            creator_handle: "mockuser.bsky.social".to_string(),
+        }
+    }
+
+    async fn get_lists(
+        &self,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyListsLoaded {
+            lists: vec![Self::mock_list()],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_list(
+        &self,
+        _list_uri: &str,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyListMembersLoaded {
+            list: Self::mock_list(),
+            members: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_list_memberships(
+        &self,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyListMembershipsLoaded {
+            did: did.clone(),
+            memberships: vec![ListMembership {
+                list: Self::mock_list(),
+                item_uri: None,
+            }],
+        })
+    }
+
+    async fn create_list(
+        &self,
+        name: &str,
+        description: &str,
+        purpose: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyListCreated {
+            list: UserList {
+                name: name.to_string(),
+                description: description.to_string(),
+                purpose: purpose.to_string(),
+                ..Self::mock_list()
+            },
+        })
+    }
+
+    async fn update_list(
+        &self,
+        _list_uri: &str,
+        _name: &str,
+        _description: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn delete_list(
+        &self,
+        _list_uri: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn get_profile_record(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ShowProfileRecord {
+            edit: ProfileEdit {
+                display_name: "Mock User".to_string(),
+                description: "Mock profile bio".to_string(),
+                ..Default::default()
+            },
+        })
+    }
+
+    async fn update_profile(
+        &self,
+        edit: &ProfileEdit,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ProfileUpdated {
+            profile: UserProfile {
+                did: "did:plc:mockuser".to_string(),
+                handle: "mock.bsky.social".to_string(),
+                display_name: edit.display_name.clone(),
+                bio: edit.description.clone(),
+                ..Default::default()
+            },
+        })
+    }
+
+    async fn add_list_member(
+        &self,
+        list_uri: &str,
+        did: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyListMemberAdded {
+            list_uri: list_uri.to_string(),
+            did: did.clone(),
+            item_uri: "at://did:plc:mockuser/app.bsky.graph.listitem/mock".to_string(),
+        })
+    }
+
+    async fn remove_list_member(
+        &self,
+        _item_uri: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::ActionSucceeded())
+    }
+
+    async fn get_saved_feeds(
+        &self,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifySavedFeedsLoaded {
+            saved: vec![],
+            generators: vec![],
+        })
+    }
+
+    async fn put_saved_feeds(
+        &self,
+        saved: &[SavedFeed],
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifySavedFeedsLoaded {
+            saved: saved.to_vec(),
+            generators: vec![],
+        })
+    }
+
+    async fn get_suggested_feeds(
+        &self,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyFeedGeneratorsLoaded {
+            kind: FeedDiscoveryKind::Suggested,
+            generators: vec![FeedGenerator {
+                uri: "at://did:plc:mockuser/app.bsky.feed.generator/mock".to_string(),
+                display_name: "Mock Feed".to_string(),
+                description: "A feed of mock posts".to_string(),
+                avatar_uri: "".to_string(),
+                creator_handle: "mockuser.bsky.social".to_string(),
+                like_count: 42,
+            }],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_popular_feeds(
+        &self,
+        _query: &Option<String>,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyFeedGeneratorsLoaded {
+            kind: FeedDiscoveryKind::Popular,
+            generators: vec![],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn bookmark(
+        &self,
+        post_ref: &StrongRef,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyBookmarkActionSucceeded {
+            post_uri: post_ref.uri.clone(),
+            bookmarked: true,
+        })
+    }
+
+    async fn unbookmark(
+        &self,
+        post_uri: &String,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyBookmarkActionSucceeded {
+            post_uri: post_uri.clone(),
+            bookmarked: false,
         })
     }
 
@@ -211,6 +691,10 @@ impl BskyJob {
         _login: &String,
         _pass: &String,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        self.open_post_db("did:plc:mockuser").await;
+        self.post_to_ui(RedskyUiMsg::NotifySessionLoaded {
+            did: "did:plc:mockuser".to_string(),
+        });
         Ok(RedskyUiMsg::LogInSucceededMsg())
     }
 
@@ -229,12 +713,25 @@ impl BskyJob {
         Ok(RedskyUiMsg::NotifyUnreadCount { count: 3 })
     }
 
+    async fn raise_desktop_notifications(
+        &self,
+        _reasons: &[String],
+        since: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyDesktopNotificationsRaised { latest: since.clone() })
+    }
+
+    async fn update_seen(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyUnreadCount { count: 0 })
+    }
+
     async fn get_notifications(
         &self,
         _cursor: &Option<String>,
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         Ok(RedskyUiMsg::RefreshNotificationsMsg {
             notifications: vec![],
+            subject_posts: vec![],
             cursor: None,
             append: false,
         })
@@ -270,4 +767,123 @@ impl BskyJob {
     ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
         Ok(RedskyUiMsg::ActionSucceeded())
     }
+
+    fn mock_convo() -> Conversation {
+        Conversation {
+            id: "mockconvo".to_string(),
+            members: vec![
+                ConvoMember {
+                    did: "did:plc:mockuser".to_string(),
+                    handle: "mockuser.bsky.social".to_string(),
+                    display_name: "Mock User".to_string(),
+                    avatar_uri: "".to_string(),
+                },
+                ConvoMember {
+                    did: "did:plc:anotheruser".to_string(),
+                    handle: "anotheruser.bsky.social".to_string(),
+                    display_name: "Another User".to_string(),
+                    avatar_uri: "".to_string(),
+                },
+            ],
+            muted: false,
+            unread_count: 1,
+            last_message: Some(Self::mock_message("Hey, how are you?")),
+            rev: "mockrev".to_string(),
+        }
+    }
+
+    fn mock_message(text: &str) -> ChatMessage {
+        ChatMessage {
+            id: format!("msg-{}", text.len()),
+            sender_did: "did:plc:anotheruser".to_string(),
+            text: text.to_string(),
+            sent_at: "2024-01-01T00:00:00Z".to_string(),
+            deleted: false,
+        }
+    }
+
+    async fn list_convos(
+        &self,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::RefreshConvosMsg {
+            convos: vec![Self::mock_convo()],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn get_messages(
+        &self,
+        convo_id: &str,
+        _cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::RefreshMessagesMsg {
+            convo_id: convo_id.to_string(),
+            messages: vec![Self::mock_message("Hey, how are you?")],
+            cursor: None,
+            append: false,
+        })
+    }
+
+    async fn send_message(
+        &self,
+        convo_id: &str,
+        text: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let mut message = Self::mock_message(text);
+        message.sender_did = "did:plc:mockuser".to_string();
+        Ok(RedskyUiMsg::NotifyMessageSent {
+            convo_id: convo_id.to_string(),
+            message,
+        })
+    }
+
+    async fn get_convo_for_members(
+        &self,
+        _did: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyConvoOpened {
+            convo: Self::mock_convo(),
+        })
+    }
+
+    async fn mute_convo(
+        &self,
+        _convo_id: &str,
+        mute: bool,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let mut convo = Self::mock_convo();
+        convo.muted = mute;
+        Ok(RedskyUiMsg::NotifyConvoUpdated { convo })
+    }
+
+    async fn leave_convo(&self, convo_id: &str) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyConvoLeft {
+            convo_id: convo_id.to_string(),
+        })
+    }
+
+    async fn update_convo_read(
+        &self,
+        _convo_id: &str,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let mut convo = Self::mock_convo();
+        convo.unread_count = 0;
+        Ok(RedskyUiMsg::NotifyConvoUpdated { convo })
+    }
+
+    async fn get_chat_log(
+        &self,
+        cursor: &Option<String>,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(RedskyUiMsg::NotifyChatLog {
+            events: vec![],
+            cursor: cursor.clone().or(Some("0".to_string())),
+        })
+    }
+
+    async fn run_realtime(&self) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        Err("live updates are not available with the mock API".into())
+    }
 }
@@ -1,21 +1,73 @@
+use crate::app::AtUriTarget;
 use crate::app::BskyActorMsg;
+use crate::app::ChatLogEvent;
+use crate::app::ChatMessage;
+use crate::app::ConvoMember;
+use crate::app::Conversation;
+use crate::app::FeedDiscoveryKind;
+use crate::app::FeedGenerator;
+use crate::app::FeedViewPref;
+use crate::app::HistoryFilter;
+#[cfg(not(feature = "mock-api"))]
+use crate::app::ListMember;
+use crate::app::ListMembership;
+use crate::app::LabelVisibility;
+use crate::app::MutedWord;
+use crate::app::MutedWordTargets;
 #[cfg(not(feature = "mock-api"))]
 use crate::app::DownloadStatus;
 use crate::app::Post;
+use crate::app::PostSearch;
+#[cfg(not(feature = "mock-api"))]
+use crate::app::PostSearchSort;
 use crate::app::PostImage;
+use crate::app::ProfileEdit;
+use crate::app::RecordState;
 use crate::app::RedskyUiMsg;
+use crate::app::SavedFeed;
 use crate::app::StrongRef;
+use crate::app::UserList;
+use crate::app::UserListKind;
+use crate::app::UserViewState;
 use crate::app::UserProfile;
+#[cfg(not(feature = "mock-api"))]
+use crate::app::NotificationTarget;
+#[cfg(not(feature = "mock-api"))]
+use crate::desktop_notify::DesktopNotification;
+use crate::desktop_notify::DesktopNotifier;
+use crate::post_db::PostDb;
+#[cfg(not(feature = "mock-api"))]
+use crate::profile_image::ProfileImageKind;
+use atrium_api::app::bsky::actor::defs::MutedWordData;
+use atrium_api::app::bsky::actor::defs::ProfileViewData;
 use atrium_api::app::bsky::bookmark::defs::BookmarkViewData;
 use atrium_api::app::bsky::bookmark::defs::BookmarkViewItemRefs;
 use atrium_api::app::bsky::embed::record::ViewRecordRefs;
+use atrium_api::app::bsky::feed::defs::GeneratorViewData;
+use atrium_api::chat::bsky::convo::defs::ConvoViewData;
+use atrium_api::chat::bsky::convo::defs::ConvoViewLastMessageRefs;
+use atrium_api::chat::bsky::convo::defs::DeletedMessageViewData;
+use atrium_api::chat::bsky::convo::defs::LogCreateMessageMessageRefs;
+use atrium_api::chat::bsky::convo::defs::LogDeleteMessageMessageRefs;
+use atrium_api::chat::bsky::convo::defs::MessageViewData;
+use atrium_api::chat::bsky::convo::get_log::OutputLogsItem as LogsItem;
 use atrium_api::app::bsky::feed::defs::PostViewData;
 use atrium_api::app::bsky::feed::defs::PostViewEmbedRefs;
 #[cfg(not(feature = "mock-api"))]
+use atrium_api::app::bsky::feed::defs::FeedViewPostReasonRefs;
+#[cfg(not(feature = "mock-api"))]
+use atrium_api::app::bsky::feed::defs::ReplyRefParentRefs;
+#[cfg(not(feature = "mock-api"))]
+use atrium_api::app::bsky::actor::defs::PreferencesItem;
+#[cfg(not(feature = "mock-api"))]
 use atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem;
 #[cfg(not(feature = "mock-api"))]
 use atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs;
 use atrium_api::app::bsky::feed::post;
+use atrium_api::app::bsky::graph::defs::ListViewData;
+use atrium_api::app::bsky::feed::post::RecordLabelsRefs;
+use atrium_api::app::bsky::richtext::facet::MainFeaturesItem;
+use atrium_api::com::atproto::label::defs::Label;
 #[cfg(not(feature = "mock-api"))]
 use atrium_api::types::string::{AtIdentifier, Datetime, RecordKey};
 use atrium_api::types::string::Cid;
@@ -24,6 +76,7 @@ use atrium_api::types::TryFromUnknown;
 use atrium_api::types::Union;
 use bsky_sdk::BskyAgent;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
 use tokio::sync::oneshot;
@@ -33,13 +86,38 @@ pub struct BskyActor {
     bsky_agent: BskyAgent,
     ctx: egui::Context,
     cancel_txs: HashMap<u64, oneshot::Sender<()>>,
+    desktop_notifier: DesktopNotifierCell,
+    feed_view_pref: FeedViewPrefCell,
+    realtime_cancel_tx: Option<oneshot::Sender<()>>,
+    typeahead_cancel_txs: HashMap<String, oneshot::Sender<()>>, // typeahead target -> request in flight
+    post_db: PostDbCell,
+    preferences_lock: PreferencesLock,
 }
+/// Connected on first use, stays `None` when no notification service is available.
+type DesktopNotifierCell = Arc<tokio::sync::OnceCell<Option<DesktopNotifier>>>;
+/// The feedViewPref entry of the Following timeline.
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+const HOME_FEED_PREF: &str = "home";
+/// The Following timeline filters, fetched from the preferences on first use.
+type FeedViewPrefCell = Arc<tokio::sync::RwLock<Option<FeedViewPref>>>;
+/// The post database of the logged in account, opened on login.
+type PostDbCell = Arc<std::sync::RwLock<Option<Arc<PostDb>>>>;
+/// Held from reading the preferences until they are written back, so two updates don't undo each other.
+type PreferencesLock = Arc<tokio::sync::Mutex<()>>;
 struct BskyJob {
     job: BskyActorMsg,
     tx: Sender<RedskyUiMsg>,
     #[cfg_attr(feature = "mock-api", allow(dead_code))]
     bsky_agent: BskyAgent,
     ctx: egui::Context, //for force repaint
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    desktop_notifier: DesktopNotifierCell,
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    feed_view_pref: FeedViewPrefCell,
+    post_db: Option<Arc<PostDb>>, // the database of the account the job was started for
+    post_db_cell: PostDbCell,
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    preferences_lock: PreferencesLock,
 }
 impl BskyActor {
     pub fn new(
@@ -54,10 +132,21 @@ impl BskyActor {
             bsky_agent,
             ctx,
             cancel_txs: HashMap::new(),
+            desktop_notifier: Arc::default(),
+            feed_view_pref: Arc::default(),
+            realtime_cancel_tx: None,
+            typeahead_cancel_txs: HashMap::new(),
+            post_db: Arc::default(),
+            preferences_lock: Arc::default(),
         }
     }
     pub fn pump(&mut self) -> bool {
-        match self.rx.recv() {
+        let msg = self.rx.recv();
+        if let Ok(BskyActorMsg::PutFeedViewPref { pref }) = &msg {
+            // Cached before the put goes out, so a GetTimeline sent right after already uses it
+            *self.feed_view_pref.blocking_write() = Some(*pref);
+        }
+        match msg {
             Ok(msg) => match msg {
                 BskyActorMsg::Close() => {
                     println!("bsky actor: closing");
@@ -69,6 +158,63 @@ impl BskyActor {
                     }
                     true
                 }
+                BskyActorMsg::StopRealtime() => {
+                    if let Some(tx) = self.realtime_cancel_tx.take() {
+                        let _ = tx.send(());
+                    }
+                    true
+                }
+                BskyActorMsg::StartRealtime() => {
+                    let (tx, rx) = oneshot::channel();
+                    if let Some(previous_tx) = self.realtime_cancel_tx.replace(tx) {
+                        let _ = previous_tx.send(());
+                    }
+                    let job = BskyJob {
+                        job: BskyActorMsg::StartRealtime(),
+                        tx: self.tx.clone(),
+                        bsky_agent: self.bsky_agent.clone(),
+                        ctx: self.ctx.clone(),
+                        desktop_notifier: self.desktop_notifier.clone(),
+                        feed_view_pref: self.feed_view_pref.clone(),
+                        post_db: self.post_db.read().unwrap().clone(),
+                        post_db_cell: self.post_db.clone(),
+                        preferences_lock: self.preferences_lock.clone(),
+                    };
+                    tokio::spawn(async move {
+                        tokio::select! {
+                            _ = job.perform() => {},
+                            _ = rx => {
+                                println!("realtime updates stopped");
+                            }
+                        }
+                    });
+                    true
+                }
+                BskyActorMsg::SearchActorsTypeahead { target, query, seq } => {
+                    // A newer keystroke makes the previous suggestions stale
+                    let (tx, rx) = oneshot::channel();
+                    if let Some(previous_tx) = self.typeahead_cancel_txs.insert(target.clone(), tx) {
+                        let _ = previous_tx.send(());
+                    }
+                    let job = BskyJob {
+                        job: BskyActorMsg::SearchActorsTypeahead { target, query, seq },
+                        tx: self.tx.clone(),
+                        bsky_agent: self.bsky_agent.clone(),
+                        ctx: self.ctx.clone(),
+                        desktop_notifier: self.desktop_notifier.clone(),
+                        feed_view_pref: self.feed_view_pref.clone(),
+                        post_db: self.post_db.read().unwrap().clone(),
+                        post_db_cell: self.post_db.clone(),
+                        preferences_lock: self.preferences_lock.clone(),
+                    };
+                    tokio::spawn(async move {
+                        tokio::select! {
+                            _ = job.perform() => {},
+                            _ = rx => {},
+                        }
+                    });
+                    true
+                }
                 BskyActorMsg::StartImageDownload { id, username, path } => {
                     let (tx, rx) = oneshot::channel();
                     self.cancel_txs.insert(id, tx);
@@ -77,6 +223,11 @@ impl BskyActor {
                         tx: self.tx.clone(),
                         bsky_agent: self.bsky_agent.clone(),
                         ctx: self.ctx.clone(),
+                        desktop_notifier: self.desktop_notifier.clone(),
+                        feed_view_pref: self.feed_view_pref.clone(),
+                        post_db: self.post_db.read().unwrap().clone(),
+                        post_db_cell: self.post_db.clone(),
+                        preferences_lock: self.preferences_lock.clone(),
                     };
                     tokio::spawn(async move {
                         tokio::select! {
@@ -94,6 +245,11 @@ impl BskyActor {
                         tx: self.tx.clone(),
                         bsky_agent: self.bsky_agent.clone(),
                         ctx: self.ctx.clone(),
+                        desktop_notifier: self.desktop_notifier.clone(),
+                        feed_view_pref: self.feed_view_pref.clone(),
+                        post_db: self.post_db.read().unwrap().clone(),
+                        post_db_cell: self.post_db.clone(),
+                        preferences_lock: self.preferences_lock.clone(),
                     };
                     tokio::spawn(job.perform());
                     true
@@ -114,11 +270,18 @@ fn extract_quote_reply(post_view: &Object<PostViewData>) -> Option<Post> {
         if let Union::Refs(ViewRecordRefs::ViewRecord(view_record)) = &embedded_record.record {
             let quote_post_data =
                 post::RecordData::try_from_unknown(view_record.value.clone()).ok()?;
+            let tags = extract_tags(&quote_post_data);
+            let labels = extract_labels(
+                &quote_post_data,
+                &view_record.labels,
+                &view_record.author.labels,
+            );
             Some(Post {
                 uri: view_record.uri.clone(),
                 cid: view_record.cid.clone(),
                 content: quote_post_data.text,
                 author: view_record.author.handle.to_string(),
+                author_did: view_record.author.did.to_string(),
                 display_name: view_record
                     .author
                     .display_name
@@ -133,7 +296,21 @@ fn extract_quote_reply(post_view: &Object<PostViewData>) -> Option<Post> {
                 is_reply: quote_post_data.reply.is_some(),
                 viewer_like: None,
                 viewer_repost: None,
+                bookmarked: false,
+                author_muted: view_record
+                    .author
+                    .viewer
+                    .as_ref()
+                    .and_then(|v| v.muted)
+                    .unwrap_or(false),
+                author_blocked: view_record
+                    .author
+                    .viewer
+                    .as_ref()
+                    .is_some_and(|v| v.blocking.is_some()),
                 thread_root: None,
+                tags,
+                labels,
                 raw_json: serde_json::to_string(&view_record).unwrap_or_default(),
             })
         } else {
@@ -143,6 +320,25 @@ fn extract_quote_reply(post_view: &Object<PostViewData>) -> Option<Post> {
         None
     }
 }
+/// Reads a value, changes it and writes it back while holding the lock.
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+async fn read_modify_write<T, E, Get, GetFut, Put, PutFut>(
+    lock: &tokio::sync::Mutex<()>,
+    get: Get,
+    update_fn: impl FnOnce(&mut T),
+    put: Put,
+) -> Result<(), E>
+where
+    Get: FnOnce() -> GetFut,
+    GetFut: std::future::Future<Output = Result<T, E>>,
+    Put: FnOnce(T) -> PutFut,
+    PutFut: std::future::Future<Output = Result<(), E>>,
+{
+    let _guard = lock.lock().await;
+    let mut value = get().await?;
+    update_fn(&mut value);
+    put(value).await
+}
 #[cfg_attr(feature = "mock-api", allow(dead_code))]
 fn extract_images(post_view: &Object<PostViewData>) -> Vec<PostImage> {
     post_view
@@ -171,6 +367,76 @@ fn extract_images(post_view: &Object<PostViewData>) -> Vec<PostImage> {
         .collect()
 }
 #[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn is_quote(post_view: &Object<PostViewData>) -> bool {
+    matches!(
+        post_view.embed,
+        Some(Union::Refs(
+            PostViewEmbedRefs::AppBskyEmbedRecordView(_) | PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(_)
+        ))
+    )
+}
+/// The did or handle an at:// uri starts with.
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn uri_author_did(uri: &str) -> Option<&str> {
+    uri.strip_prefix("at://")?.split('/').next().filter(|authority| !authority.is_empty())
+}
+/// Videos are not played, in the Videos tab of a profile their poster frame stands in for them.
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_video_thumbnail(post_view: &Object<PostViewData>) -> Option<PostImage> {
+    match &post_view.embed {
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedVideoView(data))) => {
+            data.thumbnail.as_ref().map(|thumbnail| {
+                PostImage::new(
+                    thumbnail.clone(),
+                    thumbnail.clone(),
+                    data.alt.clone().unwrap_or_else(|| "Video".to_string()),
+                )
+            })
+        }
+        _ => None,
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_tags(record: &post::RecordData) -> Vec<String> {
+    let facet_tags = record.facets.iter().flatten().flat_map(|facet| {
+        facet.features.iter().filter_map(|feature| {
+            if let Union::Refs(MainFeaturesItem::Tag(tag)) = feature {
+                Some(tag.tag.to_lowercase())
+            } else {
+                None
+            }
+        })
+    });
+    let outline_tags = record.tags.iter().flatten().map(|tag| tag.to_lowercase());
+    facet_tags.chain(outline_tags).collect()
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_labels(
+    record: &post::RecordData,
+    labels: &Option<Vec<Label>>,
+    author_labels: &Option<Vec<Label>>,
+) -> Vec<String> {
+    let self_labels = record.labels.iter().flat_map(|labels| match labels {
+        Union::Refs(RecordLabelsRefs::ComAtprotoLabelDefsSelfLabels(self_labels)) => self_labels
+            .values
+            .iter()
+            .map(|label| label.val.clone())
+            .collect::<Vec<_>>(),
+        _ => vec![],
+    });
+    let mut result: Vec<String> = labels
+        .iter()
+        .chain(author_labels.iter())
+        .flatten()
+        .filter(|label| !label.neg.unwrap_or(false))
+        .map(|label| label.val.clone())
+        .chain(self_labels)
+        .collect();
+    result.sort();
+    result.dedup();
+    result
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
 fn extract_post(post_view: &Object<PostViewData>) -> Option<Post> {
     let post_record_data =
         post::RecordData::try_from_unknown(post_view.data.record.clone()).ok()?;
@@ -181,6 +447,7 @@ fn extract_post(post_view: &Object<PostViewData>) -> Option<Post> {
         cid: post_view.cid.clone(),
         content: post_record_data.text.clone(),
         author: post_view.author.handle.to_string(),
+        author_did: post_view.author.did.to_string(),
         display_name: post_view.author.display_name.clone().unwrap_or_default(),
         avatar_img: post_view.author.avatar.clone().unwrap_or("".to_string()),
         date: post_record_data.created_at.as_str().to_string(),
@@ -191,16 +458,245 @@ fn extract_post(post_view: &Object<PostViewData>) -> Option<Post> {
         is_reply: post_record_data.reply.is_some(),
         viewer_like: post_view.viewer.as_ref().and_then(|v| v.like.clone()),
         viewer_repost: post_view.viewer.as_ref().and_then(|v| v.repost.clone()),
-        thread_root: post_record_data.reply.and_then(|reply| {
-            Some(StrongRef {
-                uri: reply.root.uri.clone(),
-                cid: reply.root.cid.clone(),
-            })
+        bookmarked: post_view
+            .viewer
+            .as_ref()
+            .and_then(|v| v.bookmarked)
+            .unwrap_or(false),
+        author_muted: post_view
+            .author
+            .viewer
+            .as_ref()
+            .and_then(|v| v.muted)
+            .unwrap_or(false),
+        author_blocked: post_view
+            .author
+            .viewer
+            .as_ref()
+            .is_some_and(|v| v.blocking.is_some()),
+        tags: extract_tags(&post_record_data),
+        labels: extract_labels(
+            &post_record_data,
+            &post_view.labels,
+            &post_view.author.labels,
+        ),
+        thread_root: post_record_data.reply.map(|reply| StrongRef {
+            uri: reply.root.uri.clone(),
+            cid: reply.root.cid.clone(),
         }),
         raw_json: serde_json::to_string(&post_view).unwrap_or_default(),
     })
 }
 #[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_profile_labels(labels: &Option<Vec<Label>>) -> Vec<String> {
+    let mut result: Vec<String> = labels
+        .iter()
+        .flatten()
+        .filter(|label| !label.neg.unwrap_or(false))
+        .map(|label| label.val.clone())
+        .collect();
+    result.sort();
+    result.dedup();
+    result
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_profile(profile: &Object<ProfileViewData>) -> UserProfile {
+    UserProfile {
+        did: profile.did.to_string(),
+        handle: profile.handle.to_string(),
+        display_name: profile
+            .display_name
+            .clone()
+            .unwrap_or("(no display name)".to_string()),
+        bio: profile
+            .description
+            .clone()
+            .unwrap_or("(No bio)".to_string()),
+        avatar_uri: profile.avatar.clone().unwrap_or("".to_string()),
+        follower_count: 0,
+        follow_count: 0,
+        post_count: 0,
+        viewer_following: profile.viewer.as_ref().and_then(|v| v.following.clone()).map(RecordState::Created),
+        viewer_followed_by: profile
+            .viewer
+            .as_ref()
+            .is_some_and(|v| v.followed_by.is_some()),
+        viewer_blocking: profile.viewer.as_ref().and_then(|v| v.blocking.clone()).map(RecordState::Created),
+        viewer_muted: profile
+            .viewer
+            .as_ref()
+            .and_then(|v| v.muted)
+            .unwrap_or(false),
+        banner_uri: String::new(),
+        labels: extract_profile_labels(&profile.labels),
+        pinned_post: None,
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_feed_generator(generator: &Object<GeneratorViewData>) -> FeedGenerator {
+    FeedGenerator {
+        uri: generator.uri.clone(),
+        display_name: generator.display_name.clone(),
+        description: generator.description.clone().unwrap_or_default(),
+        avatar_uri: generator.avatar.clone().unwrap_or_default(),
+        creator_handle: generator.creator.handle.to_string(),
+        like_count: generator.like_count.unwrap_or(0),
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_user_list(list: &Object<ListViewData>) -> UserList {
+    UserList {
+        uri: list.uri.clone(),
+        name: list.name.clone(),
+        description: list.description.clone().unwrap_or_default(),
+        purpose: list.purpose.clone(),
+        avatar_uri: list.avatar.clone().unwrap_or_default(),
+        item_count: list.list_item_count.unwrap_or(0),
+        creator_handle: list.creator.handle.to_string(),
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_chat_message(message: &MessageViewData) -> ChatMessage {
+    ChatMessage {
+        id: message.id.clone(),
+        sender_did: message.sender.did.to_string(),
+        text: message.text.clone(),
+        sent_at: message.sent_at.as_str().to_string(),
+        deleted: false,
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_deleted_chat_message(message: &DeletedMessageViewData) -> ChatMessage {
+    ChatMessage {
+        id: message.id.clone(),
+        sender_did: message.sender.did.to_string(),
+        text: String::new(),
+        sent_at: message.sent_at.as_str().to_string(),
+        deleted: true,
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_conversation(convo: &ConvoViewData) -> Conversation {
+    Conversation {
+        id: convo.id.clone(),
+        members: convo
+            .members
+            .iter()
+            .map(|member| ConvoMember {
+                did: member.did.to_string(),
+                handle: member.handle.to_string(),
+                display_name: member.display_name.clone().unwrap_or_default(),
+                avatar_uri: member.avatar.clone().unwrap_or_default(),
+            })
+            .collect(),
+        muted: convo.muted,
+        unread_count: convo.unread_count,
+        rev: convo.rev.clone(),
+        last_message: match &convo.last_message {
+            Some(Union::Refs(ConvoViewLastMessageRefs::MessageView(message))) => {
+                Some(extract_chat_message(message))
+            }
+            Some(Union::Refs(ConvoViewLastMessageRefs::DeletedMessageView(message))) => {
+                Some(extract_deleted_chat_message(message))
+            }
+            _ => None,
+        },
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_chat_log_event(log: &Union<LogsItem>) -> Option<ChatLogEvent> {
+    let Union::Refs(log) = log else {
+        return None;
+    };
+    Some(match log {
+        LogsItem::ChatBskyConvoDefsLogCreateMessage(log) => match &log.message {
+            Union::Refs(LogCreateMessageMessageRefs::MessageView(message)) => ChatLogEvent::NewMessage {
+                convo_id: log.convo_id.clone(),
+                message: extract_chat_message(message),
+            },
+            Union::Refs(LogCreateMessageMessageRefs::DeletedMessageView(message)) => {
+                ChatLogEvent::MessageDeleted {
+                    convo_id: log.convo_id.clone(),
+                    message_id: message.id.clone(),
+                }
+            }
+            Union::Unknown(_) => return None,
+        },
+        LogsItem::ChatBskyConvoDefsLogDeleteMessage(log) => match &log.message {
+            Union::Refs(LogDeleteMessageMessageRefs::MessageView(message)) => ChatLogEvent::MessageDeleted {
+                convo_id: log.convo_id.clone(),
+                message_id: message.id.clone(),
+            },
+            Union::Refs(LogDeleteMessageMessageRefs::DeletedMessageView(message)) => {
+                ChatLogEvent::MessageDeleted {
+                    convo_id: log.convo_id.clone(),
+                    message_id: message.id.clone(),
+                }
+            }
+            Union::Unknown(_) => return None,
+        },
+        LogsItem::ChatBskyConvoDefsLogLeaveConvo(log) => ChatLogEvent::ConvoLeft {
+            convo_id: log.convo_id.clone(),
+        },
+        LogsItem::ChatBskyConvoDefsLogBeginConvo(log) => ChatLogEvent::ConvoChanged {
+            convo_id: log.convo_id.clone(),
+        },
+        LogsItem::ChatBskyConvoDefsLogAcceptConvo(log) => ChatLogEvent::ConvoChanged {
+            convo_id: log.convo_id.clone(),
+        },
+        LogsItem::ChatBskyConvoDefsLogMuteConvo(log) => ChatLogEvent::ConvoChanged {
+            convo_id: log.convo_id.clone(),
+        },
+        LogsItem::ChatBskyConvoDefsLogUnmuteConvo(log) => ChatLogEvent::ConvoChanged {
+            convo_id: log.convo_id.clone(),
+        },
+        LogsItem::ChatBskyConvoDefsLogReadMessage(log) => ChatLogEvent::ConvoChanged {
+            convo_id: log.convo_id.clone(),
+        },
+        // Reactions are not displayed
+        LogsItem::ChatBskyConvoDefsLogAddReaction(_) | LogsItem::ChatBskyConvoDefsLogRemoveReaction(_) => {
+            return None
+        }
+    })
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn extract_muted_word(word: &MutedWordData) -> MutedWord {
+    let content = word.targets.iter().any(|t| t == "content");
+    let tags = word.targets.iter().any(|t| t == "tag");
+    MutedWord {
+        id: word.id.clone(),
+        value: word.value.clone(),
+        targets: match (content, tags) {
+            (true, false) => MutedWordTargets::Content,
+            (false, true) => MutedWordTargets::Tags,
+            _ => MutedWordTargets::Both,
+        },
+        expires_at: word.expires_at.as_ref().map(|dt| dt.as_ref().timestamp()),
+        actor_target: word.actor_target.clone(),
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn muted_word_to_pref(word: &MutedWord) -> MutedWordData {
+    let targets = match word.targets {
+        MutedWordTargets::Content => vec!["content".to_string()],
+        MutedWordTargets::Tags => vec!["tag".to_string()],
+        MutedWordTargets::Both => vec!["content".to_string(), "tag".to_string()],
+    };
+    MutedWordData {
+        actor_target: word.actor_target.clone(),
+        expires_at: word
+            .expires_at
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|dt| atrium_api::types::string::Datetime::new(dt.fixed_offset())),
+        // Words added here get an id like the ones added by other clients
+        id: word.id.clone().or_else(|| {
+            Some(atrium_api::types::string::Tid::now(atrium_api::types::LimitedU32::MIN).to_string())
+        }),
+        targets,
+        value: word.value.clone(),
+    }
+}
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
 fn extract_post_from_bookmark(bookmark: &Object<BookmarkViewData>) -> Option<Post> {
     match &bookmark.item {
         Union::Refs(BookmarkViewItemRefs::AppBskyFeedDefsPostView(post)) => {
@@ -216,6 +712,12 @@ impl BskyJob {
             BskyActorMsg::Login { login, pass } => self.login(login, pass).await,
             BskyActorMsg::Post { msg_body, image_paths, reply_to } => self.post(msg_body, image_paths, reply_to).await,
             BskyActorMsg::GetPostAndReplies { post_ref } => self.get_post_thread(post_ref).await,
+            BskyActorMsg::ResolveLink { link, target } => self.resolve_link(target).await.or_else(|err| {
+                Ok(RedskyUiMsg::OpenLinkFailed {
+                    link: link.clone(),
+                    error: err.to_string(),
+                })
+            }),
             BskyActorMsg::GetPostLikers { post_ref, cursor } => self.get_post_likers(post_ref, cursor).await,
             BskyActorMsg::GetPostRepostedBy { post_ref, cursor } => {
                 self.get_post_reposted_by(post_ref, cursor).await
@@ -234,38 +736,302 @@ impl BskyJob {
                 self.unrepost(post_uri.clone(), repost_record_uri.clone())
                     .await
             }
+            BskyActorMsg::Follow { did } => self.follow(did).await.or_else(|err| {
+                Ok(RedskyUiMsg::FollowActionFailed {
+                    did: did.clone(),
+                    follow_uri: None,
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::Unfollow {
+                did,
+                follow_record_uri,
+            } => self.unfollow(did.clone(), follow_record_uri.clone()).await.or_else(|err| {
+                Ok(RedskyUiMsg::FollowActionFailed {
+                    did: did.clone(),
+                    follow_uri: Some(follow_record_uri.clone()),
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::Block { did } => self.block(did).await.or_else(|err| {
+                Ok(RedskyUiMsg::BlockActionFailed {
+                    did: did.clone(),
+                    block_uri: None,
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::Unblock {
+                did,
+                block_record_uri,
+            } => self.unblock(did.clone(), block_record_uri.clone()).await.or_else(|err| {
+                Ok(RedskyUiMsg::BlockActionFailed {
+                    did: did.clone(),
+                    block_uri: Some(block_record_uri.clone()),
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::Mute { did } => self.mute(did).await.or_else(|err| {
+                Ok(RedskyUiMsg::MuteActionFailed {
+                    did: did.clone(),
+                    muted: false,
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::Unmute { did } => self.unmute(did).await.or_else(|err| {
+                Ok(RedskyUiMsg::MuteActionFailed {
+                    did: did.clone(),
+                    muted: true,
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::GetBlocks { cursor } => self.get_blocks(cursor).await,
+            BskyActorMsg::GetMutes { cursor } => self.get_mutes(cursor).await,
+            BskyActorMsg::GetMutedWords() => self.get_muted_words().await,
+            BskyActorMsg::PutMutedWords { words } => self.put_muted_words(words).await,
+            BskyActorMsg::GetContentPrefs() => self.get_content_prefs().await,
+            BskyActorMsg::GetFeedViewPref() => self.get_feed_view_pref().await,
+            BskyActorMsg::PutFeedViewPref { pref } => self.put_feed_view_pref(*pref).await,
+            BskyActorMsg::PutContentPrefs {
+                adult_content_enabled,
+                label_visibility,
+            } => {
+                self.put_content_prefs(*adult_content_enabled, label_visibility)
+                    .await
+            }
             BskyActorMsg::GetTimeline { cursor } => self.get_timeline_posts(cursor).await,
             BskyActorMsg::GetBookmarks { cursor } => self.get_bookmarks(cursor).await,
+            BskyActorMsg::Bookmark { post_ref } => self.bookmark(post_ref).await.or_else(|err| {
+                Ok(RedskyUiMsg::BookmarkActionFailed {
+                    post_uri: post_ref.uri.clone(),
+                    bookmarked: false,
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::Unbookmark { post_uri } => self.unbookmark(post_uri).await.or_else(|err| {
+                Ok(RedskyUiMsg::BookmarkActionFailed {
+                    post_uri: post_uri.clone(),
+                    bookmarked: true,
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::GetFeed { feed_uri, cursor } => self.get_feed(feed_uri, cursor).await,
+            BskyActorMsg::GetSavedFeeds() => self.get_saved_feeds().await,
+            BskyActorMsg::GetListFeed { list_uri, cursor } => {
+                self.get_list_feed(list_uri, cursor).await
+            }
+            BskyActorMsg::GetLists { cursor } => self.get_lists(cursor).await,
+            BskyActorMsg::GetList { list_uri, cursor } => self.get_list(list_uri, cursor).await,
+            BskyActorMsg::GetListMemberships { did } => self.get_list_memberships(did).await,
+            BskyActorMsg::CreateList {
+                name,
+                description,
+                purpose,
+            } => self.create_list(name, description, purpose).await,
+            BskyActorMsg::UpdateList {
+                list_uri,
+                name,
+                description,
+            } => self.update_list(list_uri, name, description).await,
+            BskyActorMsg::DeleteList { list_uri } => self.delete_list(list_uri).await,
+            BskyActorMsg::GetProfileRecord() => self.get_profile_record().await,
+            BskyActorMsg::UpdateProfile { edit } => self.update_profile(edit).await.or_else(|err| {
+                Ok(RedskyUiMsg::ProfileUpdateFailed {
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::AddListMember { list_uri, did } => {
+                self.add_list_member(list_uri, did).await.or_else(|err| {
+                    Ok(RedskyUiMsg::AddListMemberFailed {
+                        list_uri: list_uri.clone(),
+                        did: did.clone(),
+                        error: err.to_string(),
+                    })
+                })
+            }
+            BskyActorMsg::RemoveListMember { item_uri } => self.remove_list_member(item_uri).await,
+            BskyActorMsg::PutSavedFeeds { saved } => self.put_saved_feeds(saved).await,
+            BskyActorMsg::GetSuggestedFeeds { cursor } => self.get_suggested_feeds(cursor).await,
+            BskyActorMsg::GetPopularFeeds { query, cursor } => {
+                self.get_popular_feeds(query, cursor).await
+            }
             BskyActorMsg::GetUserProfile { username } => self.get_user_profile(username).await,
-            BskyActorMsg::GetUserPosts { username, cursor } => {
-                self.get_user_posts(username, cursor).await
+            BskyActorMsg::GetFollowers { username, cursor } => {
+                self.get_followers(username, cursor).await
+            }
+            BskyActorMsg::GetFollows { username, cursor } => self.get_follows(username, cursor).await,
+            BskyActorMsg::GetKnownFollowers { username, cursor } => {
+                self.get_known_followers(username, cursor).await
+            }
+            BskyActorMsg::GetUserPosts { username, view, cursor } => {
+                self.get_user_posts(username, *view, cursor).await
             }
             BskyActorMsg::GetUserLikes { username, cursor } => {
                 self.get_user_likes(username, cursor).await
             }
-            BskyActorMsg::SearchActors { query } => self.search_actors(query).await,
-            BskyActorMsg::SearchPosts { query, cursor } => self.search_posts(query, cursor).await,
+            BskyActorMsg::SearchActors { query, cursor } => self.search_actors(query, cursor).await,
+            BskyActorMsg::SearchActorsTypeahead { target, query, seq } => {
+                self.search_actors_typeahead(target, query, *seq).await
+            }
+            BskyActorMsg::SearchPosts { search, cursor } => self.search_posts(search, cursor).await,
+            BskyActorMsg::GetSavedSearch { query, cursor } => self.get_saved_search(query, cursor).await,
             BskyActorMsg::LoadImage { url } => self.load_image(url).await,
             BskyActorMsg::StartImageDownload { id, username, path } => {
                 self.download_all_images(*id, username, path).await
             }
-            BskyActorMsg::CancelImageDownload { .. } => {
+            BskyActorMsg::CancelImageDownload { .. } | BskyActorMsg::StopRealtime() => {
                 Ok(RedskyUiMsg::LogInSucceededMsg()) // dummy
             }
+            BskyActorMsg::StartRealtime() => self.run_realtime().await,
             BskyActorMsg::GetUnreadCount() => self.get_unread_count().await,
             BskyActorMsg::GetNotifications { cursor } => self.get_notifications(cursor).await,
+            BskyActorMsg::UpdateSeen() => self.update_seen().await,
+            BskyActorMsg::GetTimelineGap { cursor } => self.get_timeline_gap(cursor).await.or_else(|err| {
+                Ok(RedskyUiMsg::TimelineGapFailed {
+                    gap_cursor: cursor.clone(),
+                    error: err.to_string(),
+                })
+            }),
+            BskyActorMsg::ListConvos { cursor } => self.list_convos(cursor).await,
+            BskyActorMsg::GetMessages { convo_id, cursor } => self.get_messages(convo_id, cursor).await,
+            BskyActorMsg::SendMessage { convo_id, text } => self.send_message(convo_id, text).await,
+            BskyActorMsg::GetConvoForMembers { did } => self.get_convo_for_members(did).await,
+            BskyActorMsg::MuteConvo { convo_id, mute } => self.mute_convo(convo_id, *mute).await,
+            BskyActorMsg::LeaveConvo { convo_id } => self.leave_convo(convo_id).await,
+            BskyActorMsg::UpdateConvoRead { convo_id } => self.update_convo_read(convo_id).await,
+            BskyActorMsg::GetChatLog { cursor } => self.get_chat_log(cursor).await,
+            BskyActorMsg::RaiseDesktopNotifications { reasons, since } => {
+                self.raise_desktop_notifications(reasons, since).await
+            }
+            BskyActorMsg::LoadCachedFeed { feed } => self.load_cached_feed(feed).await,
+            BskyActorMsg::SearchHistory { query, filter } => self.search_history(query, *filter).await,
             BskyActorMsg::Close() => {
                 panic!("unexpected message");
             }
         };
-        if let Ok(reply) = result {
-            self.post_to_ui(reply);
-        } else if let Err(e) = result {
-            self.post_to_ui(RedskyUiMsg::ShowErrorMsg {
-                error: e.to_string(),
-            });
+        match result {
+            Ok(reply) => {
+                if let Some(reply) = self.store_reply(reply).await {
+                    self.post_to_ui(reply);
+                }
+            }
+            Err(e) => {
+                if let Some((feed, cursor)) = self.feed_page_request() {
+                    self.post_to_ui(RedskyUiMsg::FeedPageFailed { feed, cursor });
+                }
+                match self.offline_fallback().await {
+                    // Offline, show what was saved instead
+                    Some(reply) => self.post_to_ui(reply),
+                    None => self.post_to_ui(RedskyUiMsg::ShowErrorMsg {
+                        error: e.to_string(),
+                    }),
+                }
+            }
+        }
+    }
+    /// The feed, keyed like make_post_view, and the cursor of a feed page request.
+    fn feed_page_request(&self) -> Option<(String, Option<String>)> {
+        match &self.job {
+            BskyActorMsg::GetTimeline { cursor } => Some(("Your timeline".to_string(), cursor.clone())),
+            BskyActorMsg::GetFeed { feed_uri, cursor } => Some((feed_uri.clone(), cursor.clone())),
+            BskyActorMsg::GetListFeed { list_uri, cursor } => Some((list_uri.clone(), cursor.clone())),
+            _ => None,
         }
     }
+    fn post_db(&self) -> Result<Arc<PostDb>, Box<dyn std::error::Error + Send + Sync>> {
+        self.post_db.clone().ok_or_else(|| "The local post database is not available".into())
+    }
+    /// Each account has its own post database, jobs started before the login keep the previous one.
+    async fn open_post_db(&self, did: &str) {
+        let did = did.to_string();
+        let post_db = tokio::task::spawn_blocking(move || PostDb::open_default(&did)).await.ok().flatten();
+        *self.post_db_cell.write().unwrap() = post_db.map(Arc::new);
+    }
+    /// Saves the posts and profiles of a reply, on a blocking thread since SQLite does disk I/O.
+    /// Gives the reply back, None if the storing task panicked.
+    async fn store_reply(&self, reply: RedskyUiMsg) -> Option<RedskyUiMsg> {
+        let Ok(db) = self.post_db() else { return Some(reply) };
+        let stored = tokio::task::spawn_blocking(move || {
+            let result = match &reply {
+                RedskyUiMsg::RefreshTimelineMsg { posts, cursor, append, .. } => {
+                    db.store_feed_page("Your timeline", posts, cursor, *append)
+                }
+                RedskyUiMsg::RefreshBookmarksMsg { posts, cursor, append } => {
+                    db.store_feed_page("Your bookmarks", posts, cursor, *append)
+                }
+                RedskyUiMsg::RefreshCustomFeedMsg {
+                    feed_uri,
+                    posts,
+                    cursor,
+                    append,
+                } => db.store_feed_page(feed_uri, posts, cursor, *append),
+                RedskyUiMsg::FillTimelineGapMsg { posts, .. }
+                | RedskyUiMsg::ShowUserPostsMsg { posts, .. }
+                | RedskyUiMsg::ShowUserLikesMsg { posts, .. }
+                | RedskyUiMsg::ShowSearchPostsResults { posts, .. }
+                | RedskyUiMsg::RefreshSavedSearchMsg { posts, .. } => db.store_posts(posts),
+                RedskyUiMsg::NotifyPostAndRepliesLoaded { post, replies } => {
+                    db.store_posts(std::slice::from_ref(post)).and_then(|_| db.store_posts(replies))
+                }
+                RedskyUiMsg::NotifyRealtimePost { post } => db.store_posts(std::slice::from_ref(post)),
+                RedskyUiMsg::ShowUserProfile { profile } => db.store_profiles(std::slice::from_ref(profile)),
+                RedskyUiMsg::NotifyUserListLoaded { users, .. } => db.store_profiles(users),
+                RedskyUiMsg::ShowSearchResults { results, .. } => db.store_profiles(results),
+                _ => Ok(()),
+            };
+            if let Err(err) = result {
+                println!("post db: {}", err);
+            }
+            reply
+        });
+        match stored.await {
+            Ok(reply) => Some(reply),
+            Err(err) => {
+                println!("post db: {}", err);
+                None
+            }
+        }
+    }
+    /// First pages and profiles can be served from the local database when the network fails.
+    async fn offline_fallback(&self) -> Option<RedskyUiMsg> {
+        let feed = match &self.job {
+            BskyActorMsg::GetTimeline { cursor: None } => "Your timeline",
+            BskyActorMsg::GetBookmarks { cursor: None } => "Your bookmarks",
+            BskyActorMsg::GetFeed { feed_uri, cursor: None } => feed_uri,
+            BskyActorMsg::GetListFeed { list_uri, cursor: None } => list_uri,
+            BskyActorMsg::GetUserProfile { username } => {
+                let db = self.post_db().ok()?;
+                let username = username.clone();
+                let profile = tokio::task::spawn_blocking(move || db.load_profile(&username))
+                    .await
+                    .ok()?
+                    .ok()??;
+                return Some(RedskyUiMsg::ShowUserProfile { profile });
+            }
+            _ => return None,
+        };
+        self.load_cached_feed(feed).await.ok().filter(|reply| {
+            matches!(reply, RedskyUiMsg::ShowCachedFeedMsg { posts, .. } if !posts.is_empty())
+        })
+    }
+    async fn load_cached_feed(&self, feed: &str) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let db = self.post_db()?;
+        let feed = feed.to_string();
+        let (posts, cursor, feed) = tokio::task::spawn_blocking(move || {
+            db.load_feed(&feed).map(|(posts, cursor)| (posts, cursor, feed))
+        })
+        .await??;
+        Ok(RedskyUiMsg::ShowCachedFeedMsg { feed, posts, cursor })
+    }
+    async fn search_history(
+        &self,
+        query: &str,
+        filter: HistoryFilter,
+    ) -> Result<RedskyUiMsg, Box<dyn std::error::Error + Send + Sync>> {
+        let db = self.post_db()?;
+        let query = query.to_string();
+        let posts = tokio::task::spawn_blocking(move || db.search(&query, filter)).await??;
+        Ok(RedskyUiMsg::ShowHistoryResults { posts })
+    }
     pub fn post_to_ui(&self, msg: RedskyUiMsg) {
         self.tx.send(msg).unwrap();
         self.ctx.request_repaint();
@@ -295,4 +1061,233 @@ mod tests {
         // pump() should return false when the channel is closed
         assert!(!actor.pump());
     }
+
+    #[tokio::test]
+    async fn test_overlapping_preference_updates_keep_both_changes() {
+        let lock = Arc::new(tokio::sync::Mutex::new(()));
+        let stored: Arc<std::sync::Mutex<Vec<&str>>> = Arc::default();
+        let update = |word: &'static str| {
+            let (lock, stored) = (lock.clone(), stored.clone());
+            async move {
+                let get_store = stored.clone();
+                read_modify_write(
+                    &lock,
+                    || async move {
+                        let preferences = get_store.lock().unwrap().clone();
+                        // Give the other update time to read the same preferences
+                        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                        Ok::<_, ()>(preferences)
+                    },
+                    |preferences| preferences.push(word),
+                    |preferences| async move {
+                        *stored.lock().unwrap() = preferences;
+                        Ok(())
+                    },
+                )
+                .await
+            }
+        };
+        let (muted_words, content_prefs) = tokio::join!(update("muted words"), update("content prefs"));
+        assert!(muted_words.is_ok() && content_prefs.is_ok());
+        let mut stored = stored.lock().unwrap().clone();
+        stored.sort();
+        assert_eq!(stored, ["content prefs", "muted words"]);
+    }
+
+    #[test]
+    fn test_muted_word_pref_round_trip() {
+        let word = MutedWord {
+            id: Some("abc".to_string()),
+            value: "rust".to_string(),
+            targets: MutedWordTargets::Tags,
+            expires_at: Some(1_800_000_000),
+            actor_target: Some("exclude-following".to_string()),
+        };
+        let pref = muted_word_to_pref(&word);
+        assert_eq!(pref.targets, vec!["tag".to_string()]);
+        assert_eq!(pref.id.as_deref(), Some("abc"));
+        assert_eq!(pref.actor_target.as_deref(), Some("exclude-following"));
+        assert_eq!(extract_muted_word(&pref), word);
+
+        let new_word = MutedWord {
+            id: None,
+            actor_target: None,
+            ..word
+        };
+        assert!(muted_word_to_pref(&new_word).id.is_some());
+    }
+
+    #[test]
+    fn test_muted_word_matches() {
+        let tags = vec!["rustlang".to_string()];
+        let word = |value: &str, targets| MutedWord {
+            id: None,
+            value: value.to_string(),
+            targets,
+            expires_at: Some(100),
+            actor_target: None,
+        };
+        assert!(word("Spoiler", MutedWordTargets::Both).matches("no spoiler!", &[]));
+        assert!(!word("spoil", MutedWordTargets::Both).matches("no spoiler!", &[]));
+        assert!(word("big game", MutedWordTargets::Content).matches("The Big Game tonight", &[]));
+        assert!(word("#RustLang", MutedWordTargets::Tags).matches("", &tags));
+        assert!(!word("rustlang", MutedWordTargets::Content).matches("", &tags));
+        assert!(!word("rustlang", MutedWordTargets::Tags).matches("rustlang", &[]));
+        assert!(word("x", MutedWordTargets::Both).is_expired(100));
+        assert!(!word("x", MutedWordTargets::Both).is_expired(99));
+    }
+
+    #[test]
+    fn test_moderate_labels() {
+        use crate::app::{AppSettings, LabelAction};
+        let labels = |vals: &[&str]| vals.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        let mut settings = AppSettings::default();
+        assert_eq!(settings.moderate_labels(&labels(&["nudity"])), Some(LabelAction::Hide("nudity".to_string())));
+        assert_eq!(
+            settings.moderate_labels(&labels(&["graphic-media", "!warn"])),
+            Some(LabelAction::Warn("!warn".to_string()))
+        );
+        settings.adult_content_enabled = true;
+        assert_eq!(settings.moderate_labels(&labels(&["nudity", "spam"])), None);
+        settings.label_visibility.insert("sexual".to_string(), LabelVisibility::Hide);
+        assert_eq!(
+            settings.moderate_labels(&labels(&["graphic-media", "sexual"])),
+            Some(LabelAction::Hide("sexual".to_string()))
+        );
+        assert!(toml::from_str::<AppSettings>(&toml::to_string(&settings).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_group_notifications() {
+        use crate::app::{group_notifications, AppNotification, NotificationFilter};
+        let notif = |author: &str, reason: &str, subject: Option<&str>| AppNotification {
+            uri: format!("at://{}/app.bsky.feed.like/1", author),
+            cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
+            author: author.to_string(),
+            author_did: format!("did:plc:{}", author),
+            author_avatar: String::new(),
+            reason: reason.to_string(),
+            reason_subject: subject.map(|s| s.to_string()),
+            record_text: String::new(),
+            date: String::new(),
+            is_read: author != "carol",
+        };
+        let groups = group_notifications(&[
+            notif("alice", "like", Some("at://me/post/1")),
+            notif("bob", "reply", Some("at://me/post/1")),
+            notif("carol", "like", Some("at://me/post/1")),
+            notif("dave", "like", Some("at://me/post/2")),
+            notif("erin", "like", Some("at://me/post/1")),
+            notif("frank", "like", Some("at://me/post/1")),
+        ]);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].notifications.len(), 4);
+        assert_eq!(groups[0].authors_text(), "@alice, @carol and 2 others");
+        assert!(!groups[0].is_read());
+        assert_eq!(groups[1].reason, "reply");
+        assert_eq!(groups[2].authors_text(), "@dave");
+        assert!(NotificationFilter::Mentions.matches("reply"));
+        assert!(!NotificationFilter::Likes.matches("repost"));
+    }
+
+    #[test]
+    fn test_extract_chat_log_event() {
+        let log: Union<LogsItem> = serde_json::from_value(serde_json::json!({
+            "$type": "chat.bsky.convo.defs#logCreateMessage",
+            "convoId": "convo1",
+            "rev": "2",
+            "message": {
+                "$type": "chat.bsky.convo.defs#messageView",
+                "id": "msg1",
+                "rev": "2",
+                "text": "hello",
+                "sender": { "did": "did:plc:alice" },
+                "sentAt": "2024-01-01T00:00:00.000Z"
+            }
+        }))
+        .unwrap();
+        match extract_chat_log_event(&log) {
+            Some(ChatLogEvent::NewMessage { convo_id, message }) => {
+                assert_eq!(convo_id, "convo1");
+                assert_eq!(message.sender_did, "did:plc:alice");
+                assert_eq!(message.text, "hello");
+                assert!(!message.deleted);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let log: Union<LogsItem> = serde_json::from_value(serde_json::json!({
+            "$type": "chat.bsky.convo.defs#logLeaveConvo",
+            "convoId": "convo1",
+            "rev": "3"
+        }))
+        .unwrap();
+        assert_eq!(
+            extract_chat_log_event(&log),
+            Some(ChatLogEvent::ConvoLeft {
+                convo_id: "convo1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_actor_typeahead_debounce_and_mentions() {
+        use crate::app::{mention_at, ActorTypeahead};
+        let debounce = std::time::Duration::from_millis(250);
+        let start = std::time::Instant::now();
+        let mut typeahead = ActorTypeahead::default();
+        typeahead.set_query("al", start);
+        assert_eq!(typeahead.poll(start, debounce), None);
+        typeahead.set_query("ali", start + debounce / 2);
+        assert_eq!(typeahead.poll(start + debounce, debounce), None);
+        let (query, first_seq) = typeahead.poll(start + debounce * 2, debounce).unwrap();
+        assert_eq!(query, "ali");
+        assert_eq!(typeahead.poll(start + debounce * 3, debounce), None);
+
+        // An answer to an older request never replaces the latest one
+        typeahead.set_query("alic", start + debounce * 3);
+        let (_, second_seq) = typeahead.poll(start + debounce * 4, debounce).unwrap();
+        let profile = |handle: &str| UserProfile {
+            handle: handle.to_string(),
+            ..Default::default()
+        };
+        assert!(!typeahead.accept(first_seq, vec![profile("al.bsky.social")]));
+        assert!(typeahead.accept(second_seq, vec![profile("alice.bsky.social")]));
+        assert_eq!(typeahead.suggestions[0].handle, "alice.bsky.social");
+        typeahead.set_query("", start + debounce * 5);
+        assert!(typeahead.suggestions.is_empty());
+
+        let text = "hi @alice.bsk and a@b.c";
+        assert_eq!(mention_at(text, 13), Some((3, "alice.bsk".to_string())));
+        assert_eq!(mention_at(text, 4), Some((3, String::new())));
+        assert_eq!(mention_at(text, 2), None);
+        assert_eq!(mention_at(text, text.chars().count()), None); // not a mention inside an address
+        assert_eq!(mention_at("@bob", 4), Some((0, "bob".to_string())));
+    }
+
+    #[test]
+    fn test_feed_view_pref_filters_timeline_items() {
+        let pref = FeedViewPref::default();
+        // Only replies to people we don't follow are hidden by default
+        assert!(!pref.hides(false, None, false));
+        assert!(!pref.hides(false, Some(true), false));
+        assert!(pref.hides(false, Some(false), false));
+        assert!(!pref.hides(true, None, true));
+
+        let pref = FeedViewPref {
+            hide_reposts: true,
+            hide_replies: true,
+            hide_replies_by_unfollowed: false,
+            hide_quote_posts: true,
+        };
+        assert!(pref.hides(true, None, false));
+        assert!(pref.hides(false, Some(true), false));
+        assert!(pref.hides(false, None, true));
+        assert!(!pref.hides(false, None, false));
+
+        // Live replies are judged by the author of the parent uri
+        assert_eq!(uri_author_did("at://did:plc:bob/app.bsky.feed.post/3k2"), Some("did:plc:bob"));
+        assert_eq!(uri_author_did("https://bsky.app"), None);
+    }
+
 }
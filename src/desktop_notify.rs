@@ -0,0 +1,251 @@
+#![cfg_attr(feature = "mock-api", allow(dead_code))]
+
+use crate::app::NotificationTarget;
+
+pub type ClickHandler = Box<dyn Fn(NotificationTarget) + Send + Sync>;
+
+/// Whether clicking a notification opens its thread or profile. notify-rust does not report
+/// toast activations on Windows and macOS, there a click only brings Redsky to the front.
+pub const OPENS_CLICKED_TARGET: bool = cfg!(target_os = "linux");
+
+pub struct DesktopNotification {
+    pub summary: String,
+    pub body: String,
+    pub target: NotificationTarget,
+}
+
+/// Compares two RFC 3339 timestamps, falling back to string order if either does not parse.
+pub fn is_after(date: &str, since: &str) -> bool {
+    match (
+        chrono::DateTime::parse_from_rfc3339(date),
+        chrono::DateTime::parse_from_rfc3339(since),
+    ) {
+        (Ok(date), Ok(since)) => date > since,
+        _ => date > since,
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[zbus::proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+pub trait Notifications {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: std::collections::HashMap<&str, zbus::zvariant::Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    #[zbus(signal)]
+    fn action_invoked(&self, id: u32, action_key: String) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn notification_closed(&self, id: u32, reason: u32) -> zbus::Result<()>;
+}
+
+/// Freedesktop notifications over the D-Bus session bus. Clicking a notification
+/// invokes its "default" action, which is routed to the click handler.
+#[cfg(target_os = "linux")]
+pub struct DesktopNotifier {
+    proxy: NotificationsProxy<'static>,
+    targets: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u32, NotificationTarget>>>,
+}
+
+#[cfg(target_os = "linux")]
+impl DesktopNotifier {
+    pub async fn connect_session(
+        on_click: ClickHandler,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let connection = zbus::Connection::session().await?;
+        Self::connect(&connection, on_click).await
+    }
+
+    pub async fn connect(
+        connection: &zbus::Connection,
+        on_click: ClickHandler,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        use futures_util::StreamExt;
+
+        let proxy = NotificationsProxy::new(connection).await?;
+        let targets = std::sync::Arc::new(std::sync::Mutex::new(std::collections::HashMap::new()));
+        let mut clicks = proxy.receive_action_invoked().await?;
+        let mut closes = proxy.receive_notification_closed().await?;
+        let signal_targets = targets.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    Some(signal) = clicks.next() => {
+                        let Ok(args) = signal.args() else { continue };
+                        let target = signal_targets.lock().unwrap().remove(&args.id);
+                        if let Some(target) = target {
+                            on_click(target);
+                        }
+                    }
+                    Some(signal) = closes.next() => {
+                        if let Ok(args) = signal.args() {
+                            signal_targets.lock().unwrap().remove(&args.id);
+                        }
+                    }
+                    else => break,
+                }
+            }
+        });
+        Ok(Self { proxy, targets })
+    }
+
+    pub async fn notify(
+        &self,
+        notification: DesktopNotification,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let id = self
+            .proxy
+            .notify(
+                "Redsky",
+                0,
+                "",
+                &notification.summary,
+                &notification.body,
+                &["default", "Open"],
+                std::collections::HashMap::new(),
+                -1,
+            )
+            .await?;
+        self.targets.lock().unwrap().insert(id, notification.target);
+        Ok(())
+    }
+}
+
+/// Native notifications through notify-rust. Clicks are handled by the OS, which
+/// brings Redsky to the front but cannot open the related thread.
+#[cfg(not(target_os = "linux"))]
+pub struct DesktopNotifier;
+
+#[cfg(not(target_os = "linux"))]
+impl DesktopNotifier {
+    pub async fn connect_session(
+        _on_click: ClickHandler,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Self)
+    }
+
+    pub async fn notify(
+        &self,
+        notification: DesktopNotification,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        notify_rust::Notification::new()
+            .appname("Redsky")
+            .summary(&notification.summary)
+            .body(&notification.body)
+            .show()?;
+        Ok(())
+    }
+}
+
+// The fake daemon speaks D-Bus, which only the Linux notifier uses
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    type NotifyCalls = std::sync::Arc<std::sync::Mutex<Vec<(String, Vec<String>)>>>; // summary, actions
+
+    struct FakeNotificationServer {
+        notified: NotifyCalls,
+    }
+
+    /// Stand-in for the session bus notification daemon
+    #[zbus::interface(name = "org.freedesktop.Notifications")]
+    impl FakeNotificationServer {
+        #[allow(clippy::too_many_arguments)]
+        fn notify(
+            &self,
+            _app_name: &str,
+            _replaces_id: u32,
+            _app_icon: &str,
+            summary: &str,
+            _body: &str,
+            actions: Vec<String>,
+            _hints: std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+            _expire_timeout: i32,
+        ) -> u32 {
+            let mut notified = self.notified.lock().unwrap();
+            notified.push((summary.to_string(), actions));
+            notified.len() as u32
+        }
+
+        #[zbus(signal)]
+        async fn action_invoked(
+            emitter: &zbus::object_server::SignalEmitter<'_>,
+            id: u32,
+            action_key: &str,
+        ) -> zbus::Result<()>;
+    }
+
+    #[tokio::test]
+    async fn test_desktop_notifier_over_dbus() {
+        let (client_socket, server_socket) = std::os::unix::net::UnixStream::pair().unwrap();
+        let notified = NotifyCalls::default();
+        let server = zbus::connection::Builder::unix_stream(server_socket)
+            .server(zbus::Guid::generate())
+            .unwrap()
+            .p2p()
+            .serve_at(
+                "/org/freedesktop/Notifications",
+                FakeNotificationServer {
+                    notified: std::sync::Arc::clone(&notified),
+                },
+            )
+            .unwrap()
+            .build();
+        let client = zbus::connection::Builder::unix_stream(client_socket).p2p().build();
+        let (server, client) = tokio::try_join!(server, client).unwrap();
+
+        let (click_tx, click_rx) = std::sync::mpsc::channel();
+        let notifier = DesktopNotifier::connect(
+            &client,
+            Box::new(move |target| {
+                let _ = click_tx.send(target);
+            }),
+        )
+        .await
+        .unwrap();
+        let target = NotificationTarget::Profile("alice.test".to_string());
+        notifier
+            .notify(DesktopNotification {
+                summary: "@alice.test followed you".to_string(),
+                body: String::new(),
+                target: target.clone(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            *notified.lock().unwrap(),
+            vec![(
+                "@alice.test followed you".to_string(),
+                vec!["default".to_string(), "Open".to_string()]
+            )]
+        );
+
+        let iface = server
+            .object_server()
+            .interface::<_, FakeNotificationServer>("/org/freedesktop/Notifications")
+            .await
+            .unwrap();
+        FakeNotificationServer::action_invoked(iface.signal_emitter(), 1, "default")
+            .await
+            .unwrap();
+        let clicked = tokio::task::spawn_blocking(move || click_rx.recv_timeout(std::time::Duration::from_secs(5)))
+            .await
+            .unwrap();
+        assert_eq!(clicked, Ok(target));
+        assert!(is_after("2024-01-01T00:00:01Z", "2024-01-01T00:00:00.500Z"));
+    }
+}
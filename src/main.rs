@@ -2,7 +2,12 @@
 
 mod app;
 mod bsky_actor;
+mod desktop_notify;
+mod post_db;
+mod profile_image;
+mod realtime;
 mod updater;
+mod url_handler;
 
 use crate::app::RedskyApp;
 
@@ -30,17 +35,30 @@ fn load_icon() -> egui::IconData {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut is_screenshot_mode = false;
     let mut screenshot_output_path = None;
+    let mut startup_link = None;
 
-    for arg in std::env::args() {
+    for arg in std::env::args().skip(1) {
         if arg == "--test-screenshot" {
             is_screenshot_mode = true;
         } else if arg.starts_with("--test-screenshot-output=") {
             screenshot_output_path = Some(arg.trim_start_matches("--test-screenshot-output=").to_string());
+        } else if crate::app::AtUriTarget::parse_url(&arg).is_some() {
+            // An at:// uri or bsky.app link, this is how the OS hands over the links we handle
+            startup_link = Some(arg);
         }
     }
 
-    let mut options = eframe::NativeOptions::default();
-    options.viewport = egui::ViewportBuilder::default().with_icon(std::sync::Arc::new(load_icon()));
+    // Opening a link while Redsky runs shows it there instead of starting a second window
+    if let Some(link) = &startup_link {
+        if !is_screenshot_mode && url_handler::forward_to_running_instance(link, url_handler::INSTANCE_PORT) {
+            return Ok(());
+        }
+    }
+
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_icon(std::sync::Arc::new(load_icon())),
+        ..Default::default()
+    };
 
     // Run the GUI in the main thread.
     let _ = eframe::run_native(
@@ -50,7 +68,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let (msg_tx, msg_rx) = std::sync::mpsc::channel();
             let (result_tx, result_rx) = std::sync::mpsc::channel();
 
-            let app = RedskyApp::new(msg_tx, result_tx.clone(), result_rx, is_screenshot_mode, screenshot_output_path);
+            let app = RedskyApp::new(msg_tx, result_tx.clone(), result_rx, is_screenshot_mode, screenshot_output_path)
+                .with_startup_link(startup_link);
 
             #[cfg(target_os = "windows")]
             {
@@ -62,6 +81,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 });
             }
             let actor_ctx = _cc.egui_ctx.clone();
+            if !is_screenshot_mode {
+                url_handler::listen_for_links(url_handler::INSTANCE_PORT, result_tx.clone(), _cc.egui_ctx.clone());
+            }
 
             match app.settings.theme {
                 crate::app::AppTheme::System => {}
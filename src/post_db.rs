@@ -0,0 +1,265 @@
+use crate::app::HistoryFilter;
+use crate::app::Post;
+use crate::app::UserProfile;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::sync::Mutex;
+
+/// Feeds longer than this are cut when restored from the cache, without a cursor to continue.
+const MAX_CACHED_FEED_POSTS: usize = 1000;
+const MAX_HISTORY_RESULTS: usize = 200;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS posts (
+        uri TEXT PRIMARY KEY,
+        date TEXT NOT NULL,
+        liked INTEGER NOT NULL,
+        bookmarked INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE VIRTUAL TABLE IF NOT EXISTS posts_fts USING fts5(uri UNINDEXED, content, author, display_name);
+    CREATE TABLE IF NOT EXISTS profiles (
+        did TEXT PRIMARY KEY,
+        handle TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS profiles_handle ON profiles (handle);
+    CREATE TABLE IF NOT EXISTS feeds (
+        feed TEXT PRIMARY KEY,
+        cursor TEXT
+    );
+    CREATE TABLE IF NOT EXISTS feed_posts (
+        feed TEXT NOT NULL,
+        position INTEGER NOT NULL,
+        uri TEXT NOT NULL,
+        PRIMARY KEY (feed, position),
+        UNIQUE (feed, uri)
+    );
+";
+
+/// Local store of the posts, profiles and feed pages fetched by the actor. Feeds are keyed
+/// like make_post_view: "Your timeline", "Your bookmarks", or the custom feed / list uri.
+pub struct PostDb {
+    conn: Mutex<Connection>,
+}
+
+impl PostDb {
+    /// Every account has its own database, so switching accounts does not mix up their posts.
+    pub fn open_default(did: &str) -> Option<Self> {
+        let proj_dirs = directories::ProjectDirs::from("com", "Redsky", "Redsky")?;
+        let data_dir = crate::app::account_dir(proj_dirs.data_dir(), did);
+        std::fs::create_dir_all(&data_dir).ok()?;
+        match Self::open(data_dir.join("posts.sqlite3")) {
+            Ok(db) => Some(db),
+            Err(err) => {
+                println!("post db: {}", err);
+                None
+            }
+        }
+    }
+
+    pub fn open(path: impl AsRef<std::path::Path>) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    fn insert_posts(conn: &Connection, posts: &[Post]) -> rusqlite::Result<()> {
+        for post in posts {
+            let data = serde_json::to_string(post).map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
+            conn.execute(
+                "INSERT OR REPLACE INTO posts (uri, date, liked, bookmarked, data) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![post.uri, post.date, post.viewer_like.is_some(), post.bookmarked, data],
+            )?;
+            conn.execute("DELETE FROM posts_fts WHERE uri = ?1", params![post.uri])?;
+            conn.execute(
+                "INSERT INTO posts_fts (uri, content, author, display_name) VALUES (?1, ?2, ?3, ?4)",
+                params![post.uri, post.content, post.author, post.display_name],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn store_posts(&self, posts: &[Post]) -> rusqlite::Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        Self::insert_posts(&tx, posts)?;
+        tx.commit()
+    }
+
+    /// Stores a page of a feed. A first page (`append == false`) starts the feed over.
+    pub fn store_feed_page(
+        &self,
+        feed: &str,
+        posts: &[Post],
+        cursor: &Option<String>,
+        append: bool,
+    ) -> rusqlite::Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        Self::insert_posts(&tx, posts)?;
+        if !append {
+            tx.execute("DELETE FROM feed_posts WHERE feed = ?1", params![feed])?;
+        }
+        let mut position: i64 = tx.query_row(
+            "SELECT COALESCE(MAX(position) + 1, 0) FROM feed_posts WHERE feed = ?1",
+            params![feed],
+            |row| row.get(0),
+        )?;
+        for post in posts {
+            position += tx.execute(
+                "INSERT OR IGNORE INTO feed_posts (feed, position, uri) VALUES (?1, ?2, ?3)",
+                params![feed, position, post.uri],
+            )? as i64;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO feeds (feed, cursor) VALUES (?1, ?2)",
+            params![feed, cursor],
+        )?;
+        tx.commit()
+    }
+
+    pub fn store_profiles(&self, profiles: &[UserProfile]) -> rusqlite::Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        for profile in profiles {
+            let data =
+                serde_json::to_string(profile).map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
+            tx.execute(
+                "INSERT OR REPLACE INTO profiles (did, handle, data) VALUES (?1, ?2, ?3)",
+                params![profile.did, profile.handle, data],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Returns the stored pages of a feed and the cursor to continue after them.
+    pub fn load_feed(&self, feed: &str) -> rusqlite::Result<(Vec<Post>, Option<String>)> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "SELECT posts.data FROM feed_posts JOIN posts ON posts.uri = feed_posts.uri
+             WHERE feed_posts.feed = ?1 ORDER BY feed_posts.position LIMIT ?2",
+        )?;
+        let posts = statement
+            .query_map(params![feed, MAX_CACHED_FEED_POSTS + 1], |row| row.get::<_, String>(0))?
+            .filter_map(|data| serde_json::from_str(&data.ok()?).ok())
+            .collect::<Vec<Post>>();
+        if posts.len() > MAX_CACHED_FEED_POSTS {
+            return Ok((posts.into_iter().take(MAX_CACHED_FEED_POSTS).collect(), None));
+        }
+        let cursor = conn
+            .query_row("SELECT cursor FROM feeds WHERE feed = ?1", params![feed], |row| row.get(0))
+            .optional()?
+            .flatten();
+        Ok((posts, cursor))
+    }
+
+    pub fn load_profile(&self, handle_or_did: &str) -> rusqlite::Result<Option<UserProfile>> {
+        let conn = self.conn.lock().unwrap();
+        let data: Option<String> = conn
+            .query_row(
+                "SELECT data FROM profiles WHERE handle = ?1 OR did = ?1",
+                params![handle_or_did],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(data.and_then(|data| serde_json::from_str(&data).ok()))
+    }
+
+    /// Full-text search over every stored post, best matches first.
+    pub fn search(&self, query: &str, filter: HistoryFilter) -> rusqlite::Result<Vec<Post>> {
+        let Some(match_query) = fts_query(query) else {
+            return Ok(Vec::new());
+        };
+        let filter = match filter {
+            HistoryFilter::All => "",
+            HistoryFilter::Liked => "AND posts.liked",
+            HistoryFilter::Bookmarked => "AND posts.bookmarked",
+        };
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(&format!(
+            "SELECT posts.data FROM posts_fts JOIN posts ON posts.uri = posts_fts.uri
+             WHERE posts_fts MATCH ?1 {} ORDER BY posts_fts.rank LIMIT ?2",
+            filter
+        ))?;
+        let posts = statement
+            .query_map(params![match_query, MAX_HISTORY_RESULTS], |row| row.get::<_, String>(0))?
+            .filter_map(|data| serde_json::from_str(&data.ok()?).ok())
+            .collect();
+        Ok(posts)
+    }
+}
+
+/// Turns user input into an FTS5 query where every word must match, as a prefix.
+fn fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(rkey: &str, content: &str) -> Post {
+        Post {
+            uri: format!("at://did:plc:alice/app.bsky.feed.post/{}", rkey),
+            cid: "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m".parse().unwrap(),
+            content: content.to_string(),
+            author: "alice".to_string(),
+            author_did: "did:plc:alice".to_string(),
+            display_name: String::new(),
+            avatar_img: String::new(),
+            date: String::new(),
+            like_count: 0,
+            repost_count: 0,
+            embeds: vec![],
+            quoted_post: None,
+            is_reply: false,
+            viewer_like: None,
+            viewer_repost: None,
+            bookmarked: false,
+            author_muted: false,
+            author_blocked: false,
+            labels: vec![],
+            thread_root: None,
+            tags: vec![],
+            raw_json: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_post_db_feed_cache_and_search() {
+        let db = PostDb::open_in_memory().unwrap();
+        let mut liked = post("2", "Rustaceans love borrow checking");
+        liked.viewer_like = Some("at://did:plc:me/app.bsky.feed.like/1".to_string());
+        db.store_feed_page("Your timeline", &[post("1", "hello world"), liked], &Some("c1".to_string()), false)
+            .unwrap();
+        db.store_feed_page("Your timeline", &[post("3", "goodbye \"world\"")], &Some("c2".to_string()), true)
+            .unwrap();
+
+        let (posts, cursor) = db.load_feed("Your timeline").unwrap();
+        let contents: Vec<&str> = posts.iter().map(|post| post.content.as_str()).collect();
+        assert_eq!(contents, ["hello world", "Rustaceans love borrow checking", "goodbye \"world\""]);
+        assert_eq!(cursor.as_deref(), Some("c2"));
+
+        // A first page starts the feed over, stored posts stay searchable
+        db.store_feed_page("Your timeline", &[post("4", "fresh")], &None, false).unwrap();
+        assert_eq!(db.load_feed("Your timeline").unwrap().0.len(), 1);
+        assert_eq!(db.search("world", HistoryFilter::All).unwrap().len(), 2);
+        assert_eq!(db.search("\"world", HistoryFilter::All).unwrap().len(), 2);
+        let found = db.search("rust borrow", HistoryFilter::Liked).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].uri, "at://did:plc:alice/app.bsky.feed.post/2");
+        assert!(db.search("hello", HistoryFilter::Bookmarked).unwrap().is_empty());
+    }
+}
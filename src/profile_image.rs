@@ -0,0 +1,92 @@
+#![cfg_attr(feature = "mock-api", allow(dead_code))]
+
+use image::imageops::FilterType;
+use image::DynamicImage;
+
+/// The PDS rejects profile images above this size.
+const MAX_IMAGE_BYTES: usize = 1_000_000;
+const JPEG_QUALITIES: [u8; 6] = [90, 80, 70, 60, 50, 40];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileImageKind {
+    Avatar,
+    Banner,
+}
+
+impl ProfileImageKind {
+    /// Aspect ratio and largest size of the uploaded image, the same as the official app.
+    fn target_size(self) -> (u32, u32) {
+        match self {
+            ProfileImageKind::Avatar => (1000, 1000),
+            ProfileImageKind::Banner => (3000, 1000),
+        }
+    }
+}
+
+/// Crops the center of the image to the aspect ratio of `kind`, scaled down to its target size.
+pub fn crop_and_resize(image: &DynamicImage, kind: ProfileImageKind) -> DynamicImage {
+    let (target_width, target_height) = kind.target_size();
+    let (width, height) = (image.width() as u64, image.height() as u64);
+    let (crop_width, crop_height) = if width * target_height as u64 > height * target_width as u64 {
+        (height * target_width as u64 / target_height as u64, height)
+    } else {
+        (width, width * target_height as u64 / target_width as u64)
+    };
+    let (crop_width, crop_height) = (crop_width.max(1) as u32, crop_height.max(1) as u32);
+    let cropped = image.crop_imm(
+        (image.width() - crop_width) / 2,
+        (image.height() - crop_height) / 2,
+        crop_width,
+        crop_height,
+    );
+    if crop_width > target_width {
+        cropped.resize_exact(target_width, target_height, FilterType::Lanczos3)
+    } else {
+        cropped
+    }
+}
+
+/// Decodes a picked image file and re-encodes it as a JPEG that fits the upload limit.
+pub fn prepare(bytes: &[u8], kind: ProfileImageKind) -> Result<Vec<u8>, String> {
+    let image = image::load_from_memory(bytes).map_err(|e| e.to_string())?;
+    let image = crop_and_resize(&image, kind).to_rgb8();
+    for quality in JPEG_QUALITIES {
+        let mut encoded = Vec::new();
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut encoded, quality)
+            .encode_image(&image)
+            .map_err(|e| e.to_string())?;
+        if encoded.len() <= MAX_IMAGE_BYTES {
+            return Ok(encoded);
+        }
+    }
+    Err("Image is too large to upload".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_image_crop_and_resize() {
+        let photo = image::DynamicImage::new_rgb8(1600, 1200);
+        let avatar = crop_and_resize(&photo, ProfileImageKind::Avatar);
+        assert_eq!((avatar.width(), avatar.height()), (1000, 1000));
+        let banner = crop_and_resize(&photo, ProfileImageKind::Banner);
+        assert_eq!((banner.width(), banner.height()), (1600, 533));
+
+        // Images are never scaled up
+        let small = image::DynamicImage::new_rgb8(300, 400);
+        let avatar = crop_and_resize(&small, ProfileImageKind::Avatar);
+        assert_eq!((avatar.width(), avatar.height()), (300, 300));
+        let banner = crop_and_resize(&small, ProfileImageKind::Banner);
+        assert_eq!((banner.width(), banner.height()), (300, 100));
+
+        let mut png = Vec::new();
+        small
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        let jpeg = prepare(&png, ProfileImageKind::Avatar).unwrap();
+        assert_eq!(image::guess_format(&jpeg).unwrap(), image::ImageFormat::Jpeg);
+        assert!(prepare(b"not an image", ProfileImageKind::Banner).is_err());
+    }
+}
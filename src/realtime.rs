@@ -0,0 +1,334 @@
+#![cfg_attr(feature = "mock-api", allow(dead_code))]
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_tungstenite::tungstenite::Message;
+
+pub const JETSTREAM_URL: &str = "wss://jetstream2.us-east.bsky.network/subscribe";
+const WANTED_COLLECTIONS: [&str; 3] = ["app.bsky.feed.post", "app.bsky.feed.like", "app.bsky.feed.repost"];
+/// Jetstream cursors are microsecond timestamps, rewinding a little on reconnect makes replay gapless.
+const CURSOR_REWIND_US: i64 = 2_000_000;
+const MAX_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RealtimeEvent {
+    Post {
+        did: String,
+        uri: String,
+        reply_parent: Option<String>,
+        mentions: Vec<String>, // mentioned DIDs
+    },
+    Like {
+        did: String,
+        rkey: String,
+        subject_uri: String,
+    },
+    Repost {
+        did: String,
+        rkey: String,
+        subject_uri: String,
+    },
+    /// An unlike or an unrepost, deletions only name the record
+    Undo {
+        did: String,
+        rkey: String,
+        is_like: bool, // repost otherwise
+    },
+}
+
+/// Likes and reposts already counted, so that events replayed on reconnect are not counted
+/// twice and deletions find the post they were about.
+#[derive(Default)]
+pub struct CountedRecords {
+    order: std::collections::VecDeque<String>,
+    subjects: std::collections::HashMap<String, String>, // did/collection/rkey -> subject uri
+}
+
+impl CountedRecords {
+    const CAPACITY: usize = 10_000;
+
+    pub fn key(did: &str, is_like: bool, rkey: &str) -> String {
+        let collection = if is_like { "app.bsky.feed.like" } else { "app.bsky.feed.repost" };
+        format!("{}/{}/{}", did, collection, rkey)
+    }
+
+    /// Returns false when the record was counted already.
+    pub fn insert(&mut self, key: String, subject_uri: String) -> bool {
+        if self.subjects.contains_key(&key) {
+            return false;
+        }
+        if self.order.len() >= Self::CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.subjects.remove(&oldest);
+            }
+        }
+        self.order.push_back(key.clone());
+        self.subjects.insert(key, subject_uri);
+        true
+    }
+
+    /// The subject of a deleted record, None when it was not counted or already removed.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.subjects.remove(key)
+    }
+}
+
+#[derive(Deserialize)]
+struct JetstreamMessage {
+    did: String,
+    time_us: i64,
+    commit: Option<JetstreamCommit>,
+}
+
+#[derive(Deserialize)]
+struct JetstreamCommit {
+    operation: String,
+    collection: String,
+    rkey: String,
+    record: Option<serde_json::Value>,
+}
+
+/// Returns the cursor of any Jetstream message, and the event for the records we care about.
+pub fn parse_message(text: &str) -> Option<(i64, Option<RealtimeEvent>)> {
+    let message: JetstreamMessage = serde_json::from_str(text).ok()?;
+    let event = message.commit.and_then(|commit| {
+        if commit.operation == "delete" {
+            return match commit.collection.as_str() {
+                "app.bsky.feed.like" | "app.bsky.feed.repost" => Some(RealtimeEvent::Undo {
+                    did: message.did.clone(),
+                    rkey: commit.rkey,
+                    is_like: commit.collection == "app.bsky.feed.like",
+                }),
+                _ => None,
+            };
+        }
+        if commit.operation != "create" {
+            return None;
+        }
+        let record = commit.record?;
+        let subject_uri = || record["subject"]["uri"].as_str().map(|uri| uri.to_string());
+        match commit.collection.as_str() {
+            "app.bsky.feed.post" => Some(RealtimeEvent::Post {
+                uri: format!("at://{}/app.bsky.feed.post/{}", message.did, commit.rkey),
+                did: message.did.clone(),
+                reply_parent: record["reply"]["parent"]["uri"].as_str().map(|uri| uri.to_string()),
+                mentions: record["facets"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|facet| facet["features"].as_array().into_iter().flatten())
+                    .filter(|feature| feature["$type"] == "app.bsky.richtext.facet#mention")
+                    .filter_map(|feature| feature["did"].as_str().map(|did| did.to_string()))
+                    .collect(),
+            }),
+            "app.bsky.feed.like" => Some(RealtimeEvent::Like {
+                did: message.did.clone(),
+                rkey: commit.rkey.clone(),
+                subject_uri: subject_uri()?,
+            }),
+            "app.bsky.feed.repost" => Some(RealtimeEvent::Repost {
+                did: message.did.clone(),
+                rkey: commit.rkey.clone(),
+                subject_uri: subject_uri()?,
+            }),
+            _ => None,
+        }
+    });
+    Some((message.time_us, event))
+}
+
+pub fn subscribe_url(base_url: &str, cursor: Option<i64>) -> String {
+    let mut url = format!("{}?requireHello=true", base_url);
+    for collection in WANTED_COLLECTIONS {
+        url.push_str(&format!("&wantedCollections={}", collection));
+    }
+    if let Some(cursor) = cursor {
+        url.push_str(&format!("&cursor={}", cursor));
+    }
+    url
+}
+
+/// The DID filter is sent as an options update rather than in the URL, which would
+/// get too long for accounts following thousands of people.
+fn options_update(dids: &[String]) -> String {
+    serde_json::json!({
+        "type": "options_update",
+        "payload": {
+            "wantedCollections": WANTED_COLLECTIONS,
+            "wantedDids": dids,
+        }
+    })
+    .to_string()
+}
+
+/// Streams events from Jetstream, filtered to `dids`, reconnecting with cursor replay
+/// and exponential backoff. Only returns once `events` is closed.
+pub async fn subscribe(base_url: &str, dids: &[String], events: UnboundedSender<RealtimeEvent>) {
+    let mut cursor: Option<i64> = None;
+    let mut delay = std::time::Duration::from_secs(1);
+    loop {
+        let url = subscribe_url(base_url, cursor.map(|c| c - CURSOR_REWIND_US));
+        match tokio_tungstenite::connect_async(url.as_str()).await {
+            Ok((mut socket, _)) => {
+                if socket.send(Message::Text(options_update(dids).into())).await.is_ok() {
+                    while let Some(Ok(message)) = socket.next().await {
+                        let Message::Text(text) = message else { continue };
+                        let Some((time_us, event)) = parse_message(text.as_str()) else { continue };
+                        cursor = Some(time_us);
+                        delay = std::time::Duration::from_secs(1);
+                        if let Some(event) = event {
+                            if events.send(event).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                println!("jetstream: disconnected, reconnecting in {:?}", delay);
+            }
+            Err(err) => println!("jetstream: {}, reconnecting in {:?}", err, delay),
+        }
+        if events.is_closed() {
+            return;
+        }
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[allow(clippy::result_large_err)] // the handshake callback signature is fixed by tungstenite
+    async fn test_realtime_reconnects_with_cursor_replay() {
+        // Local Jetstream stand-in: each connection records its URL and the options update,
+        // sends one event, then drops the connection
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("ws://{}/subscribe", listener.local_addr().unwrap());
+        let (requests_tx, mut requests_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for time_us in [10_000_000i64, 20_000_000] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut url = String::new();
+                let mut socket = tokio_tungstenite::accept_hdr_async(
+                    stream,
+                    |request: &tokio_tungstenite::tungstenite::handshake::server::Request, response| {
+                        url = request.uri().to_string();
+                        Ok(response)
+                    },
+                )
+                .await
+                .unwrap();
+                let Some(Ok(Message::Text(options))) = socket.next().await else {
+                    panic!("expected an options update");
+                };
+                requests_tx.send((url, options.to_string())).unwrap();
+                let event = serde_json::json!({
+                    "did": "did:plc:alice",
+                    "time_us": time_us,
+                    "kind": "commit",
+                    "commit": {
+                        "rev": "1",
+                        "operation": "create",
+                        "collection": "app.bsky.feed.like",
+                        "rkey": "abc",
+                        "cid": "bafyreidfzuflltehrwqx5dzlqg3vzd2q6fudx75h7m3e7y22qpxg3ntv6m",
+                        "record": {
+                            "$type": "app.bsky.feed.like",
+                            "subject": { "uri": "at://did:plc:me/app.bsky.feed.post/1", "cid": "x" },
+                            "createdAt": "2024-01-01T00:00:00Z"
+                        }
+                    }
+                });
+                socket.send(Message::Text(event.to_string().into())).await.unwrap();
+                socket.close(None).await.unwrap();
+            }
+        });
+
+        let (events_tx, mut events_rx) = tokio::sync::mpsc::unbounded_channel();
+        let dids = vec!["did:plc:alice".to_string()];
+        let subscription = tokio::spawn(async move { subscribe(&base_url, &dids, events_tx).await });
+
+        for _ in 0..2 {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(10), events_rx.recv())
+                .await
+                .unwrap();
+            assert_eq!(
+                event,
+                Some(RealtimeEvent::Like {
+                    did: "did:plc:alice".to_string(),
+                    rkey: "abc".to_string(),
+                    subject_uri: "at://did:plc:me/app.bsky.feed.post/1".to_string(),
+                })
+            );
+        }
+        let (first_url, options) = requests_rx.recv().await.unwrap();
+        assert!(!first_url.contains("cursor="));
+        assert!(first_url.contains("wantedCollections=app.bsky.feed.post"));
+        assert!(options.contains("\"wantedDids\":[\"did:plc:alice\"]"));
+        let (second_url, _) = requests_rx.recv().await.unwrap();
+        assert!(second_url.contains("cursor=8000000"), "{}", second_url);
+        subscription.abort();
+    }
+
+    #[test]
+    fn test_parse_jetstream_post() {
+        let message = serde_json::json!({
+            "did": "did:plc:bob",
+            "time_us": 42,
+            "kind": "commit",
+            "commit": {
+                "rev": "1",
+                "operation": "create",
+                "collection": "app.bsky.feed.post",
+                "rkey": "3kxyz",
+                "record": {
+                    "text": "hi @me",
+                    "reply": { "parent": { "uri": "at://did:plc:me/app.bsky.feed.post/1" } },
+                    "facets": [{ "features": [{ "$type": "app.bsky.richtext.facet#mention", "did": "did:plc:me" }] }]
+                }
+            }
+        });
+        assert_eq!(
+            parse_message(&message.to_string()),
+            Some((
+                42,
+                Some(RealtimeEvent::Post {
+                    did: "did:plc:bob".to_string(),
+                    uri: "at://did:plc:bob/app.bsky.feed.post/3kxyz".to_string(),
+                    reply_parent: Some("at://did:plc:me/app.bsky.feed.post/1".to_string()),
+                    mentions: vec!["did:plc:me".to_string()],
+                })
+            ))
+        );
+        // Identity and account events only move the cursor
+        assert_eq!(
+            parse_message(r#"{"did":"did:plc:bob","time_us":43,"kind":"identity"}"#),
+            Some((43, None))
+        );
+
+        // Unlikes name the deleted record only, counted records are taken back once
+        let unlike = r#"{"did":"did:plc:bob","time_us":44,"kind":"commit","commit":{"rev":"2","operation":"delete","collection":"app.bsky.feed.like","rkey":"3kl"}}"#;
+        assert_eq!(
+            parse_message(unlike),
+            Some((
+                44,
+                Some(RealtimeEvent::Undo {
+                    did: "did:plc:bob".to_string(),
+                    rkey: "3kl".to_string(),
+                    is_like: true,
+                })
+            ))
+        );
+        let mut counted = CountedRecords::default();
+        let key = CountedRecords::key("did:plc:bob", true, "3kl");
+        let subject = "at://did:plc:me/app.bsky.feed.post/1".to_string();
+        assert!(counted.insert(key.clone(), subject.clone()));
+        assert!(!counted.insert(key.clone(), subject.clone()), "replayed likes count once");
+        assert_ne!(key, CountedRecords::key("did:plc:bob", false, "3kl"));
+        assert_eq!(counted.remove(&key), Some(subject));
+        assert_eq!(counted.remove(&key), None);
+    }
+}
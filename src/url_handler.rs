@@ -0,0 +1,130 @@
+//! Registers Redsky as the handler of at:// links, which the OS then opens as `redsky <uri>`.
+//! A link opened while Redsky already runs is handed over to that instance on a local port.
+
+use crate::app::{AtUriTarget, RedskyUiMsg};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+use std::process::Command;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+/// Where the first instance listens for links.
+pub const INSTANCE_PORT: u16 = 47613;
+/// Sent before the link, so whatever else listens on the port does not take it for us.
+const HANDOFF_GREETING: &str = "redsky-open";
+const HANDOFF_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[cfg(target_os = "linux")]
+const DESKTOP_FILE: &str = "redsky.desktop";
+
+/// Registers the running executable for the current user.
+pub fn register() -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|err| err.to_string())?;
+    register_exe(&exe.display().to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn register_exe(exe: &str) -> Result<(), String> {
+    let key = r"HKCU\Software\Classes\at";
+    let command = format!("\"{}\" \"%1\"", exe);
+    run(Command::new("reg").args(["add", key, "/ve", "/d", "URL:AT Protocol", "/f"]))?;
+    run(Command::new("reg").args(["add", key, "/v", "URL Protocol", "/d", "", "/f"]))?;
+    run(Command::new("reg").args(["add", &format!(r"{}\shell\open\command", key), "/ve", "/d", &command, "/f"]))
+}
+
+#[cfg(target_os = "linux")]
+fn register_exe(exe: &str) -> Result<(), String> {
+    let dirs = directories::BaseDirs::new().ok_or("Could not find the home directory")?;
+    let applications = dirs.data_dir().join("applications");
+    std::fs::create_dir_all(&applications).map_err(|err| err.to_string())?;
+    let entry = format!(
+        "[Desktop Entry]\nType=Application\nName=Redsky\nExec=\"{}\" %u\nTerminal=false\nNoDisplay=true\nMimeType=x-scheme-handler/at;\n",
+        exe
+    );
+    std::fs::write(applications.join(DESKTOP_FILE), entry).map_err(|err| err.to_string())?;
+    run(Command::new("xdg-mime").args(["default", DESKTOP_FILE, "x-scheme-handler/at"]))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn register_exe(_exe: &str) -> Result<(), String> {
+    Err("On this system the link handler is declared by the application bundle".to_string())
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn run(command: &mut Command) -> Result<(), String> {
+    let output = command.output().map_err(|err| err.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Hands the link to an already running Redsky, false if there is none.
+pub fn forward_to_running_instance(link: &str, port: u16) -> bool {
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, HANDOFF_TIMEOUT) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(HANDOFF_TIMEOUT));
+    if writeln!(stream, "{} {}", HANDOFF_GREETING, link).is_err() {
+        return false;
+    }
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply).is_ok() && reply.trim() == "ok"
+}
+
+/// Takes the links of later launches in the background and returns the port listened on.
+/// Only the first instance gets the port.
+pub fn listen_for_links(port: u16, tx: Sender<RedskyUiMsg>, ctx: egui::Context) -> Option<u16> {
+    let listener = match TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], port))) {
+        Ok(listener) => listener,
+        Err(err) => {
+            println!("link handoff: {}", err);
+            return None;
+        }
+    };
+    let port = listener.local_addr().ok()?.port();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = stream.set_read_timeout(Some(HANDOFF_TIMEOUT));
+            let mut line = String::new();
+            if BufReader::new((&stream).take(4096)).read_line(&mut line).is_err() {
+                continue;
+            }
+            let Some(link) = line.trim().strip_prefix(HANDOFF_GREETING).map(str::trim) else {
+                continue;
+            };
+            if AtUriTarget::parse_url(link).is_none() {
+                continue;
+            }
+            let _ = (&stream).write_all(b"ok\n");
+            if tx.send(RedskyUiMsg::OpenForwardedLink { link: link.to_string() }).is_err() {
+                break;
+            }
+            ctx.request_repaint();
+        }
+    });
+    Some(port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_handoff_to_running_instance() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let port = listen_for_links(0, tx, egui::Context::default()).unwrap();
+        assert!(forward_to_running_instance("at://alice.bsky.social", port));
+        match rx.recv_timeout(Duration::from_secs(5)) {
+            Ok(RedskyUiMsg::OpenForwardedLink { link }) => assert_eq!(link, "at://alice.bsky.social"),
+            _ => panic!("the link was not handed over"),
+        }
+        // Only at:// and bsky.app links are taken, not file names that look like handles
+        assert!(!forward_to_running_instance("--test-screenshot", port));
+        assert!(!forward_to_running_instance("notes.txt", port));
+        assert!(rx.try_recv().is_err());
+    }
+}